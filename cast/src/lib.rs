@@ -33,6 +33,8 @@ use tx::{TxBuilderOutput, TxBuilderPeekOutput};
 
 pub mod base;
 pub mod errors;
+pub mod metadata;
+pub mod mpt;
 mod rlp_converter;
 mod tx;
 
@@ -80,7 +82,7 @@ where
     /// let to = Address::from_str("0xB3C95ff08316fb2F2e3E52Ee82F8e7b605Aa1304")?;
     /// let sig = "function greeting(uint256 i) public returns (string)";
     /// let args = vec!["5".to_owned()];
-    /// let mut builder = TxBuilder::new(&provider, Address::zero(), Some(to), Chain::Mainnet, false).await?;
+    /// let mut builder = TxBuilder::new(&provider, Address::zero(), Some(to), Chain::Mainnet, false, false).await?;
     /// builder
     ///     .set_args(sig, args).await?;
     /// let builder_output = builder.build();
@@ -150,7 +152,7 @@ where
     /// let to = Address::from_str("0xB3C95ff08316fb2F2e3E52Ee82F8e7b605Aa1304")?;
     /// let sig = "greeting(uint256)(string)";
     /// let args = vec!["5".to_owned()];
-    /// let mut builder = TxBuilder::new(&provider, Address::zero(), Some(to), Chain::Mainnet, false).await?;
+    /// let mut builder = TxBuilder::new(&provider, Address::zero(), Some(to), Chain::Mainnet, false, false).await?;
     /// builder
     ///     .set_args(sig, args).await?;
     /// let builder_output = builder.peek();
@@ -215,7 +217,7 @@ where
     /// let gas = U256::from_str("200000").unwrap();
     /// let value = U256::from_str("1").unwrap();
     /// let nonce = U256::from_str("1").unwrap();
-    /// let mut builder = TxBuilder::new(&provider, Address::zero(), Some(to), Chain::Mainnet, false).await?;
+    /// let mut builder = TxBuilder::new(&provider, Address::zero(), Some(to), Chain::Mainnet, false, false).await?;
     /// builder
     ///     .set_args(sig, args).await?
     ///     .set_gas(gas)
@@ -282,21 +284,25 @@ where
     /// let sig = "greet(string)()";
     /// let args = vec!["5".to_owned()];
     /// let value = U256::from_str("1").unwrap();
-    /// let mut builder = TxBuilder::new(&provider, from, Some(to), Chain::Mainnet, false).await?;
+    /// let mut builder = TxBuilder::new(&provider, from, Some(to), Chain::Mainnet, false, false).await?;
     /// builder
     ///     .set_value(value)
     ///     .set_args(sig, args).await?;
     /// let builder_output = builder.peek();
     /// let cast = Cast::new(&provider);
-    /// let data = cast.estimate(builder_output).await?;
+    /// let data = cast.estimate(builder_output, None).await?;
     /// println!("{}", data);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn estimate(&self, builder_output: TxBuilderPeekOutput<'_>) -> Result<U256> {
+    pub async fn estimate(
+        &self,
+        builder_output: TxBuilderPeekOutput<'_>,
+        block: Option<BlockId>,
+    ) -> Result<U256> {
         let (tx, _) = builder_output;
 
-        let res = self.provider.estimate_gas(tx, None).await?;
+        let res = self.provider.estimate_gas(tx, block).await?;
 
         Ok::<_, eyre::Error>(res)
     }
@@ -311,17 +317,21 @@ where
     /// # async fn foo() -> eyre::Result<()> {
     /// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
     /// let cast = Cast::new(provider);
-    /// let block = cast.block(5, true, None, false).await?;
+    /// let block = cast.block(5, true, None, false, false).await?;
     /// println!("{}", block);
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Numeric fields are rendered in decimal by default; pass `hex: true` to render a
+    /// single selected numeric field (e.g. `baseFeePerGas`) in hex instead.
     pub async fn block<T: Into<BlockId>>(
         &self,
         block: T,
         full: bool,
         field: Option<String>,
         to_json: bool,
+        hex: bool,
     ) -> Result<String> {
         let block = block.into();
         let block = if full {
@@ -331,8 +341,9 @@ where
                 .await?
                 .ok_or_else(|| eyre::eyre!("block {:?} not found", block))?;
             if let Some(ref field) = field {
-                get_pretty_block_attr(&block, field)
-                    .unwrap_or_else(|| format!("{field} is not a valid block field"))
+                let value = get_pretty_block_attr(&block, field)
+                    .unwrap_or_else(|| format!("{field} is not a valid block field"));
+                to_hex_if(value, hex)
             } else if to_json {
                 serde_json::to_value(&block).unwrap().to_string()
             } else {
@@ -349,8 +360,9 @@ where
                 if field == "transactions" {
                     "use --full to view transactions".to_string()
                 } else {
-                    get_pretty_block_attr(&block, field)
-                        .unwrap_or_else(|| format!("{field} is not a valid block field"))
+                    let value = get_pretty_block_attr(&block, field)
+                        .unwrap_or_else(|| format!("{field} is not a valid block field"));
+                    to_hex_if(value, hex)
                 }
             } else if to_json {
                 serde_json::to_value(&block).unwrap().to_string()
@@ -371,6 +383,7 @@ where
             // Select only select field
             Some(field),
             false,
+            false,
         )
         .await?;
 
@@ -406,12 +419,14 @@ where
             // Select only block hash
             Some(String::from("hash")),
             false,
+            false,
         )
         .await?;
 
         Ok(match &genesis_hash[..] {
             "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3" => {
-                match &(Cast::block(self, 1920000, false, Some("hash".to_string()), false).await?)[..]
+                match &(Cast::block(self, 1920000, false, Some("hash".to_string()), false, false)
+                    .await?)[..]
                 {
                     "0x94365e3a8c0b35089c1d1195081fe7489b528a84b22199c916180db8b28ade7f" => {
                         "etclive"
@@ -443,7 +458,9 @@ where
             "0x6d3c66c5357ec91d5c43af47e234a939b22557cbb552dc45bebbceeed90fbe34" => "bsctest",
             "0x0d21840abff46b96c84b2ac9e10e4f5cdaeb5693cb665db62a2f3b02d2d57b5b" => "bsc",
             "0x31ced5b9beb7f8782b014660da0cb18cc409f121f408186886e1ca3e8eeca96b" => {
-                match &(Cast::block(self, 1, false, Some(String::from("hash")), false).await?)[..] {
+                match &(Cast::block(self, 1, false, Some(String::from("hash")), false, false)
+                    .await?)[..]
+                {
                     "0x738639479dc82d199365626f90caa82f7eafcfe9ed354b456fb3d294597ceb53" => {
                         "avalanche-fuji"
                     }
@@ -466,6 +483,15 @@ where
         Ok(self.provider.get_gas_price().await?)
     }
 
+    pub async fn fee_history(
+        &self,
+        block_count: u64,
+        newest_block: BlockNumber,
+        reward_percentiles: &[f64],
+    ) -> Result<FeeHistory> {
+        Ok(self.provider.fee_history(block_count, newest_block, reward_percentiles).await?)
+    }
+
     /// # Example
     ///
     /// ```no_run
@@ -537,7 +563,7 @@ where
     /// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
     /// let cast = Cast::new(provider);
     /// let addr = Address::from_str("0x00000000219ab540356cbb839cbe05303d7705fa")?;
-    /// let code = cast.code(addr, None).await?;
+    /// let code = cast.code(addr, None, false).await?;
     /// println!("{}", code);
     /// # Ok(())
     /// # }
@@ -546,8 +572,14 @@ where
         &self,
         who: T,
         block: Option<BlockId>,
+        disassemble: bool,
     ) -> Result<String> {
-        Ok(format!("{}", self.provider.get_code(who, block).await?))
+        let code = self.provider.get_code(who, block).await?;
+        if disassemble {
+            SimpleCast::disassemble(&format!("{code}"))
+        } else {
+            Ok(format!("{code}"))
+        }
     }
 
     /// # Example
@@ -561,7 +593,7 @@ where
     /// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
     /// let cast = Cast::new(provider);
     /// let tx_hash = "0xf8d1713ea15a81482958fb7ddf884baee8d3bcc478c5f2f604e008dc788ee4fc";
-    /// let tx = cast.transaction(tx_hash.to_string(), None, false).await?;
+    /// let tx = cast.transaction(tx_hash.to_string(), None, false, false).await?;
     /// println!("{}", tx);
     /// # Ok(())
     /// # }
@@ -571,6 +603,7 @@ where
         tx_hash: String,
         field: Option<String>,
         to_json: bool,
+        hex: bool,
     ) -> Result<String> {
         let tx_hash = H256::from_str(&tx_hash).wrap_err("invalid tx hash")?;
         let tx = self
@@ -580,8 +613,9 @@ where
             .ok_or_else(|| eyre::eyre!("tx not found: {:?}", tx_hash))?;
 
         Ok(if let Some(ref field) = field {
-            get_pretty_tx_attr(&tx, field)
-                .ok_or_else(|| eyre::eyre!("invalid tx field: {}", field))?
+            let value = get_pretty_tx_attr(&tx, field)
+                .ok_or_else(|| eyre::eyre!("invalid tx field: {}", field))?;
+            to_hex_if(value, hex)
         } else if to_json {
             // to_value first to sort json object keys
             serde_json::to_value(&tx)?.to_string()
@@ -601,19 +635,28 @@ where
     /// let provider = Provider::<Http>::try_from("http://localhost:8545")?;
     /// let cast = Cast::new(provider);
     /// let tx_hash = "0xf8d1713ea15a81482958fb7ddf884baee8d3bcc478c5f2f604e008dc788ee4fc";
-    /// let receipt = cast.receipt(tx_hash.to_string(), None, 1, false, false).await?;
+    /// let (receipt, reverted) = cast.receipt(tx_hash.to_string(), None, 1, 120, false, false).await?;
     /// println!("{}", receipt);
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Note: `to_json` serializes the receipt as returned by the node, so its `logs` are raw
+    /// (undecoded) topics/data, same as `cast logs` falls back to without a matching event
+    /// signature. Decoding them would mean fetching the emitting contract's ABI (e.g. via
+    /// Etherscan, as `cast interface` does), which isn't information this method has on hand.
+    ///
+    /// Returns the pretty-printed (or field-selected/JSON) receipt along with whether the
+    /// transaction reverted, so that callers can exit with a non-zero status on failure.
     pub async fn receipt(
         &self,
         tx_hash: String,
         field: Option<String>,
         confs: usize,
+        timeout: u64,
         cast_async: bool,
         to_json: bool,
-    ) -> Result<String> {
+    ) -> Result<(String, bool)> {
         let tx_hash = H256::from_str(&tx_hash).wrap_err("invalid tx hash")?;
 
         let mut receipt: TransactionReceiptWithRevertReason =
@@ -625,13 +668,23 @@ where
                     if cast_async {
                         eyre::bail!("tx not found: {:?}", tx_hash)
                     } else {
-                        let tx = PendingTransaction::new(tx_hash, self.provider.provider());
-                        tx.confirmations(confs).await?.ok_or_else(|| {
-                            eyre::eyre!(
-                                "tx not found, might have been dropped from mempool: {:?}",
-                                tx_hash
-                            )
-                        })?
+                        let tx = PendingTransaction::new(tx_hash, self.provider.provider())
+                            .confirmations(confs);
+                        match tokio::time::timeout(std::time::Duration::from_secs(timeout), tx)
+                            .await
+                        {
+                            Ok(result) => result?.ok_or_else(|| {
+                                eyre::eyre!(
+                                    "tx not found, might have been dropped from mempool: {:?}",
+                                    tx_hash
+                                )
+                            })?,
+                            Err(_) => eyre::bail!(
+                                "Timed out after {timeout}s waiting for a receipt for {tx_hash:?}. \
+                                 It may still land later - inspect it with `cast tx {tx_hash:?}` \
+                                 or replace it by resubmitting its nonce with a higher gas price."
+                            ),
+                        }
                     }
                 }
             }
@@ -639,16 +692,23 @@ where
 
         // Allow to fail silently
         let _ = receipt.update_revert_reason(&self.provider).await;
-
-        Ok(if let Some(ref field) = field {
-            get_pretty_tx_receipt_attr(&receipt, field)
-                .ok_or_else(|| eyre::eyre!("invalid receipt field: {}", field))?
+        let reverted = receipt.is_failure().unwrap_or(false);
+
+        let out = if let Some(ref field) = field {
+            get_pretty_tx_receipt_attr(&receipt, field).ok_or_else(|| {
+                eyre::eyre!(
+                    "invalid receipt field `{field}`. Valid fields are: {}",
+                    RECEIPT_FIELDS.join(", ")
+                )
+            })?
         } else if to_json {
             // to_value first to sort json object keys
             serde_json::to_value(&receipt)?.to_string()
         } else {
             receipt.pretty()
-        })
+        };
+
+        Ok((out, reverted))
     }
 
     /// Perform a raw JSON-RPC request
@@ -1317,6 +1377,10 @@ impl SimpleCast {
     /// For value types v, slot number of v is keccak256(concat(h(v) , p)) where h is the padding
     /// function and p is slot number of the mapping.
     ///
+    /// `string` and `bytes` keys are dynamic: per Solidity's storage layout rules, `h(k)` for
+    /// those is the *unpadded* key bytes rather than an ABI-encoded (offset + length + data)
+    /// representation, so they're hashed separately from the other (fixed-size) key types.
+    ///
     /// # Example
     ///
     /// ```
@@ -1326,14 +1390,108 @@ impl SimpleCast {
     ///
     ///    assert_eq!(Cast::index("address", "0xD0074F4E6490ae3f888d1d4f7E3E43326bD3f0f5" ,"2").unwrap().as_str(),"0x9525a448a9000053a4d151336329d6563b7e80b24f8e628e95527f218e8ab5fb");
     ///    assert_eq!(Cast::index("uint256","42" ,"6").unwrap().as_str(),"0xfc808b0f31a1e6b9cf25ff6289feae9b51017b392cc8e25620a94a38dcdafcc1");
+    ///    assert_eq!(Cast::index("string", "hello", "3").unwrap().as_str(), "0x963a4c0d01b136d7a32fcf2a069eced58a33a0b6ef6c92ca6b7eb61e2282c309");
     /// #    Ok(())
     /// # }
     /// ```
     pub fn index(from_type: &str, from_value: &str, slot_number: &str) -> Result<String> {
-        let sig = format!("x({from_type},uint256)");
-        let encoded = Self::abi_encode(&sig, &[from_value, slot_number])?;
-        let location: String = Self::keccak(&encoded)?;
-        Ok(location)
+        match from_type {
+            "string" | "bytes" => {
+                let mut bytes = if from_type == "bytes" {
+                    hex::decode(strip_0x(from_value))?
+                } else {
+                    from_value.as_bytes().to_vec()
+                };
+                let padded_slot = Self::abi_encode("x(uint256)", &[slot_number])?;
+                bytes.extend_from_slice(&hex::decode(strip_0x(&padded_slot))?);
+                Self::keccak(&format!("0x{}", hex::encode(bytes)))
+            }
+            _ => {
+                let sig = format!("x({from_type},uint256)");
+                let encoded = Self::abi_encode(&sig, &[from_value, slot_number])?;
+                let location: String = Self::keccak(&encoded)?;
+                Ok(location)
+            }
+        }
+    }
+
+    /// Computes the storage slot for the `i`th element of a dynamic array whose length slot is
+    /// `slot_number`, per Solidity's rule `keccak256(p) + i * element_size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cast::SimpleCast as Cast;
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    ///    assert_eq!(
+    ///        Cast::index_array("0", "0", None).unwrap().as_str(),
+    ///        "0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563"
+    ///    );
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn index_array(
+        slot_number: &str,
+        index: &str,
+        element_size: Option<&str>,
+    ) -> Result<String> {
+        let element_size: U256 = match element_size {
+            Some(size) => U256::from_dec_str(size).wrap_err("invalid element size")?,
+            None => U256::one(),
+        };
+        let index: U256 = U256::from_dec_str(index).wrap_err("invalid index")?;
+
+        let padded_slot = Self::abi_encode("x(uint256)", &[slot_number])?;
+        let base = Self::keccak(&padded_slot)?;
+        let base: U256 = U256::from_str_radix(strip_0x(&base), 16)?;
+
+        let slot = base + index * element_size;
+        Ok(format!("{:#066x}", slot))
+    }
+
+    /// Disassembles bytecode into one opcode per line, using the same opcode table as the
+    /// debugger so the two never diverge. If the bytecode ends with a Solidity metadata CBOR
+    /// blob, it's split off and its compiler version / content hash are printed separately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cast::SimpleCast as Cast;
+    ///
+    /// # fn main() -> eyre::Result<()> {
+    ///     assert_eq!(
+    ///         "00000000: PUSH1 0x01\n00000002: JUMPDEST\n00000003: STOP\n",
+    ///         Cast::disassemble("0x60015b00")?.as_str()
+    ///     );
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn disassemble(code: &str) -> Result<String> {
+        let bytes = hex::decode(strip_0x(code))?;
+        let (runtime, metadata) = crate::metadata::split_metadata(&bytes);
+
+        let mut out = String::new();
+        for op in foundry_evm::disas::disassemble(runtime, foundry_evm::revm::SpecId::LATEST) {
+            out.push_str(&format!("{:08x}: {}", op.pc, op.mnemonic));
+            if let Some(push_data) = &op.push_data {
+                out.push_str(&format!(" 0x{}", hex::encode(push_data)));
+            }
+            out.push('\n');
+        }
+
+        if let Some(metadata) = metadata {
+            out.push('\n');
+            out.push_str("metadata:\n");
+            if let Some(solc_version) = &metadata.solc_version {
+                out.push_str(&format!("  solc: {solc_version}\n"));
+            }
+            if let Some((kind, hash)) = &metadata.hash {
+                out.push_str(&format!("  {kind}: {hash}\n"));
+            }
+        }
+
+        Ok(out)
     }
 
     /// Converts ENS names to their namehash representation
@@ -1486,12 +1644,14 @@ impl SimpleCast {
         etherscan_api_key: String,
     ) -> Result<String> {
         let client = Client::new(chain, etherscan_api_key)?;
-        let metadata = client.contract_source_code(contract_address.parse()?).await?;
+        let address = contract_address.parse()?;
+        let metadata = Self::fetch_etherscan_metadata(&client, address, chain).await?;
         Ok(metadata.source_code())
     }
 
     /// Fetches the source code of verified contracts from etherscan and expands the resulting
-    /// files to a directory for easy perusal.
+    /// files to a directory for easy perusal, alongside a `foundry.toml` compiler-settings
+    /// snippet derived from the verified metadata.
     ///
     /// # Example
     ///
@@ -1512,17 +1672,72 @@ impl SimpleCast {
         output_directory: PathBuf,
     ) -> eyre::Result<()> {
         let client = Client::new(chain, etherscan_api_key)?;
-        let meta = client.contract_source_code(contract_address.parse()?).await?;
+        let address = contract_address.parse()?;
+        let meta = Self::fetch_etherscan_metadata(&client, address, chain).await?;
+
         let source_tree = meta.source_tree();
         source_tree.write_to(&output_directory)?;
+
+        if let Some(item) = meta.items.first() {
+            let settings = format!(
+                "# Generated from the verified compiler settings of {address:?} on {chain}.\n\
+                 [profile.default]\n\
+                 solc = \"{}\"\n\
+                 optimizer = {}\n\
+                 optimizer_runs = {}\n\
+                 evm_version = \"{}\"\n",
+                item.compiler_version.trim_start_matches('v'),
+                item.optimization_used != 0,
+                item.runs,
+                item.evm_version.to_lowercase(),
+            );
+            foundry_common::fs::write(output_directory.join("foundry.toml"), settings)?;
+        }
+
         Ok(())
     }
+
+    /// Fetches the verified contract metadata for `address`, translating the underlying
+    /// Etherscan client errors into clear, actionable messages.
+    async fn fetch_etherscan_metadata(
+        client: &Client,
+        address: ethers_core::types::Address,
+        chain: Chain,
+    ) -> Result<ethers_etherscan::contract::ContractMetadata> {
+        match client.contract_source_code(address).await {
+            Ok(metadata) => Ok(metadata),
+            Err(EtherscanError::InvalidApiKey) => {
+                eyre::bail!("Invalid Etherscan API key. Did you set it correctly? You may be using an API key for another Etherscan API chain (e.g. Etherscan API key for Polygonscan).")
+            }
+            Err(EtherscanError::ContractCodeNotVerified(address)) => {
+                eyre::bail!("Contract source code at {:?} on {} not verified. Maybe you have selected the wrong chain?", address, chain)
+            }
+            Err(EtherscanError::RateLimitExceeded) => {
+                eyre::bail!("Etherscan rate limit exceeded. Please wait a moment and try again, or set ETHERSCAN_API_KEY to raise your rate limit.")
+            }
+            Err(err) => {
+                eyre::bail!(err)
+            }
+        }
+    }
 }
 
 fn strip_0x(s: &str) -> &str {
     s.strip_prefix("0x").unwrap_or(s)
 }
 
+/// Re-renders a single extracted field as hex if it's a decimal number, leaving already-hex
+/// fields (addresses, hashes, bytes) untouched. No-op when `hex` is `false`.
+fn to_hex_if(value: String, hex: bool) -> String {
+    if !hex {
+        return value
+    }
+    match U256::from_dec_str(&value) {
+        Ok(n) => format!("{n:#x}"),
+        Err(_) => value,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SimpleCast as Cast;
@@ -0,0 +1,184 @@
+//! Splits the Solidity compiler metadata tail off the end of deployed bytecode and decodes it.
+//!
+//! solc appends a CBOR-encoded map to every contract's runtime bytecode, followed by a 2-byte
+//! big-endian length prefix for that map. See the Solidity docs on the "Contract Metadata" for
+//! the exact format: <https://docs.soliditylang.org/en/latest/metadata.html#encoding-of-the-metadata-hash-in-the-bytecode>
+
+/// The decoded contents of a Solidity metadata CBOR map that are useful to print: the compiler
+/// version and whichever content hash (`ipfs`, `bzzr1`, or `bzzr0`) is present.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// The `solc` compiler version, formatted as `major.minor.patch` when it was encoded as the
+    /// usual 3-byte version, or left as a hex dump otherwise.
+    pub solc_version: Option<String>,
+    /// The `ipfs`/`bzzr1`/`bzzr0` content hash, hex-encoded (not base58-decoded).
+    pub hash: Option<(&'static str, String)>,
+}
+
+/// Splits `code` into `(runtime_code, metadata)` if its tail looks like a Solidity metadata CBOR
+/// map; otherwise returns `(code, None)` unchanged.
+pub fn split_metadata(code: &[u8]) -> (&[u8], Option<Metadata>) {
+    if code.len() < 2 {
+        return (code, None)
+    }
+
+    let len = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+    if len == 0 || len + 2 > code.len() {
+        return (code, None)
+    }
+
+    let cbor = &code[code.len() - 2 - len..code.len() - 2];
+    match decode_metadata_map(cbor) {
+        Some(metadata) => (&code[..code.len() - 2 - len], Some(metadata)),
+        None => (code, None),
+    }
+}
+
+/// Decodes a top-level CBOR map of the shape solc emits: string keys, with byte-string values
+/// (or a boolean for `experimental`). Anything else causes decoding to bail out with `None`,
+/// since it's not metadata we recognize.
+fn decode_metadata_map(bytes: &[u8]) -> Option<Metadata> {
+    let mut cursor = Cursor { bytes, pos: 0 };
+    let entries = cursor.read_map_len()?;
+
+    let mut metadata = Metadata::default();
+    for _ in 0..entries {
+        let key = cursor.read_text_string()?;
+        match key.as_str() {
+            "solc" => {
+                let value = cursor.read_byte_string()?;
+                metadata.solc_version = Some(if value.len() == 3 {
+                    format!("{}.{}.{}", value[0], value[1], value[2])
+                } else {
+                    format!("0x{}", hex::encode(value))
+                });
+            }
+            "ipfs" => {
+                metadata.hash =
+                    Some(("ipfs", format!("0x{}", hex::encode(cursor.read_byte_string()?))))
+            }
+            "bzzr1" => {
+                metadata.hash =
+                    Some(("bzzr1", format!("0x{}", hex::encode(cursor.read_byte_string()?))))
+            }
+            "bzzr0" => {
+                metadata.hash =
+                    Some(("bzzr0", format!("0x{}", hex::encode(cursor.read_byte_string()?))))
+            }
+            "experimental" => {
+                cursor.skip_bool()?;
+            }
+            _ => return None,
+        }
+    }
+
+    // Not all bytes need to be consumed (solc sometimes pads), but the map itself must have
+    // decoded successfully to be considered real metadata.
+    Some(metadata)
+}
+
+/// A tiny, read-only cursor over the handful of CBOR constructs solc's metadata encoder uses:
+/// maps, text strings, byte strings, and booleans. This is not a general-purpose CBOR decoder.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn next_byte(&mut self) -> Option<u8> {
+        let byte = *self.bytes.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    /// Reads a CBOR unsigned integer argument (the `length` encoded after a type byte).
+    fn read_length(&mut self, initial_byte: u8) -> Option<usize> {
+        let info = initial_byte & 0x1f;
+        match info {
+            0..=23 => Some(info as usize),
+            24 => self.next_byte().map(|b| b as usize),
+            25 => {
+                let hi = self.next_byte()? as usize;
+                let lo = self.next_byte()? as usize;
+                Some((hi << 8) | lo)
+            }
+            _ => None, // solc never emits lengths large enough to need a 4- or 8-byte argument
+        }
+    }
+
+    fn read_map_len(&mut self) -> Option<usize> {
+        let byte = self.next_byte()?;
+        if byte & 0xe0 != 0xa0 {
+            return None
+        }
+        self.read_length(byte)
+    }
+
+    fn read_text_string(&mut self) -> Option<String> {
+        let byte = self.next_byte()?;
+        if byte & 0xe0 != 0x60 {
+            return None
+        }
+        let len = self.read_length(byte)?;
+        let bytes = self.bytes.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+
+    fn read_byte_string(&mut self) -> Option<&'a [u8]> {
+        let byte = self.next_byte()?;
+        if byte & 0xe0 != 0x40 {
+            return None
+        }
+        let len = self.read_length(byte)?;
+        let bytes = self.bytes.get(self.pos..self.pos + len)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn skip_bool(&mut self) -> Option<()> {
+        match self.next_byte()? {
+            0xf4 | 0xf5 => Some(()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_solc_and_ipfs_metadata() {
+        // {"ipfs": h'1220' ++ 32 zero bytes, "solc": h'000811'} (solc 0.8.17), CBOR-encoded by
+        // hand in the same order real solc output uses, followed by the 2-byte length prefix.
+        let mut cbor = vec![0xa2]; // map(2)
+        cbor.extend([0x64]); // text(4)
+        cbor.extend(b"ipfs");
+        cbor.extend([0x58, 0x22]); // bytes(34)
+        cbor.extend([0x12, 0x20]);
+        cbor.extend([0u8; 32]);
+        cbor.extend([0x64]); // text(4)
+        cbor.extend(b"solc");
+        cbor.extend([0x43]); // bytes(3)
+        cbor.extend([0, 8, 17]);
+
+        let mut code = vec![0xfe]; // a single INVALID opcode as the "runtime code"
+        code.extend(&cbor);
+        code.extend((cbor.len() as u16).to_be_bytes());
+
+        let (runtime, metadata) = split_metadata(&code);
+        assert_eq!(runtime, &[0xfe]);
+        let metadata = metadata.unwrap();
+        assert_eq!(metadata.solc_version, Some("0.8.17".to_string()));
+        assert_eq!(metadata.hash.unwrap().0, "ipfs");
+    }
+
+    #[test]
+    fn leaves_code_without_metadata_untouched() {
+        let code = [0x60, 0x01, 0x00];
+        let (runtime, metadata) = split_metadata(&code);
+        assert_eq!(runtime, &code);
+        assert!(metadata.is_none());
+    }
+}
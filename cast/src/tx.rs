@@ -2,13 +2,16 @@ use crate::errors::FunctionSignatureError;
 use ethers_core::{
     abi::Function,
     types::{
-        transaction::eip2718::TypedTransaction, Eip1559TransactionRequest, NameOrAddress,
-        TransactionRequest, H160, U256,
+        transaction::{eip2718::TypedTransaction, eip2930::AccessList},
+        Eip1559TransactionRequest, NameOrAddress, TransactionRequest, H160, U256,
     },
 };
 use ethers_providers::Middleware;
-use eyre::{eyre, Result};
-use foundry_common::abi::{encode_args, get_func, get_func_etherscan};
+use eyre::Result;
+use foundry_common::{
+    abi::{encode_args, get_func, get_func_etherscan},
+    ens::resolve_ens,
+};
 use foundry_config::Chain;
 use futures::future::join_all;
 
@@ -21,6 +24,7 @@ pub struct TxBuilder<'a, M: Middleware> {
     func: Option<Function>,
     etherscan_api_key: Option<String>,
     provider: &'a M,
+    no_ens: bool,
 }
 
 pub type TxBuilderOutput = (TypedTransaction, Option<Function>);
@@ -32,7 +36,7 @@ pub type TxBuilderPeekOutput<'a> = (&'a TypedTransaction, &'a Option<Function>);
 ///   use ethers_core::types::{Chain, U256};
 ///   use cast::TxBuilder;
 ///   let provider = ethers_providers::test_provider::MAINNET.provider();
-///   let mut builder = TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false).await?;
+///   let mut builder = TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false, false).await?;
 ///   builder
 ///       .gas(Some(U256::from(1)));
 ///   let (tx, _) = builder.build();
@@ -46,15 +50,20 @@ impl<'a, M: Middleware> TxBuilder<'a, M> {
     /// `to` - `to`. Could be a ENS
     /// `chain` - chain to construct the tx for
     /// `legacy` - use type 1 transaction
+    /// `no_ens` - reject `from`/`to` ENS names instead of resolving them, for untrusted input
     pub async fn new<F: Into<NameOrAddress>, T: Into<NameOrAddress>>(
         provider: &'a M,
         from: F,
         to: Option<T>,
         chain: impl Into<Chain>,
         legacy: bool,
-    ) -> Result<TxBuilder<'a, M>> {
+        no_ens: bool,
+    ) -> Result<TxBuilder<'a, M>>
+    where
+        M::Error: 'static,
+    {
         let chain = chain.into();
-        let from_addr = resolve_ens(provider, from).await?;
+        let from_addr = resolve_ens(provider, from, no_ens).await?;
 
         let mut tx: TypedTransaction = if chain.is_legacy() || legacy {
             TransactionRequest::new().from(from_addr).chain_id(chain.id()).into()
@@ -63,15 +72,18 @@ impl<'a, M: Middleware> TxBuilder<'a, M> {
         };
 
         let to_addr = if let Some(to) = to {
-            let addr =
-                resolve_ens(provider, foundry_utils::resolve_addr(to, chain.try_into().ok())?)
-                    .await?;
+            let addr = resolve_ens(
+                provider,
+                foundry_utils::resolve_addr(to, chain.try_into().ok())?,
+                no_ens,
+            )
+            .await?;
             tx.set_to(addr);
             Some(addr)
         } else {
             None
         };
-        Ok(Self { to: to_addr, chain, tx, func: None, etherscan_api_key: None, provider })
+        Ok(Self { to: to_addr, chain, tx, func: None, etherscan_api_key: None, provider, no_ens })
     }
 
     /// Set gas for tx
@@ -165,6 +177,20 @@ impl<'a, M: Middleware> TxBuilder<'a, M> {
         self
     }
 
+    /// Set the access list, e.g. one generated by `cast access-list`
+    pub fn set_access_list(&mut self, v: AccessList) -> &mut Self {
+        self.tx.set_access_list(v);
+        self
+    }
+
+    /// Set the access list, if `v` is not None
+    pub fn access_list(&mut self, v: Option<AccessList>) -> &mut Self {
+        if let Some(value) = v {
+            self.set_access_list(value);
+        }
+        self
+    }
+
     pub async fn create_args(
         &mut self,
         sig: &str,
@@ -246,15 +272,6 @@ impl<'a, M: Middleware> TxBuilder<'a, M> {
     }
 }
 
-async fn resolve_ens<M: Middleware, T: Into<NameOrAddress>>(provider: &M, addr: T) -> Result<H160> {
-    let from_addr = match addr.into() {
-        NameOrAddress::Name(ref ens_name) => provider.resolve_name(ens_name).await,
-        NameOrAddress::Address(addr) => Ok(addr),
-    }
-    .map_err(|x| eyre!("Failed to resolve ENS name: {x}"))?;
-    Ok(from_addr)
-}
-
 async fn resolve_name_args<M: Middleware>(args: &[String], provider: &M) -> Vec<String> {
     join_all(args.iter().map(|arg| async {
         if arg.contains('.') {
@@ -315,6 +332,7 @@ mod tests {
             match ens_name {
                 "a.eth" => Ok(H160::from_str(ADDR_1).unwrap()),
                 "b.eth" => Ok(H160::from_str(ADDR_2).unwrap()),
+                "unregistered.eth" => Ok(Address::zero()),
                 _ => unreachable!("don't know how to resolve {ens_name}"),
             }
         }
@@ -323,7 +341,7 @@ mod tests {
     async fn builder_new_non_legacy() -> eyre::Result<()> {
         let provider = MyProvider {};
         let builder =
-            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false).await?;
+            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false, false).await?;
         let (tx, args) = builder.build();
         assert_eq!(*tx.from().unwrap(), H160::from_str(ADDR_1).unwrap());
         assert_eq!(*tx.to().unwrap(), NameOrAddress::Address(H160::from_str(ADDR_2).unwrap()));
@@ -342,7 +360,7 @@ mod tests {
     async fn builder_new_legacy() -> eyre::Result<()> {
         let provider = MyProvider {};
         let builder =
-            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, true).await?;
+            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, true, false).await?;
         // don't check anything other than the tx type - the rest is covered in the non-legacy case
         let (tx, _) = builder.build();
         match tx {
@@ -354,11 +372,38 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn builder_new_rejects_ens_with_no_ens() {
+        let provider = MyProvider {};
+        let err = TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false, true)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("--no-ens"));
+    }
+
+    #[tokio::test]
+    async fn builder_new_reports_unregistered_ens_name() {
+        let provider = MyProvider {};
+        let err = TxBuilder::new(
+            &provider,
+            "unregistered.eth",
+            Some("b.eth"),
+            Chain::Mainnet,
+            false,
+            false,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("is not registered"));
+    }
+
     #[tokio::test]
     async fn builder_fields() -> eyre::Result<()> {
         let provider = MyProvider {};
         let mut builder =
-            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false).await.unwrap();
+            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false, false)
+                .await
+                .unwrap();
         builder
             .gas(Some(U256::from(12u32)))
             .gas_price(Some(U256::from(34u32)))
@@ -380,7 +425,9 @@ mod tests {
     async fn builder_args() -> eyre::Result<()> {
         let provider = MyProvider {};
         let mut builder =
-            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false).await.unwrap();
+            TxBuilder::new(&provider, "a.eth", Some("b.eth"), Chain::Mainnet, false, false)
+                .await
+                .unwrap();
         builder.args(Some(("what_a_day(int)", vec![String::from("31337")]))).await?;
         let (_, function_maybe) = builder.build();
 
@@ -7,6 +7,7 @@ use ethers::{
     providers::Middleware,
     types::{Address, I256, U256},
 };
+use eyre::WrapErr;
 use foundry_cli::{
     cmd::Cmd,
     handler,
@@ -18,8 +19,9 @@ use foundry_common::{
     abi::{format_tokens, get_event},
     fs,
     selectors::{
-        decode_calldata, decode_event_topic, decode_function_selector, import_selectors,
-        parse_signatures, pretty_calldata, ParsedSignatures, SelectorImportData,
+        decode_calldata_with_cache, decode_event_topic_with_cache,
+        decode_function_selector_with_cache, import_selectors, parse_signatures,
+        ParsedSignatures, SelectorImportData,
     },
     try_get_http_provider,
 };
@@ -178,14 +180,7 @@ async fn main() -> eyre::Result<()> {
             println!("{}", SimpleCast::calldata_encode(sig, &args)?);
         }
         Subcommands::Interface(cmd) => cmd.run()?.await?,
-        Subcommands::PrettyCalldata { calldata, offline } => {
-            if !calldata.starts_with("0x") {
-                eprintln!("Expected calldata hex string, received \"{calldata}\"");
-                std::process::exit(0)
-            }
-            let pretty_data = pretty_calldata(&calldata, offline).await?;
-            println!("{pretty_data}");
-        }
+        Subcommands::PrettyCalldata(cmd) => cmd.run()?.await?,
         Subcommands::Sig { sig } => {
             let selector = HumanReadableParser::parse_function(&sig)?.short_signature();
             println!("0x{}", hex::encode(selector));
@@ -203,7 +198,8 @@ async fn main() -> eyre::Result<()> {
             };
 
             let mut builder =
-                TxBuilder::new(&provider, config.sender, Some(address), chain, false).await?;
+                TxBuilder::new(&provider, config.sender, Some(address), chain, false, eth.no_ens)
+                    .await?;
             builder.set_args(&sig, args).await?;
             let builder_output = builder.peek();
 
@@ -222,19 +218,18 @@ async fn main() -> eyre::Result<()> {
             let provider = try_get_http_provider(rpc_url)?;
             println!("{}", Cast::new(provider).balance(who, block).await?);
         }
-        Subcommands::BaseFee { block, rpc_url } => {
+        Subcommands::BaseFee { block, rpc_url, gwei, to_json } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
 
             let provider = try_get_http_provider(rpc_url)?;
-            println!(
-                "{}",
-                Cast::new(provider).base_fee(block.unwrap_or(BlockId::Number(Latest))).await?
-            );
+            let base_fee =
+                Cast::new(provider).base_fee(block.unwrap_or(BlockId::Number(Latest))).await?;
+            println!("{}", format_gas_price(base_fee, gwei, to_json)?);
         }
-        Subcommands::Block { rpc_url, block, full, field, to_json } => {
+        Subcommands::Block { rpc_url, block, full, field, to_json, hex } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
             let provider = try_get_http_provider(rpc_url)?;
-            println!("{}", Cast::new(provider).block(block, full, field, to_json).await?);
+            println!("{}", Cast::new(provider).block(block, full, field, to_json, hex).await?);
         }
         Subcommands::BlockNumber { rpc_url } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
@@ -258,10 +253,13 @@ async fn main() -> eyre::Result<()> {
             let provider = try_get_http_provider(rpc_url)?;
             println!("{}", provider.client_version().await?);
         }
-        Subcommands::Code { block, who, rpc_url } => {
+        Subcommands::Code { block, who, rpc_url, disassemble } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
             let provider = try_get_http_provider(rpc_url)?;
-            println!("{}", Cast::new(provider).code(who, block).await?);
+            println!("{}", Cast::new(provider).code(who, block, disassemble).await?);
+        }
+        Subcommands::Disassemble { bytecode } => {
+            println!("{}", SimpleCast::disassemble(&bytecode)?);
         }
         Subcommands::ComputeAddress { rpc_url, address, nonce } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
@@ -272,30 +270,48 @@ async fn main() -> eyre::Result<()> {
             println!("Computed Address: {}", SimpleCast::to_checksum_address(&addr));
         }
         Subcommands::FindBlock(cmd) => cmd.run()?.await?,
-        Subcommands::GasPrice { rpc_url } => {
+        Subcommands::GasPrice { rpc_url, gwei, to_json } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
             let provider = try_get_http_provider(rpc_url)?;
-            println!("{}", Cast::new(provider).gas_price().await?);
+            let gas_price = Cast::new(provider).gas_price().await?;
+            println!("{}", format_gas_price(gas_price, gwei, to_json)?);
         }
+        Subcommands::FeeHistory(cmd) => cmd.run()?.await?,
         Subcommands::Index { key_type, key, slot_number } => {
             let encoded = SimpleCast::index(&key_type, &key, &slot_number)?;
             println!("{encoded}");
         }
+        Subcommands::IndexArray { slot_number, index, element_size } => {
+            let encoded = SimpleCast::index_array(&slot_number, &index, element_size.as_deref())?;
+            println!("{encoded}");
+        }
         Subcommands::Nonce { block, who, rpc_url } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
 
             let provider = try_get_http_provider(rpc_url)?;
             println!("{}", Cast::new(provider).nonce(who, block).await?);
         }
-        Subcommands::Proof { address, slots, rpc_url, block } => {
+        Subcommands::Proof { address, slots, rpc_url, block, verify } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
 
             let provider = try_get_http_provider(rpc_url)?;
             let value = provider.get_proof(address, slots, block).await?;
+
+            if verify {
+                let block = provider
+                    .get_block(block.unwrap_or(BlockId::Number(Latest)))
+                    .await?
+                    .ok_or_else(|| eyre::eyre!("could not fetch the block to verify against"))?;
+                cast::mpt::verify_eip1186_proof(block.state_root, &value)?;
+                println!("Proof verified against state root {:?}", block.state_root);
+            }
+
             println!("{}", serde_json::to_string(&value)?);
         }
         Subcommands::Rpc(cmd) => cmd.run()?.await?,
         Subcommands::Storage(cmd) => cmd.run().await?,
+        Subcommands::Logs(cmd) => cmd.run().await?,
+        Subcommands::Multicall(cmd) => cmd.run()?.await?,
 
         // Calls & transactions
         Subcommands::Call(cmd) => cmd.run().await?,
@@ -315,38 +331,56 @@ async fn main() -> eyre::Result<()> {
                 println!("{}", serde_json::json!(receipt));
             }
         }
-        Subcommands::Receipt { tx_hash, field, to_json, rpc_url, cast_async, confirmations } => {
+        Subcommands::Receipt {
+            tx_hash,
+            field,
+            to_json,
+            rpc_url,
+            cast_async,
+            confirmations,
+            timeout,
+        } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
             let provider = try_get_http_provider(rpc_url)?;
-            println!(
-                "{}",
-                Cast::new(provider)
-                    .receipt(tx_hash, field, confirmations, cast_async, to_json)
-                    .await?
-            );
+            let (receipt, reverted) = Cast::new(provider)
+                .receipt(tx_hash, field, confirmations, timeout, cast_async, to_json)
+                .await?;
+            println!("{receipt}");
+            if reverted {
+                std::process::exit(1)
+            }
         }
         Subcommands::Run(cmd) => cmd.run()?,
         Subcommands::SendTx(cmd) => cmd.run().await?,
-        Subcommands::Tx { rpc_url, tx_hash, field, to_json } => {
+        Subcommands::Tx { rpc_url, tx_hash, field, to_json, hex } => {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
             let provider = try_get_http_provider(rpc_url)?;
-            println!("{}", Cast::new(&provider).transaction(tx_hash, field, to_json).await?)
+            println!("{}", Cast::new(&provider).transaction(tx_hash, field, to_json, hex).await?)
         }
 
         // 4Byte
-        Subcommands::FourByte { selector } => {
-            let sigs = decode_function_selector(&selector).await?;
+        Subcommands::FourByte { selector, offline } => {
+            let sigs = decode_function_selector_with_cache(
+                &selector,
+                Config::foundry_cache_dir(),
+                offline,
+            )
+            .await?;
             sigs.iter().for_each(|sig| println!("{sig}"));
         }
-        Subcommands::FourByteDecode { calldata } => {
+        Subcommands::FourByteDecode { calldata, index, offline } => {
             let calldata = unwrap_or_stdin(calldata)?;
-            let sigs = decode_calldata(&calldata).await?;
+            let sigs =
+                decode_calldata_with_cache(&calldata, Config::foundry_cache_dir(), offline).await?;
             sigs.iter().enumerate().for_each(|(i, sig)| println!("{}) \"{sig}\"", i + 1));
 
-            let sig = match sigs.len() {
-                0 => Err(eyre::eyre!("No signatures found")),
-                1 => Ok(sigs.get(0).unwrap()),
-                _ => {
+            let sig = match (sigs.len(), index) {
+                (0, _) => Err(eyre::eyre!("No signatures found")),
+                (_, Some(index)) => sigs
+                    .get(index - 1)
+                    .ok_or_else(|| eyre::eyre!("Invalid signature index: {index}")),
+                (1, None) => Ok(sigs.get(0).unwrap()),
+                (_, None) => {
                     print!("Select a function signature by number: ");
                     io::stdout().flush()?;
                     let mut input = String::new();
@@ -361,8 +395,9 @@ async fn main() -> eyre::Result<()> {
 
             tokens.for_each(|t| println!("{t}"));
         }
-        Subcommands::FourByteEvent { topic } => {
-            let sigs = decode_event_topic(&topic).await?;
+        Subcommands::FourByteEvent { topic, offline } => {
+            let sigs =
+                decode_event_topic_with_cache(&topic, Config::foundry_cache_dir(), offline).await?;
             sigs.iter().for_each(|sig| println!("{sig}"));
         }
         Subcommands::UploadSignature { signatures } => {
@@ -397,7 +432,13 @@ async fn main() -> eyre::Result<()> {
             let rpc_url = try_consume_config_rpc_url(rpc_url)?;
             let provider = try_get_http_provider(rpc_url)?;
             let who = unwrap_or_stdin(who)?;
-            let address = provider.resolve_name(&who).await?;
+            let address = provider
+                .resolve_name(&who)
+                .await
+                .wrap_err_with(|| format!("failed to resolve ENS name `{who}`"))?;
+            if address.is_zero() {
+                eyre::bail!("ENS name `{who}` is not registered")
+            }
             if verify {
                 let name = provider.lookup_address(address).await?;
                 assert_eq!(
@@ -468,6 +509,22 @@ async fn main() -> eyre::Result<()> {
     Ok(())
 }
 
+/// Renders a wei-denominated fee value. Raw wei by default, so it stays a plain integer other
+/// tools and scripts can parse; human-readable gwei is opt-in (`--gwei`), and `--json` returns
+/// both as a `{"wei": ..., "gwei": ...}` object.
+fn format_gas_price(wei: U256, gwei: bool, to_json: bool) -> eyre::Result<String> {
+    if to_json {
+        let gwei = SimpleCast::from_wei(&wei.to_string(), "gwei")?;
+        Ok(serde_json::to_string_pretty(
+            &serde_json::json!({ "wei": wei.to_string(), "gwei": gwei }),
+        )?)
+    } else if gwei {
+        Ok(format!("{} gwei", SimpleCast::from_wei(&wei.to_string(), "gwei")?))
+    } else {
+        Ok(wei.to_string())
+    }
+}
+
 fn unwrap_or_stdin<T>(what: Option<T>) -> eyre::Result<T>
 where
     T: FromStr + Send + Sync,
@@ -4,17 +4,22 @@ use crate::{
         cast::{parse_block_id, parse_name_or_address},
         EthereumOpts, TransactionOpts,
     },
-    utils::parse_ether_value,
+    utils::{parse_ether_value, parse_u256},
 };
-use cast::{Cast, TxBuilder};
+use cast::{trace::identifier::SignaturesIdentifier, Cast, TxBuilder};
 use clap::Parser;
 use ethers::{
     providers::Middleware,
-    types::{Address, BlockId, NameOrAddress, U256},
+    types::{Address, BlockId, BlockNumber, NameOrAddress, U256},
 };
 use eyre::WrapErr;
+use forge::{
+    executor::{opts::EvmOpts, Backend, ExecutorBuilder},
+    trace::{identifier::EtherscanIdentifier, CallTraceDecoderBuilder, TraceKind},
+};
 use foundry_common::try_get_http_provider;
-use foundry_config::{Chain, Config};
+use foundry_config::{find_project_root_path, Chain, Config};
+use std::str::FromStr;
 
 #[derive(Debug, Parser)]
 pub struct CallArgs {
@@ -46,6 +51,21 @@ pub struct CallArgs {
     #[clap(long, short, help = "the block you want to query, can also be earliest/latest/pending", value_parser = parse_block_id, value_name = "BLOCK")]
     block: Option<BlockId>,
 
+    #[clap(
+        long,
+        help = "Executes the call on a local fork of the RPC and prints the decoded call trace instead of just the raw result."
+    )]
+    trace: bool,
+
+    #[clap(
+        long = "override",
+        help = "Overrides the state of an account before executing the call. Implies --trace.",
+        long_help = "Overrides the state of an account before executing the call. Implies --trace.\n\nFormat: <address>:<field>=<value>[,<field>=<value>...], where <field> is one of `balance`, `nonce`, `code`, or `slot:<slot>` for a storage slot.\n\nExample: 0x123...:balance=1ether,code=0x60,slot:0x0=0x1",
+        value_parser = parse_state_override,
+        value_name = "OVERRIDE"
+    )]
+    overrides: Vec<StateOverride>,
+
     #[clap(subcommand)]
     command: Option<CallSubcommands>,
 }
@@ -74,7 +94,7 @@ Examples: 1ether, 10gwei, 0.01ether"#,
 }
 impl CallArgs {
     pub async fn run(self) -> eyre::Result<()> {
-        let CallArgs { to, sig, args, data, tx, eth, command, block } = self;
+        let CallArgs { to, sig, args, data, tx, eth, command, block, trace, overrides } = self;
         let config = Config::from(&eth);
         let provider = try_get_http_provider(config.get_rpc_url_or_localhost_http()?)?;
 
@@ -82,7 +102,7 @@ impl CallArgs {
             if let Some(chain) = eth.chain { chain } else { provider.get_chainid().await?.into() };
 
         let from = eth.wallet.from.unwrap_or(Address::zero());
-        let mut builder = TxBuilder::new(&provider, from, to, chain, tx.legacy).await?;
+        let mut builder = TxBuilder::new(&provider, from, to, chain, tx.legacy, eth.no_ens).await?;
         builder
             .gas(tx.gas_limit)
             .etherscan_api_key(config.get_etherscan_api_key(Some(chain)))
@@ -118,11 +138,153 @@ impl CallArgs {
         };
 
         let builder_output = builder.build();
-        println!("{}", Cast::new(provider).call(builder_output, block).await?);
+
+        // The plain `eth_call` fast-path stays the default; tracing and state overrides both
+        // require executing the call ourselves on a local fork instead of delegating to the RPC.
+        if trace || !overrides.is_empty() {
+            run_with_trace(&config, chain, block, builder_output, overrides).await?;
+        } else {
+            println!("{}", Cast::new(provider).call(builder_output, block).await?);
+        }
         Ok(())
     }
 }
 
+/// A state override applied to the local fork before executing a traced call.
+#[derive(Debug, Clone)]
+struct StateOverride {
+    address: Address,
+    balance: Option<U256>,
+    nonce: Option<u64>,
+    code: Option<Vec<u8>>,
+    storage: Vec<(U256, U256)>,
+}
+
+/// Parses a single `--override` value, e.g. `0xabc...:balance=1ether,code=0x60,slot:0x0=0x1`.
+fn parse_state_override(s: &str) -> eyre::Result<StateOverride> {
+    let (addr, fields) =
+        s.split_once(':').ok_or_else(|| eyre::eyre!("invalid override `{s}`: missing `:`"))?;
+    let address = Address::from_str(addr).wrap_err_with(|| format!("invalid address `{addr}`"))?;
+
+    let mut override_ =
+        StateOverride { address, balance: None, nonce: None, code: None, storage: Vec::new() };
+
+    for field in fields.split(',') {
+        if let Some(slot_and_value) = field.strip_prefix("slot:") {
+            let (slot, value) = slot_and_value
+                .split_once('=')
+                .ok_or_else(|| eyre::eyre!("invalid storage override `{field}`"))?;
+            override_.storage.push((parse_u256(slot)?, parse_u256(value)?));
+            continue
+        }
+
+        let (key, value) =
+            field.split_once('=').ok_or_else(|| eyre::eyre!("invalid override field `{field}`"))?;
+        match key {
+            "balance" => override_.balance = Some(parse_ether_value(value)?),
+            "nonce" => override_.nonce = Some(value.parse()?),
+            "code" => {
+                override_.code = Some(hex::decode(value.strip_prefix("0x").unwrap_or(value))?)
+            }
+            other => eyre::bail!("unknown override field `{other}`"),
+        }
+    }
+
+    Ok(override_)
+}
+
+/// Executes the call on a local fork of `provider`, applying any state overrides, and prints the
+/// decoded call trace in addition to the call's return data.
+async fn run_with_trace(
+    config: &Config,
+    chain: Chain,
+    block: Option<BlockId>,
+    builder_output: cast::TxBuilderOutput,
+    overrides: Vec<StateOverride>,
+) -> eyre::Result<()> {
+    let (tx, func) = builder_output;
+
+    let figment = Config::figment_with_root(find_project_root_path().unwrap());
+    let mut evm_opts = figment.extract::<EvmOpts>()?;
+    evm_opts.fork_url = Some(config.get_rpc_url_or_localhost_http()?.into_owned());
+    evm_opts.fork_block_number = match block {
+        Some(BlockId::Number(BlockNumber::Number(n))) => Some(n.as_u64()),
+        _ => None,
+    };
+
+    let env = evm_opts.evm_env().await;
+    let db = Backend::spawn(evm_opts.get_fork(config, env.clone()));
+
+    let builder = ExecutorBuilder::default()
+        .with_config(env)
+        .with_spec(crate::utils::evm_spec(&config.evm_version));
+    let mut executor = builder.build(db);
+    executor.set_tracing(true);
+
+    for override_ in overrides {
+        if let Some(balance) = override_.balance {
+            executor.set_balance(override_.address, balance)?;
+        }
+        if let Some(nonce) = override_.nonce {
+            executor.set_nonce(override_.address, nonce)?;
+        }
+        if let Some(code) = override_.code {
+            executor.set_code(override_.address, code.into())?;
+        }
+        for (slot, value) in override_.storage {
+            executor.set_storage(override_.address, slot, value)?;
+        }
+    }
+
+    let from = tx.from().copied().unwrap_or_default();
+    let to = match tx.to() {
+        Some(NameOrAddress::Address(addr)) => *addr,
+        _ => eyre::bail!("must provide a destination address for `cast call --trace`"),
+    };
+    let calldata = tx.data().cloned().unwrap_or_default();
+    let value = tx.value().copied().unwrap_or_default();
+
+    let call_result = executor.call_raw(from, to, calldata.0.into(), value)?;
+    let mut traces = vec![(TraceKind::Execution, call_result.traces.clone().unwrap_or_default())];
+
+    let mut etherscan_identifier = EtherscanIdentifier::new(config, Some(chain))?;
+    let mut decoder = CallTraceDecoderBuilder::new().build();
+    decoder.add_signature_identifier(SignaturesIdentifier::new(
+        Config::foundry_cache_dir(),
+        config.offline,
+    )?);
+
+    println!("Traces:");
+    for (_, trace) in &mut traces {
+        decoder.identify(trace, &mut etherscan_identifier);
+        decoder.decode(trace).await;
+        println!("{trace}");
+    }
+    println!();
+
+    if call_result.reverted {
+        let reason = forge::decode::decode_revert(
+            &call_result.result[..],
+            None,
+            Some(call_result.exit_reason),
+        )
+        .unwrap_or_else(|_| "unknown revert reason".to_string());
+        eyre::bail!("execution reverted: {reason}");
+    }
+
+    if let Some(func) = func {
+        let decoded = func.decode_output(call_result.result.as_ref())?;
+        println!(
+            "{}",
+            decoded.iter().map(foundry_common::abi::format_token).collect::<Vec<_>>().join("\n")
+        );
+    } else {
+        println!("0x{}", hex::encode(&call_result.result));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +315,28 @@ mod tests {
 
         assert!(args.is_err());
     }
+
+    #[test]
+    fn can_parse_state_override() {
+        let addr = Address::zero();
+        let args: CallArgs = CallArgs::parse_from([
+            "foundry-cli",
+            "--override",
+            &format!("{addr:?}:balance=1ether,code=0x60,slot:0x0=0x1"),
+        ]);
+        assert_eq!(args.overrides.len(), 1);
+        let override_ = &args.overrides[0];
+        assert_eq!(override_.address, addr);
+        assert_eq!(override_.balance, Some(U256::from(10).pow(U256::from(18))));
+        assert_eq!(override_.code, Some(vec![0x60]));
+        assert_eq!(override_.storage, vec![(U256::zero(), U256::one())]);
+    }
+
+    #[test]
+    fn override_rejects_unknown_field() {
+        let addr = Address::zero();
+        let result =
+            CallArgs::try_parse_from(["foundry-cli", "--override", &format!("{addr:?}:nope=1")]);
+        assert!(result.is_err());
+    }
 }
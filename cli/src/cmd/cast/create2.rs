@@ -11,7 +11,11 @@ use ethers::{
 use eyre::{Result, WrapErr};
 use rayon::prelude::*;
 use regex::RegexSetBuilder;
-use std::{str::FromStr, time::Instant};
+use std::{
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Instant,
+};
 
 /// CLI arguments for `cast create2`.
 #[derive(Debug, Clone, Parser)]
@@ -59,6 +63,13 @@ pub struct Create2Args {
         value_name = "HEX"
     )]
     init_code_hash: Option<String>,
+    #[clap(
+        short,
+        long,
+        help = "Number of threads to use for the vanity search. Defaults to the number of logical CPUs.",
+        value_name = "NUM"
+    )]
+    jobs: Option<usize>,
 }
 
 #[allow(dead_code)]
@@ -85,6 +96,7 @@ impl Create2Args {
             deployer,
             init_code,
             init_code_hash,
+            jobs,
         } = self;
 
         let mut regexs = vec![];
@@ -139,35 +151,48 @@ impl Create2Args {
 
         println!("Starting to generate deterministic contract address...");
         let timer = Instant::now();
-        let (salt, addr) = std::iter::repeat(())
-            .par_bridge()
-            .map(|_| {
-                let salt = H256::random_using(&mut thread_rng());
-                let salt = Bytes::from(salt.to_fixed_bytes());
-
-                let addr = SimpleCast::to_checksum_address(&get_create2_address_from_hash(
-                    deployer,
-                    salt.clone(),
-                    init_code_hash,
-                ));
-
-                (salt, addr)
-            })
-            .find_any(move |(_, addr)| {
-                let addr = addr.to_string();
-                let addr = addr.strip_prefix("0x").unwrap();
-                regex.matches(addr).into_iter().count() == regex.patterns().len()
-            })
-            .unwrap();
+        let attempts = AtomicU64::new(0);
+        let attempts_ref = &attempts;
+
+        let find = move || {
+            std::iter::repeat(())
+                .par_bridge()
+                .map(move |_| {
+                    attempts_ref.fetch_add(1, Ordering::Relaxed);
+
+                    let salt = H256::random_using(&mut thread_rng());
+                    let salt = Bytes::from(salt.to_fixed_bytes());
+
+                    let addr = SimpleCast::to_checksum_address(&get_create2_address_from_hash(
+                        deployer,
+                        salt.clone(),
+                        init_code_hash,
+                    ));
+
+                    (salt, addr)
+                })
+                .find_any(move |(_, addr)| {
+                    let addr = addr.to_string();
+                    let addr = addr.strip_prefix("0x").unwrap();
+                    regex.matches(addr).into_iter().count() == regex.patterns().len()
+                })
+                .unwrap()
+        };
+
+        let (salt, addr) = if let Some(jobs) = jobs {
+            rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?.install(find)
+        } else {
+            find()
+        };
 
         let salt = U256::from(salt.to_vec().as_slice());
         let address = Address::from_str(&addr).unwrap();
 
+        let elapsed = timer.elapsed().as_secs_f64();
+        let rate = attempts.load(Ordering::Relaxed) as f64 / elapsed.max(f64::EPSILON);
+
         println!(
-            "Successfully found contract address in {} seconds.\nAddress: {}\nSalt: {}",
-            timer.elapsed().as_secs(),
-            addr,
-            salt
+            "Successfully found contract address in {elapsed:.1} seconds ({rate:.0} addr/s).\nAddress: {addr}\nSalt: {salt}",
         );
 
         Ok(Create2Output { address, salt })
@@ -1,14 +1,18 @@
 // cast estimate subcommands
 use crate::{
-    opts::{cast::parse_name_or_address, EthereumOpts},
+    opts::{
+        cast::{parse_block_id, parse_name_or_address},
+        EthereumOpts,
+    },
     utils::parse_ether_value,
 };
 use cast::{Cast, TxBuilder};
 use clap::Parser;
 use ethers::{
     providers::Middleware,
-    types::{Address, NameOrAddress, U256},
+    types::{Address, BlockId, NameOrAddress, U256},
 };
+use eyre::WrapErr;
 use foundry_common::try_get_http_provider;
 use foundry_config::{Chain, Config};
 
@@ -21,6 +25,14 @@ pub struct EstimateArgs {
     sig: Option<String>,
     #[clap(help = "The arguments of the function to call.", value_name = "ARGS")]
     args: Vec<String>,
+    #[clap(
+        long,
+        help = "Data for the transaction.",
+        value_name = "DATA",
+        value_parser = foundry_common::clap_helpers::strip_0x_prefix,
+        conflicts_with_all = &["sig", "args"]
+    )]
+    data: Option<String>,
     #[clap(
         long,
         help = "Ether to send in the transaction.",
@@ -31,6 +43,8 @@ Examples: 1ether, 10gwei, 0.01ether"#,
         value_name = "VALUE"
     )]
     value: Option<U256>,
+    #[clap(long, short, help = "the block you want to query, can also be earliest/latest/pending", value_parser = parse_block_id, value_name = "BLOCK")]
+    block: Option<BlockId>,
     #[clap(flatten)]
     // TODO: We only need RPC URL and Etherscan API key here.
     eth: EthereumOpts,
@@ -62,7 +76,7 @@ Examples: 1ether, 10gwei, 0.01ether"#,
 }
 impl EstimateArgs {
     pub async fn run(self) -> eyre::Result<()> {
-        let EstimateArgs { to, sig, args, value, eth, command } = self;
+        let EstimateArgs { to, sig, args, data, value, block, eth, command } = self;
         let config = Config::from(&eth);
         let provider = try_get_http_provider(config.get_rpc_url_or_localhost_http()?)?;
 
@@ -70,7 +84,7 @@ impl EstimateArgs {
             if let Some(chain) = eth.chain { chain } else { provider.get_chainid().await?.into() };
 
         let from = eth.wallet.from.unwrap_or(Address::zero());
-        let mut builder = TxBuilder::new(&provider, from, to, chain, false).await?;
+        let mut builder = TxBuilder::new(&provider, from, to, chain, false, eth.no_ens).await?;
         builder.etherscan_api_key(config.get_etherscan_api_key(Some(chain)));
         match command {
             Some(EstimateSubcommands::Create { code, sig, args, value }) => {
@@ -86,12 +100,22 @@ impl EstimateArgs {
                 builder.set_data(data);
             }
             _ => {
-                builder.value(value).set_args(sig.unwrap().as_str(), args).await?;
+                builder.value(value);
+
+                if let Some(sig) = sig {
+                    builder.set_args(sig.as_str(), args).await?;
+                }
+                if let Some(data) = data {
+                    // Note: `sig+args` and `data` are mutually exclusive
+                    builder.set_data(
+                        hex::decode(data).wrap_err("Expected hex encoded function data")?,
+                    );
+                }
             }
         };
 
         let builder_output = builder.peek();
-        let gas = Cast::new(&provider).estimate(builder_output).await?;
+        let gas = Cast::new(&provider).estimate(builder_output, block).await?;
         println!("{gas}");
         Ok(())
     }
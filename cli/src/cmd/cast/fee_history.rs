@@ -0,0 +1,102 @@
+//! cast fee-history subcommand
+
+use crate::{cmd::Cmd, opts::cast::parse_block_number, utils::try_consume_config_rpc_url};
+use cast::{Cast, SimpleCast};
+use clap::Parser;
+use comfy_table::{presets::ASCII_MARKDOWN, Table};
+use ethers::types::BlockNumber;
+use eyre::Result;
+use foundry_common::try_get_http_provider;
+use futures::future::BoxFuture;
+
+/// CLI arguments for `cast fee-history`.
+#[derive(Debug, Clone, Parser)]
+pub struct FeeHistoryArgs {
+    #[clap(
+        long,
+        short = 'b',
+        help = "The number of blocks to include, going backwards from --block.",
+        default_value = "10",
+        value_name = "BLOCKS"
+    )]
+    blocks: u64,
+
+    #[clap(
+        long,
+        help = "The newest block to include. Defaults to the latest block.",
+        value_parser = parse_block_number,
+        value_name = "BLOCK"
+    )]
+    block: Option<BlockNumber>,
+
+    #[clap(
+        long,
+        help = "Comma-separated list of reward percentiles to report priority fees for.",
+        value_delimiter = ',',
+        default_value = "25,50,75",
+        value_name = "PERCENTILES"
+    )]
+    percentiles: Vec<f64>,
+
+    #[clap(long, help = "Print fees in wei instead of gwei.", help_heading = "Display options")]
+    wei: bool,
+
+    #[clap(long = "json", short = 'j', help_heading = "Display options")]
+    to_json: bool,
+
+    #[clap(long, env = "ETH_RPC_URL", value_name = "URL")]
+    rpc_url: Option<String>,
+}
+
+impl Cmd for FeeHistoryArgs {
+    type Output = BoxFuture<'static, Result<()>>;
+
+    fn run(self) -> Result<Self::Output> {
+        Ok(Box::pin(Self::fee_history(self)))
+    }
+}
+
+impl FeeHistoryArgs {
+    async fn fee_history(self) -> Result<()> {
+        let FeeHistoryArgs { blocks, block, percentiles, wei, to_json, rpc_url } = self;
+        let rpc_url = try_consume_config_rpc_url(rpc_url)?;
+        let provider = try_get_http_provider(rpc_url)?;
+        let cast = Cast::new(provider);
+
+        let history =
+            cast.fee_history(blocks, block.unwrap_or(BlockNumber::Latest), &percentiles).await?;
+
+        if to_json {
+            println!("{}", serde_json::to_string_pretty(&history)?);
+            return Ok(())
+        }
+
+        let fmt = |wei_value: ethers::types::U256| -> Result<String> {
+            if wei {
+                Ok(wei_value.to_string())
+            } else {
+                Ok(format!("{} gwei", SimpleCast::from_wei(&wei_value.to_string(), "gwei")?))
+            }
+        };
+
+        let mut table = Table::new();
+        table.load_preset(ASCII_MARKDOWN);
+        let mut header = vec!["block".to_string(), "base fee".to_string()];
+        header.extend(percentiles.iter().map(|p| format!("{p}th percentile")));
+        table.set_header(header);
+
+        let oldest_block = history.oldest_block.as_u64();
+        for (i, base_fee) in history.base_fee_per_gas.iter().enumerate() {
+            let mut row = vec![(oldest_block + i as u64).to_string(), fmt(*base_fee)?];
+            if let Some(rewards) = history.reward.get(i) {
+                for reward in rewards {
+                    row.push(fmt(*reward)?);
+                }
+            }
+            table.add_row(row);
+        }
+
+        println!("{table}");
+        Ok(())
+    }
+}
@@ -2,17 +2,27 @@
 
 use crate::{cmd::Cmd, utils::try_consume_config_rpc_url};
 use cast::Cast;
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use clap::Parser;
 use ethers::prelude::*;
-use eyre::Result;
+use eyre::{Result, WrapErr};
 use foundry_common::try_get_http_provider;
-use futures::{future::BoxFuture, join};
+use futures::future::BoxFuture;
 
 /// CLI arguments for `cast find-block`.
 #[derive(Debug, Clone, Parser)]
 pub struct FindBlockArgs {
-    #[clap(help = "The UNIX timestamp to search for (in seconds)", value_name = "TIMESTAMP")]
+    #[clap(
+        help = "The timestamp to search for, either in unix seconds or as an ISO-8601 date/datetime (e.g. `2023-03-01` or `2023-03-01T00:00:00Z`).",
+        value_parser = parse_timestamp,
+        value_name = "TIMESTAMP"
+    )]
     timestamp: u64,
+    #[clap(
+        long,
+        help = "Find the earliest block at or after TIMESTAMP instead of the latest block at or before it."
+    )]
+    after: bool,
     #[clap(long, env = "ETH_RPC_URL", value_name = "URL")]
     rpc_url: Option<String>,
 }
@@ -21,72 +31,122 @@ impl Cmd for FindBlockArgs {
     type Output = BoxFuture<'static, Result<()>>;
 
     fn run(self) -> Result<Self::Output> {
-        let FindBlockArgs { timestamp, rpc_url } = self;
-        Ok(Box::pin(Self::query_block(timestamp, rpc_url)))
+        let FindBlockArgs { timestamp, after, rpc_url } = self;
+        Ok(Box::pin(Self::query_block(timestamp, after, rpc_url)))
     }
 }
 
 impl FindBlockArgs {
-    async fn query_block(timestamp: u64, rpc_url: Option<String>) -> Result<()> {
-        let ts_target = U256::from(timestamp);
+    async fn query_block(target: u64, after: bool, rpc_url: Option<String>) -> Result<()> {
         let rpc_url = try_consume_config_rpc_url(rpc_url)?;
-
         let provider = try_get_http_provider(rpc_url)?;
-        let last_block_num = provider.get_block_number().await?;
-        let cast_provider = Cast::new(provider);
-
-        let res = join!(cast_provider.timestamp(last_block_num), cast_provider.timestamp(1));
-        let ts_block_latest = res.0?;
-        let ts_block_1 = res.1?;
-
-        let block_num = if ts_block_latest.lt(&ts_target) {
-            // If the most recent block's timestamp is below the target, return it
-            last_block_num
-        } else if ts_block_1.gt(&ts_target) {
-            // If the target timestamp is below block 1's timestamp, return that
-            U64::from(1_u64)
+        let cast = Cast::new(provider);
+
+        let latest = cast.block_number().await?.as_u64();
+        let (block, timestamp) = if after {
+            find_first_at_or_after(&cast, target, latest).await?
         } else {
-            // Otherwise, find the block that is closest to the timestamp
-            let mut low_block = U64::from(1_u64); // block 0 has a timestamp of 0: https://github.com/ethereum/go-ethereum/issues/17042#issuecomment-559414137
-            let mut high_block = last_block_num;
-            let mut matching_block: Option<U64> = None;
-            while high_block.gt(&low_block) && matching_block.is_none() {
-                // Get timestamp of middle block (this approach approach to avoids overflow)
-                let high_minus_low_over_2 = high_block
-                    .checked_sub(low_block)
-                    .ok_or_else(|| eyre::eyre!("unexpected underflow"))
-                    .unwrap()
-                    .checked_div(U64::from(2_u64))
-                    .unwrap();
-                let mid_block = high_block.checked_sub(high_minus_low_over_2).unwrap();
-                let ts_mid_block = cast_provider.timestamp(mid_block).await?;
-
-                // Check if we've found a match or should keep searching
-                if ts_mid_block.eq(&ts_target) {
-                    matching_block = Some(mid_block)
-                } else if high_block.checked_sub(low_block).unwrap().eq(&U64::from(1_u64)) {
-                    // The target timestamp is in between these blocks. This rounds to the
-                    // highest block if timestamp is equidistant between blocks
-                    let res = join!(
-                        cast_provider.timestamp(high_block),
-                        cast_provider.timestamp(low_block)
-                    );
-                    let ts_high = res.0.unwrap();
-                    let ts_low = res.1.unwrap();
-                    let high_diff = ts_high.checked_sub(ts_target).unwrap();
-                    let low_diff = ts_target.checked_sub(ts_low).unwrap();
-                    let is_low = low_diff.lt(&high_diff);
-                    matching_block = if is_low { Some(low_block) } else { Some(high_block) }
-                } else if ts_mid_block.lt(&ts_target) {
-                    low_block = mid_block;
-                } else {
-                    high_block = mid_block;
-                }
-            }
-            matching_block.unwrap_or(low_block)
+            find_last_at_or_before(&cast, target, latest).await?
         };
-        println!("{block_num}");
 
+        println!("{block} (timestamp: {timestamp})");
         Ok(())
     }
 }
+
+async fn timestamp_of<M: Middleware>(cast: &Cast<M>, block: u64) -> Result<u64>
+where
+    M::Error: 'static,
+{
+    Ok(cast.timestamp(block).await?.as_u64())
+}
+
+/// Binary-searches `[0, latest]` for the latest block whose timestamp is `<= target`, making
+/// O(log latest) RPC calls. Block timestamps only need to be non-decreasing in block number for
+/// this to be correct - irregular gaps between them don't matter.
+async fn find_last_at_or_before<M: Middleware>(
+    cast: &Cast<M>,
+    target: u64,
+    latest: u64,
+) -> Result<(u64, u64)>
+where
+    M::Error: 'static,
+{
+    let genesis_ts = timestamp_of(cast, 0).await?;
+    if genesis_ts > target {
+        eyre::bail!("timestamp {target} is before the genesis block (timestamp {genesis_ts})")
+    }
+
+    let latest_ts = timestamp_of(cast, latest).await?;
+    if latest_ts <= target {
+        return Ok((latest, latest_ts))
+    }
+
+    let mut lo = 0u64;
+    let mut hi = latest;
+    while lo < hi {
+        let mid = lo + (hi - lo + 1) / 2;
+        if timestamp_of(cast, mid).await? <= target {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    let ts = timestamp_of(cast, lo).await?;
+    Ok((lo, ts))
+}
+
+/// Binary-searches `[0, latest]` for the earliest block whose timestamp is `>= target`, making
+/// O(log latest) RPC calls.
+async fn find_first_at_or_after<M: Middleware>(
+    cast: &Cast<M>,
+    target: u64,
+    latest: u64,
+) -> Result<(u64, u64)>
+where
+    M::Error: 'static,
+{
+    let latest_ts = timestamp_of(cast, latest).await?;
+    if latest_ts < target {
+        eyre::bail!("timestamp {target} is after the latest block {latest} (timestamp {latest_ts})")
+    }
+
+    let genesis_ts = timestamp_of(cast, 0).await?;
+    if genesis_ts >= target {
+        return Ok((0, genesis_ts))
+    }
+
+    let mut lo = 0u64;
+    let mut hi = latest;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if timestamp_of(cast, mid).await? >= target {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    let ts = timestamp_of(cast, lo).await?;
+    Ok((lo, ts))
+}
+
+/// Parses a timestamp argument as either unix seconds or an ISO-8601 date/datetime.
+fn parse_timestamp(s: &str) -> Result<u64> {
+    if let Ok(unix) = s.parse::<u64>() {
+        return Ok(unix)
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Ok(dt.timestamp() as u64)
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(dt.timestamp() as u64)
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        let dt = date.and_hms_opt(0, 0, 0).wrap_err("invalid date")?;
+        return Ok(dt.timestamp() as u64)
+    }
+
+    eyre::bail!("invalid timestamp `{s}`: expected unix seconds or an ISO-8601 date/datetime")
+}
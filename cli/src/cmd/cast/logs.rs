@@ -0,0 +1,223 @@
+use crate::{
+    opts::cast::{parse_block_number, parse_name_or_address},
+    utils::try_consume_config_rpc_url,
+};
+use clap::Parser;
+use ethers::{
+    abi::{ethabi::ethereum_types::BigEndianHash, Event, RawLog},
+    providers::Middleware,
+    types::{serde_helpers::Numeric, Address, BlockNumber, Filter, NameOrAddress, H256, U256},
+};
+use eyre::{Result, WrapErr};
+use foundry_common::{
+    abi::{format_token, get_event},
+    ens::resolve_ens,
+    try_get_http_provider, RetryProvider,
+};
+use std::{collections::HashSet, str::FromStr};
+
+/// The number of blocks queried per `eth_getLogs` call.
+///
+/// Most RPC providers reject `eth_getLogs` requests that span too wide a block range, so large
+/// `--from-block`/`--to-block` ranges are split into chunks of this size and queried one at a
+/// time.
+const BLOCK_RANGE_CHUNK_SIZE: u64 = 10_000;
+
+/// CLI arguments for `cast logs`.
+#[derive(Debug, Clone, Parser)]
+pub struct LogsArgs {
+    #[clap(
+        help = "The event signature, e.g. `Transfer(address indexed,address indexed,uint256)`.",
+        long_help = "The event signature, e.g. `Transfer(address indexed,address indexed,uint256)`.\n\nIf omitted, all logs matching the other filters are fetched and printed with raw topics and data instead of decoded parameters.",
+        value_name = "SIG"
+    )]
+    sig: Option<String>,
+
+    #[clap(
+        long,
+        help = "The contract address to filter logs by.",
+        value_parser = parse_name_or_address,
+        value_name = "ADDRESS"
+    )]
+    address: Option<NameOrAddress>,
+
+    #[clap(
+        long,
+        help = "The block height to start querying from.",
+        long_help = "The block height to start querying from. Can also be the tags earliest, latest, or pending.",
+        value_parser = parse_block_number,
+        value_name = "BLOCK"
+    )]
+    from_block: Option<BlockNumber>,
+
+    #[clap(
+        long,
+        help = "The block height to stop querying at.",
+        long_help = "The block height to stop querying at. Can also be the tags earliest, latest, or pending.",
+        value_parser = parse_block_number,
+        value_name = "BLOCK"
+    )]
+    to_block: Option<BlockNumber>,
+
+    #[clap(long, help = "The value of the 2nd indexed topic.", value_name = "TOPIC")]
+    topic1: Option<String>,
+    #[clap(long, help = "The value of the 3rd indexed topic.", value_name = "TOPIC")]
+    topic2: Option<String>,
+    #[clap(long, help = "The value of the 4th indexed topic.", value_name = "TOPIC")]
+    topic3: Option<String>,
+
+    #[clap(short, long, env = "ETH_RPC_URL", value_name = "URL")]
+    rpc_url: Option<String>,
+
+    #[clap(long = "json", short = 'j', help_heading = "Display options")]
+    to_json: bool,
+
+    #[clap(long, help = "Do not automatically resolve ENS names in --address.")]
+    no_ens: bool,
+}
+
+impl LogsArgs {
+    pub async fn run(self) -> Result<()> {
+        let Self {
+            sig,
+            address,
+            from_block,
+            to_block,
+            topic1,
+            topic2,
+            topic3,
+            rpc_url,
+            to_json,
+            no_ens,
+        } = self;
+
+        let rpc_url = try_consume_config_rpc_url(rpc_url)?;
+        let provider = try_get_http_provider(rpc_url)?;
+
+        let address = match address {
+            Some(address) => Some(resolve_ens(&provider, address, no_ens).await?),
+            None => None,
+        };
+
+        let event =
+            sig.as_deref().map(get_event).transpose().wrap_err("invalid event signature")?;
+
+        let mut filter = Filter::new();
+        if let Some(address) = address {
+            filter = filter.address(address);
+        }
+        if let Some(event) = &event {
+            filter = filter.topic0(event.signature());
+        }
+        if let Some(topic1) = &topic1 {
+            filter = filter.topic1(parse_topic(topic1)?);
+        }
+        if let Some(topic2) = &topic2 {
+            filter = filter.topic2(parse_topic(topic2)?);
+        }
+        if let Some(topic3) = &topic3 {
+            filter = filter.topic3(parse_topic(topic3)?);
+        }
+
+        let from_block =
+            resolve_block_number(&provider, from_block.unwrap_or(BlockNumber::Earliest)).await?;
+        let to_block =
+            resolve_block_number(&provider, to_block.unwrap_or(BlockNumber::Latest)).await?;
+
+        let mut logs = Vec::new();
+        let mut start = from_block;
+        loop {
+            let end = start.saturating_add(BLOCK_RANGE_CHUNK_SIZE - 1).min(to_block);
+            let page = provider
+                .get_logs(&filter.clone().from_block(start).to_block(end))
+                .await
+                .wrap_err_with(|| format!("failed to fetch logs for blocks {start}..={end}"))?;
+            logs.extend(page);
+
+            if end >= to_block {
+                break
+            }
+            start = end + 1;
+        }
+
+        if to_json {
+            println!("{}", serde_json::to_string(&logs)?);
+            return Ok(())
+        }
+
+        for log in &logs {
+            print_log(log, event.as_ref());
+        }
+
+        Ok(())
+    }
+}
+
+/// Resolves a [`BlockNumber`] to a concrete block height, querying the node for `latest` and
+/// `pending`.
+async fn resolve_block_number(provider: &RetryProvider, block: BlockNumber) -> Result<u64> {
+    Ok(match block {
+        BlockNumber::Number(n) => n.as_u64(),
+        BlockNumber::Earliest => 0,
+        BlockNumber::Latest | BlockNumber::Pending => provider.get_block_number().await?.as_u64(),
+    })
+}
+
+/// Parses a CLI topic value (an address, a 32-byte hash, or a number) into its 32-byte topic
+/// representation.
+fn parse_topic(s: &str) -> Result<H256> {
+    if let Ok(address) = Address::from_str(s) {
+        let mut out = [0u8; 32];
+        out[12..].copy_from_slice(address.as_bytes());
+        return Ok(H256::from_slice(&out))
+    }
+    if let Ok(hash) = H256::from_str(s) {
+        return Ok(hash)
+    }
+
+    let n: U256 =
+        Numeric::from_str(s).map_err(|e| eyre::eyre!("invalid topic value `{s}`: {e}"))?.into();
+    Ok(H256::from_uint(&n))
+}
+
+/// Prints a single log, decoding it against `event` if one was given. Falls back to raw topics
+/// and data if there is no event, or if the log does not match the event's shape.
+fn print_log(log: &ethers::types::Log, event: Option<&Event>) {
+    if let Some(event) = event {
+        let mut event = event.clone();
+        let patched = patch_nameless_params(&mut event);
+        let raw_log = RawLog { topics: log.topics.clone(), data: log.data.to_vec() };
+        if let Ok(decoded) = event.parse_log(raw_log) {
+            println!("- {}", event.name);
+            for param in decoded.params {
+                let name = if patched.contains(&param.name) { "-".to_string() } else { param.name };
+                println!("  {name}: {}", format_token(&param.value));
+            }
+            return
+        }
+    }
+
+    println!("- address: {:?}", log.address);
+    for (i, topic) in log.topics.iter().enumerate() {
+        println!("  topic{i}: {topic:?}");
+    }
+    println!("  data: 0x{}", hex::encode(&log.data));
+}
+
+/// Patches empty parameter names so that `Event::parse_log`, which keys decoded params by name,
+/// does not misattribute values when an event has more than one unnamed parameter.
+///
+/// See <https://github.com/rust-ethereum/ethabi/issues/206>.
+fn patch_nameless_params(event: &mut Event) -> HashSet<String> {
+    let mut patched = HashSet::new();
+    if event.inputs.iter().filter(|input| input.name.is_empty()).count() > 1 {
+        for (idx, param) in event.inputs.iter_mut().enumerate() {
+            if !param.name.is_empty() {
+                continue
+            }
+            param.name = format!("param{idx}");
+            patched.insert(param.name.clone());
+        }
+    }
+    patched
+}
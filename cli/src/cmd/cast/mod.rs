@@ -8,8 +8,12 @@
 pub mod call;
 pub mod create2;
 pub mod estimate;
+pub mod fee_history;
 pub mod find_block;
 pub mod interface;
+pub mod logs;
+pub mod multicall;
+pub mod pretty_calldata;
 pub mod rpc;
 pub mod run;
 pub mod send;
@@ -0,0 +1,237 @@
+//! cast multicall subcommand
+
+use crate::{
+    cmd::Cmd,
+    opts::{
+        cast::{parse_block_id, parse_name_or_address},
+        EthereumOpts,
+    },
+};
+use cast::{Cast, TxBuilder};
+use clap::Parser;
+use ethers::{
+    abi::{decode, ParamType, Token},
+    providers::Middleware,
+    types::{Address, BlockId, NameOrAddress},
+};
+use eyre::{Result, WrapErr};
+use foundry_common::{abi::format_tokens, abi::get_func, fs, try_get_http_provider};
+use foundry_config::{Chain, Config};
+use futures::future::{join_all, BoxFuture};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The canonical Multicall3 deployment address - identical on every chain it's deployed to.
+const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+/// CLI arguments for `cast multicall`.
+#[derive(Debug, Clone, Parser)]
+pub struct MulticallArgs {
+    #[clap(
+        help = "The calls to batch, as a path to a JSON file or an inline JSON array.",
+        long_help = r#"The calls to batch, as a path to a JSON file or an inline JSON array of `{"to", "sig", "args"}` objects.
+
+Example: `[{"to": "0x...", "sig": "balanceOf(address)", "args": ["0x..."]}, {"to": "0x...", "sig": "totalSupply()"}]`"#,
+        value_name = "CALLS"
+    )]
+    calls: String,
+
+    #[clap(
+        long,
+        short,
+        help = "The block to query, applied to the whole batch for a consistent snapshot.",
+        value_parser = parse_block_id,
+        value_name = "BLOCK"
+    )]
+    block: Option<BlockId>,
+
+    #[clap(
+        long,
+        help = "Skip the Multicall3 contract and send each call as a separate eth_call, run concurrently."
+    )]
+    no_multicall: bool,
+
+    #[clap(long, help = "Exit with an error as soon as any individual call fails.")]
+    require_success: bool,
+
+    #[clap(long = "json", short = 'j', help_heading = "Display options")]
+    to_json: bool,
+
+    #[clap(flatten)]
+    eth: EthereumOpts,
+}
+
+impl Cmd for MulticallArgs {
+    type Output = BoxFuture<'static, Result<()>>;
+
+    fn run(self) -> Result<Self::Output> {
+        Ok(Box::pin(Self::multicall(self)))
+    }
+}
+
+/// One call read from `--calls`.
+#[derive(Debug, Clone, Deserialize)]
+struct CallInput {
+    to: String,
+    sig: String,
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+/// One call's outcome, in input order.
+#[derive(Debug, Serialize)]
+struct CallOutput {
+    to: String,
+    sig: String,
+    success: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    result: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl MulticallArgs {
+    async fn multicall(self) -> Result<()> {
+        let MulticallArgs { calls, block, no_multicall, require_success, to_json, eth } = self;
+
+        let calls: Vec<CallInput> = if Path::new(&calls).exists() {
+            fs::read_json_file(Path::new(&calls)).wrap_err("could not read calls file")?
+        } else {
+            serde_json::from_str(&calls).wrap_err("could not parse calls as JSON")?
+        };
+        if calls.is_empty() {
+            eyre::bail!("no calls provided")
+        }
+
+        let config = Config::from(&eth);
+        let provider = try_get_http_provider(config.get_rpc_url_or_localhost_http()?)?;
+        let chain: Chain =
+            if let Some(chain) = eth.chain { chain } else { provider.get_chainid().await?.into() };
+
+        let from = eth.wallet.from.unwrap_or(Address::zero());
+
+        // Resolve every call's target and calldata up front, so a bad signature or an
+        // unresolvable ENS name fails fast instead of after we've already hit the network.
+        let mut builders = Vec::with_capacity(calls.len());
+        for call in &calls {
+            let mut builder = TxBuilder::new(
+                &provider,
+                from,
+                Some(parse_name_or_address(&call.to)?),
+                chain,
+                false,
+                eth.no_ens,
+            )
+            .await?;
+            builder.set_args(&call.sig, call.args.clone()).await?;
+            builders.push(builder.build());
+        }
+
+        let results = if no_multicall {
+            let cast = Cast::new(&provider);
+            join_all(builders.iter().map(|(tx, func)| async {
+                match cast.call((tx.clone(), func.clone()), block).await {
+                    Ok(result) => (true, result),
+                    Err(err) => (false, err.to_string()),
+                }
+            }))
+            .await
+        } else {
+            let multicall_address: Address = MULTICALL3_ADDRESS.parse().unwrap();
+            let call_tokens = builders
+                .iter()
+                .map(|(tx, _)| {
+                    let Some(NameOrAddress::Address(to)) = tx.to() else {
+                        unreachable!("builder always resolves `to` to an address")
+                    };
+                    let data = tx.data().cloned().unwrap_or_default();
+                    // Always let sub-calls fail individually and report their own success/revert
+                    // data - Multicall3 makes the *whole* aggregate3 call revert when a sub-call
+                    // fails and allowFailure is false, which would turn a single failed call into
+                    // a misleading "is it deployed on this chain?" error instead of the per-call
+                    // failure --require-success is meant to surface.
+                    Token::Tuple(vec![
+                        Token::Address(*to),
+                        Token::Bool(true),
+                        Token::Bytes(data.to_vec()),
+                    ])
+                })
+                .collect();
+
+            let selector = get_func("aggregate3((address,bool,bytes)[])")?.short_signature();
+            let mut data = selector.to_vec();
+            data.extend(ethers::abi::encode(&[Token::Array(call_tokens)]));
+
+            let tx = ethers::types::TransactionRequest::new().to(multicall_address).data(data);
+            let res = provider.call(&tx.into(), block).await.wrap_err(
+                "Multicall3 call failed - is it deployed on this chain? Try --no-multicall to fall back to individual eth_calls.",
+            )?;
+
+            let param = ParamType::Array(Box::new(ParamType::Tuple(vec![
+                ParamType::Bool,
+                ParamType::Bytes,
+            ])));
+            let Token::Array(items) = decode(&[param], &res)?.remove(0) else {
+                unreachable!("decoded against an Array ParamType")
+            };
+
+            items
+                .into_iter()
+                .zip(&builders)
+                .map(|(item, (_, func))| {
+                    let Token::Tuple(mut fields) = item else {
+                        unreachable!("decoded against a Tuple ParamType")
+                    };
+                    let data = fields.remove(1).into_bytes().unwrap();
+                    let success = fields.remove(0).into_bool().unwrap();
+                    if !success {
+                        return (false, String::from_utf8_lossy(&data).into_owned())
+                    }
+                    match func.as_ref().map(|func| func.decode_output(&data)) {
+                        Some(Ok(tokens)) => {
+                            (true, format_tokens(&tokens).collect::<Vec<_>>().join("\n"))
+                        }
+                        Some(Err(err)) => (false, err.to_string()),
+                        None => (true, format!("0x{}", ethers::utils::hex::encode(data))),
+                    }
+                })
+                .collect()
+        };
+
+        if require_success {
+            if let Some((i, _)) = results.iter().enumerate().find(|(_, (ok, _))| !ok) {
+                eyre::bail!("call {i} ({}) failed: {}", calls[i].sig, results[i].1)
+            }
+        }
+
+        let outputs: Vec<CallOutput> = calls
+            .iter()
+            .zip(results)
+            .map(|(call, (success, output))| CallOutput {
+                to: call.to.clone(),
+                sig: call.sig.clone(),
+                success,
+                result: if success { output.lines().map(str::to_string).collect() } else { vec![] },
+                error: (!success).then_some(output),
+            })
+            .collect();
+
+        if to_json {
+            println!("{}", serde_json::to_string_pretty(&outputs)?);
+        } else {
+            for output in &outputs {
+                println!("{} {}", output.to, output.sig);
+                if output.success {
+                    for line in &output.result {
+                        println!("{line}");
+                    }
+                } else {
+                    println!("Error: {}", output.error.as_deref().unwrap_or_default());
+                }
+                println!();
+            }
+        }
+
+        Ok(())
+    }
+}
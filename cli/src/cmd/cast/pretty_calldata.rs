@@ -0,0 +1,210 @@
+//! cast pretty-calldata subcommand
+
+use cast::SimpleCast;
+use clap::Parser;
+use ethers::abi::Token;
+use eyre::Result;
+use foundry_common::{
+    abi::{format_token, get_func},
+    selectors::decode_function_selector_with_cache,
+};
+use foundry_config::Config;
+use futures::future::BoxFuture;
+use serde::Serialize;
+
+use crate::cmd::Cmd;
+
+/// How deep `--recurse` will unwrap nested `bytes`/`bytes[]` arguments, so a maliciously- or
+/// accidentally-nested blob (or a cycle, if that were even possible here) can't send us into
+/// unbounded recursion.
+const MAX_RECURSE_DEPTH: u8 = 4;
+
+/// CLI arguments for `cast pretty-calldata`.
+#[derive(Debug, Clone, Parser)]
+pub struct PrettyCalldataArgs {
+    #[clap(help = "The calldata.", value_name = "CALLDATA")]
+    calldata: String,
+    #[clap(long, short, help = "Skip the local cache and signature directory lookup.")]
+    offline: bool,
+    #[clap(
+        long,
+        help = "Recurse into nested calldata carried in `bytes`/`bytes[]` arguments, e.g. multicall batches or a Safe's execTransaction."
+    )]
+    recurse: bool,
+    #[clap(long = "json", short = 'j', help_heading = "Display options")]
+    to_json: bool,
+}
+
+impl Cmd for PrettyCalldataArgs {
+    type Output = BoxFuture<'static, Result<()>>;
+
+    fn run(self) -> Result<Self::Output> {
+        Ok(Box::pin(Self::pretty_calldata(self)))
+    }
+}
+
+impl PrettyCalldataArgs {
+    async fn pretty_calldata(self) -> Result<()> {
+        let PrettyCalldataArgs { calldata, offline, recurse, to_json } = self;
+        if !calldata.starts_with("0x") {
+            eyre::bail!("expected calldata hex string, received \"{calldata}\"")
+        }
+
+        let decoded = decode_call(calldata, offline, recurse, 0).await?;
+        if to_json {
+            println!("{}", serde_json::to_string_pretty(&decoded)?);
+        } else {
+            print!("{}", decoded.render(0));
+        }
+        Ok(())
+    }
+}
+
+/// A single decoded (or, failing that, raw) call.
+#[derive(Debug, Serialize)]
+struct DecodedCall {
+    selector: String,
+    /// Every cached/directory signature that actually decodes against this calldata - usually
+    /// one, but selector collisions do happen.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    signatures: Vec<DecodedSig>,
+    /// The calldata chunked into 32-byte words, populated only when no signature matched.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    words: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct DecodedSig {
+    signature: String,
+    args: Vec<DecodedArg>,
+}
+
+#[derive(Debug, Serialize)]
+struct DecodedArg {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(rename = "type")]
+    ty: String,
+    value: String,
+    /// Calls found while unwrapping this argument under `--recurse`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    nested: Vec<DecodedCall>,
+}
+
+impl DecodedCall {
+    fn render(&self, depth: usize) -> String {
+        let pad = "    ".repeat(depth);
+        let mut out = String::new();
+
+        if self.signatures.is_empty() {
+            out += &format!("{pad}Method: {}\n", self.selector);
+            out += &format!("{pad}------------\n");
+            for (i, word) in self.words.iter().enumerate() {
+                out += &format!("{pad}[{i:>2}]: {word}\n");
+            }
+            return out
+        }
+
+        if self.signatures.len() > 1 {
+            out += &format!("{pad}Possible methods:\n");
+        }
+        for sig in &self.signatures {
+            out += &format!("{pad}{}\n", sig.signature);
+            for arg in &sig.args {
+                let label = arg.name.as_deref().unwrap_or("_");
+                out += &format!("{pad}  {label} ({}): {}\n", arg.ty, arg.value);
+                for nested in &arg.nested {
+                    out += &nested.render(depth + 2);
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Decodes `calldata` against every signature in the local cache/signature directory that
+/// actually matches it, recursing into `bytes`/`bytes[]` arguments when `recurse` is set.
+fn decode_call(
+    calldata: String,
+    offline: bool,
+    recurse: bool,
+    depth: u8,
+) -> BoxFuture<'static, Result<DecodedCall>> {
+    Box::pin(async move {
+        let stripped = calldata.trim_start_matches("0x");
+        if stripped.len() < 8 {
+            eyre::bail!("calldata cannot be less than 4 bytes")
+        }
+        let selector = format!("0x{}", &stripped[..8]);
+
+        let candidates = if offline {
+            vec![]
+        } else {
+            decode_function_selector_with_cache(&selector, Config::foundry_cache_dir(), offline)
+                .await
+                .unwrap_or_default()
+        };
+
+        let mut signatures = Vec::new();
+        for sig in candidates {
+            let Ok(tokens) = SimpleCast::abi_decode(&sig, &calldata, true) else { continue };
+            let Ok(func) = get_func(&sig) else { continue };
+
+            let mut args = Vec::with_capacity(tokens.len());
+            for (param, token) in func.inputs.iter().zip(tokens.iter()) {
+                let nested = if recurse && depth < MAX_RECURSE_DEPTH {
+                    recurse_into(token, offline, depth).await?
+                } else {
+                    vec![]
+                };
+                args.push(DecodedArg {
+                    name: (!param.name.is_empty()).then(|| param.name.clone()),
+                    ty: param.kind.to_string(),
+                    value: format_token(token),
+                    nested,
+                });
+            }
+            signatures.push(DecodedSig { signature: sig, args });
+        }
+
+        if !signatures.is_empty() {
+            return Ok(DecodedCall { selector, signatures, words: vec![] })
+        }
+
+        let data = &stripped[8..];
+        if data.len() % 64 != 0 {
+            eyre::bail!("invalid calldata size")
+        }
+        let words = (0..data.len() / 64)
+            .map(|i| format!("0x{}", &data[64 * i..64 * (i + 1)]))
+            .collect();
+        Ok(DecodedCall { selector, signatures: vec![], words })
+    })
+}
+
+/// If `token` is (or contains) raw bytes that look like nested calldata, decodes them too.
+async fn recurse_into(token: &Token, offline: bool, depth: u8) -> Result<Vec<DecodedCall>> {
+    let candidates: Vec<&Vec<u8>> = match token {
+        Token::Bytes(bytes) => vec![bytes],
+        Token::Array(tokens) | Token::FixedArray(tokens) => tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::Bytes(bytes) => Some(bytes),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    let mut nested = Vec::new();
+    for bytes in candidates {
+        if bytes.len() < 4 {
+            continue
+        }
+        let calldata = format!("0x{}", hex::encode(bytes));
+        if let Ok(call) = decode_call(calldata, offline, true, depth + 1).await {
+            nested.push(call);
+        }
+    }
+    Ok(nested)
+}
@@ -7,6 +7,10 @@ use futures::future::BoxFuture;
 use itertools::Itertools;
 
 /// CLI arguments for `cast rpc`.
+///
+/// Works against any method, including provider-specific namespaces (e.g. Erigon/Reth debug and
+/// admin methods) that don't have a dedicated `cast` subcommand. Note: authentication is limited
+/// to whatever `--rpc-url`'s `ProviderBuilder` supports; the engine API's JWT auth is not handled.
 #[derive(Debug, Clone, Parser)]
 pub struct RpcArgs {
     #[clap(short, long, env = "ETH_RPC_URL", value_name = "URL")]
@@ -10,6 +10,7 @@ use ethers::{
 use eyre::WrapErr;
 use forge::{
     debug::DebugArena,
+    decode::decode_revert,
     executor::{
         inspector::cheatcodes::util::configure_tx_env, opts::EvmOpts, Backend, DeployResult,
         ExecutorBuilder, RawCallResult,
@@ -32,6 +33,12 @@ pub struct RunArgs {
     rpc_url: Option<String>,
     #[clap(long, short = 'd', help = "Debugs the transaction.")]
     debug: bool,
+    #[clap(
+        long,
+        help = "Opens the debugger at the step that reverted, if any. Requires --debug.",
+        requires = "debug"
+    )]
+    jump_to_revert: bool,
     #[clap(long, short = 't', help = "Print out opcode traces.")]
     trace_printer: bool,
     #[clap(
@@ -159,12 +166,20 @@ impl RunArgs {
                     gas_used: gas,
                     traces,
                     debug: run_debug,
-                    exit_reason: _,
+                    exit_reason,
+                    result: return_data,
                     ..
                 } = executor.commit_tx_with_env(env).unwrap();
 
+                let revert_reason = if reverted {
+                    decode_revert(&return_data[..], None, Some(exit_reason)).ok()
+                } else {
+                    None
+                };
+
                 RunResult {
                     success: !reverted,
+                    revert_reason,
                     traces: vec![(TraceKind::Execution, traces.unwrap_or_default())],
                     debug: run_debug.unwrap_or_default(),
                     gas_used: gas,
@@ -176,6 +191,7 @@ impl RunArgs {
 
                 RunResult {
                     success: true,
+                    revert_reason: None,
                     traces: vec![(TraceKind::Execution, traces.unwrap_or_default())],
                     debug: run_debug.unwrap_or_default(),
                     gas_used,
@@ -214,7 +230,7 @@ impl RunArgs {
 
         if self.debug {
             let (sources, bytecode) = etherscan_identifier.get_compiled_contracts().await?;
-            run_debugger(result, decoder, bytecode, sources)?;
+            run_debugger(result, decoder, bytecode, sources, self.jump_to_revert)?;
         } else {
             print_traces(&mut result, decoder, self.verbose).await?;
         }
@@ -227,12 +243,25 @@ fn run_debugger(
     decoder: CallTraceDecoder,
     known_contracts: BTreeMap<ArtifactId, ContractBytecodeSome>,
     sources: BTreeMap<ArtifactId, String>,
+    jump_to_revert: bool,
 ) -> eyre::Result<()> {
     let calls: Vec<DebugArena> = vec![result.debug];
     let flattened = calls.last().expect("we should have collected debug info").flatten(0);
+    let (call_index, step_index) = if jump_to_revert {
+        match Tui::find_revert(&flattened) {
+            Some(location) => location,
+            None => {
+                println!("{}", Paint::yellow("Nothing reverted, opening debugger at the start"));
+                (0, 0)
+            }
+        }
+    } else {
+        (0, 0)
+    };
     let tui = Tui::new(
         flattened,
-        0,
+        step_index,
+        call_index,
         decoder.contracts,
         known_contracts.into_iter().map(|(id, artifact)| (id.name, artifact)).collect(),
         sources
@@ -271,6 +300,8 @@ async fn print_traces(
 
     if result.success {
         println!("{}", Paint::green("Transaction successfully executed."));
+    } else if let Some(reason) = &result.revert_reason {
+        println!("{}", Paint::red(format!("Transaction failed: {reason}")));
     } else {
         println!("{}", Paint::red("Transaction failed."));
     }
@@ -281,6 +312,7 @@ async fn print_traces(
 
 struct RunResult {
     pub success: bool,
+    pub revert_reason: Option<String>,
     pub traces: Traces,
     pub debug: DebugArena,
     pub gas_used: u64,
@@ -2,7 +2,10 @@
 use crate::opts::{cast::parse_name_or_address, EthereumOpts, TransactionOpts, WalletType};
 use cast::{Cast, TxBuilder};
 use clap::Parser;
-use ethers::{providers::Middleware, types::NameOrAddress};
+use ethers::{
+    providers::Middleware,
+    types::{transaction::eip2930::AccessList, NameOrAddress},
+};
 use foundry_common::try_get_http_provider;
 use foundry_config::{Chain, Config};
 use std::sync::Arc;
@@ -30,6 +33,13 @@ pub struct SendTxArgs {
     cast_async: bool,
     #[clap(flatten)]
     tx: TransactionOpts,
+    #[clap(
+        long,
+        help = "The access list to include, as JSON, e.g. the output of `cast access-list --json`.",
+        value_parser = parse_access_list,
+        value_name = "ACCESS_LIST"
+    )]
+    access_list: Option<AccessList>,
     #[clap(flatten)]
     eth: EthereumOpts,
     #[clap(
@@ -40,6 +50,13 @@ pub struct SendTxArgs {
         value_name = "CONFIRMATIONS"
     )]
     confirmations: usize,
+    #[clap(
+        long,
+        help = "Timeout for the receipt wait, in seconds.",
+        default_value = "120",
+        value_name = "TIMEOUT"
+    )]
+    timeout: u64,
     #[clap(long = "json", short = 'j', help_heading = "Display options")]
     to_json: bool,
     #[clap(
@@ -75,7 +92,9 @@ impl SendTxArgs {
             cast_async,
             mut args,
             mut tx,
+            access_list,
             confirmations,
+            timeout,
             to_json,
             resend,
             command,
@@ -84,6 +103,7 @@ impl SendTxArgs {
         let provider = Arc::new(try_get_http_provider(config.get_rpc_url_or_localhost_http()?)?);
         let chain: Chain =
             if let Some(chain) = eth.chain { chain } else { provider.get_chainid().await?.into() };
+        let no_ens = eth.no_ens;
         let mut sig = sig.unwrap_or_default();
 
         if let Ok(Some(signer)) = eth.signer_with(chain.into(), provider.clone()).await {
@@ -128,11 +148,14 @@ impl SendTxArgs {
                         code,
                         (sig, args),
                         tx,
+                        access_list.clone(),
                         chain,
                         config.etherscan_api_key,
                         cast_async,
                         confirmations,
+                        timeout,
                         to_json,
+                        no_ens,
                     )
                     .await?;
                 }
@@ -144,11 +167,14 @@ impl SendTxArgs {
                         code,
                         (sig, args),
                         tx,
+                        access_list.clone(),
                         chain,
                         config.etherscan_api_key,
                         cast_async,
                         confirmations,
+                        timeout,
                         to_json,
+                        no_ens,
                     )
                     .await?;
                 }
@@ -160,11 +186,14 @@ impl SendTxArgs {
                         code,
                         (sig, args),
                         tx,
+                        access_list.clone(),
                         chain,
                         config.etherscan_api_key,
                         cast_async,
                         confirmations,
+                        timeout,
                         to_json,
+                        no_ens,
                     )
                     .await?;
                 }
@@ -176,11 +205,14 @@ impl SendTxArgs {
                         code,
                         (sig, args),
                         tx,
+                        access_list.clone(),
                         chain,
                         config.etherscan_api_key,
                         cast_async,
                         confirmations,
+                        timeout,
                         to_json,
+                        no_ens,
                     )
                     .await?;
                 }
@@ -211,11 +243,14 @@ impl SendTxArgs {
                 code,
                 (sig, args),
                 tx,
+                access_list,
                 chain,
                 config.etherscan_api_key,
                 cast_async,
                 confirmations,
+                timeout,
                 to_json,
+                no_ens,
             )
             .await?;
         } else {
@@ -233,25 +268,29 @@ async fn cast_send<M: Middleware, F: Into<NameOrAddress>, T: Into<NameOrAddress>
     code: Option<String>,
     args: (String, Vec<String>),
     tx: TransactionOpts,
+    access_list: Option<AccessList>,
     chain: Chain,
     etherscan_api_key: Option<String>,
     cast_async: bool,
     confs: usize,
+    timeout: u64,
     to_json: bool,
+    no_ens: bool,
 ) -> eyre::Result<()>
 where
     M::Error: 'static,
 {
     let (sig, params) = args;
     let params = if !sig.is_empty() { Some((&sig[..], params)) } else { None };
-    let mut builder = TxBuilder::new(&provider, from, to, chain, tx.legacy).await?;
+    let mut builder = TxBuilder::new(&provider, from, to, chain, tx.legacy, no_ens).await?;
     builder
         .etherscan_api_key(etherscan_api_key)
         .gas(tx.gas_limit)
         .gas_price(tx.gas_price)
         .priority_gas_price(tx.priority_gas_price)
         .value(tx.value)
-        .nonce(tx.nonce);
+        .nonce(tx.nonce)
+        .access_list(access_list);
 
     if let Some(code) = code {
         let mut data = hex::decode(code.strip_prefix("0x").unwrap_or(&code))?;
@@ -269,15 +308,43 @@ where
 
     let cast = Cast::new(provider);
 
-    let pending_tx = cast.send(builder_output).await?;
+    let pending_tx = cast.send(builder_output).await.map_err(explain_send_error)?;
     let tx_hash = *pending_tx;
 
     if cast_async {
         println!("{tx_hash:#x}");
     } else {
-        let receipt = cast.receipt(format!("{tx_hash:#x}"), None, confs, false, to_json).await?;
+        let (receipt, reverted) =
+            cast.receipt(format!("{tx_hash:#x}"), None, confs, timeout, false, to_json).await?;
         println!("{receipt}");
+        if reverted {
+            std::process::exit(1)
+        }
     }
 
     Ok(())
 }
+
+/// Parses an access list from JSON, e.g. the output of `cast access-list --json`.
+fn parse_access_list(s: &str) -> eyre::Result<AccessList> {
+    Ok(serde_json::from_str(s)?)
+}
+
+/// Adds an actionable hint to nonce-conflict errors (a replacement transaction underpriced, or a
+/// nonce that's already been used), which otherwise surface as an opaque RPC error message.
+fn explain_send_error(err: eyre::Report) -> eyre::Report {
+    let msg = err.to_string();
+    if msg.contains("underpriced") {
+        eyre::eyre!(
+            "{msg}\n\nThere is already a pending transaction with the same nonce. Consider \
+             bumping the gas price with --gas-price, or using a fresh nonce with --nonce."
+        )
+    } else if msg.contains("nonce too low") || msg.contains("already known") {
+        eyre::eyre!(
+            "{msg}\n\nThe nonce has already been used. Consider specifying a fresh nonce with \
+             --nonce, or omitting it to use the next available one."
+        )
+    } else {
+        err
+    }
+}
@@ -7,18 +7,25 @@ use cast::Cast;
 use clap::Parser;
 use comfy_table::{presets::ASCII_MARKDOWN, Table};
 use ethers::{
-    abi::ethabi::ethereum_types::BigEndianHash, etherscan::Client, prelude::*,
+    abi::ethabi::ethereum_types::BigEndianHash,
+    etherscan::Client,
+    prelude::*,
     solc::artifacts::StorageLayout,
+    types::{serde_helpers::Numeric, I256},
+    utils::keccak256,
 };
-use eyre::{ContextCompat, Result};
+use eyre::{ContextCompat, Result, WrapErr};
 use foundry_common::{
     abi::find_source,
     compile::{compile, etherscan_project, suppress_compile},
+    ens::resolve_ens,
     try_get_http_provider, RetryProvider,
 };
 use foundry_config::Config;
 use futures::future::join_all;
 use semver::Version;
+use serde_json::Value;
+use std::str::FromStr;
 
 /// The minimum Solc version for outputting storage layouts.
 ///
@@ -32,11 +39,10 @@ pub struct StorageArgs {
     #[clap(help = "The contract address.", value_parser = parse_name_or_address, value_name = "ADDRESS")]
     address: NameOrAddress,
     #[clap(
-        help = "The storage slot number (hex or decimal)",
-        value_parser = parse_slot,
+        help = "The storage slot number (hex or decimal), or a variable path such as `balances[0xabc...].amount` to resolve against the contract's storage layout.",
         value_name = "SLOT"
     )]
-    slot: Option<H256>,
+    slot: Option<String>,
     #[clap(long, env = "ETH_RPC_URL", value_name = "URL")]
     rpc_url: Option<String>,
     #[clap(
@@ -65,28 +71,34 @@ pub struct StorageArgs {
     // Forge
     #[clap(flatten)]
     build: build::CoreBuildArgs,
+
+    #[clap(long, help = "Do not automatically resolve ENS names in the address argument.")]
+    no_ens: bool,
 }
 
 impl StorageArgs {
     pub async fn run(self) -> Result<()> {
-        let Self { address, block, build, rpc_url, slot, chain, etherscan_api_key } = self;
+        let Self { address, block, build, rpc_url, slot, chain, etherscan_api_key, no_ens } = self;
 
         let rpc_url = try_consume_config_rpc_url(rpc_url)?;
         let provider = try_get_http_provider(rpc_url)?;
 
-        let address = match address {
-            NameOrAddress::Name(name) => provider.resolve_name(&name).await?,
-            NameOrAddress::Address(address) => address,
-        };
+        let address = resolve_ens(&provider, address, no_ens).await?;
 
-        // Slot was provided, perform a simple RPC call
-        if let Some(slot) = slot {
-            let cast = Cast::new(provider);
-            println!("{}", cast.storage(address, slot, block).await?);
-            return Ok(())
-        }
+        // A raw slot number was provided, perform a simple RPC call
+        let path = match slot.as_deref().map(parse_slot) {
+            Some(Ok(slot)) => {
+                let cast = Cast::new(provider);
+                println!("{}", cast.storage(address, slot, block).await?);
+                return Ok(())
+            }
+            // Not a raw slot number: treat it as a variable path to resolve against the
+            // contract's storage layout instead.
+            Some(Err(_)) => slot,
+            None => None,
+        };
 
-        // No slot was provided
+        // No slot/path was provided, or a variable path was provided
         // Get deployed bytecode at given address
         let address_code = provider.get_code(address, block).await?;
         if address_code.is_empty() {
@@ -107,7 +119,15 @@ impl StorageArgs {
             let artifact =
                 out.artifacts().find(|(_, artifact)| match_code(artifact).unwrap_or_default());
             if let Some((_, artifact)) = artifact {
-                return fetch_and_print_storage(provider, address, artifact, true).await
+                return fetch_and_print_storage(
+                    provider,
+                    address,
+                    artifact,
+                    path.clone(),
+                    block,
+                    true,
+                )
+                .await
             }
         }
 
@@ -166,7 +186,7 @@ impl StorageArgs {
         // Clear temp directory
         root.close()?;
 
-        fetch_and_print_storage(provider, address, artifact, true).await
+        fetch_and_print_storage(provider, address, artifact, path, block, true).await
     }
 }
 
@@ -174,16 +194,327 @@ async fn fetch_and_print_storage(
     provider: RetryProvider,
     address: Address,
     artifact: &ConfigurableContractArtifact,
+    path: Option<String>,
+    block: Option<BlockId>,
     pretty: bool,
 ) -> Result<()> {
     if is_storage_layout_empty(&artifact.storage_layout) {
         eprintln!("Storage layout is empty.");
-        Ok(())
+        return Ok(())
+    }
+
+    let layout = artifact.storage_layout.as_ref().unwrap().clone();
+
+    if let Some(path) = path {
+        let value = fetch_storage_path_value(provider, address, block, &layout, &path).await?;
+        println!("{value}");
+        return Ok(())
+    }
+
+    let values = fetch_storage_values(provider, address, &layout).await?;
+    print_storage(layout, values, pretty)
+}
+
+/// Resolves `path` (e.g. `balances[0xabc...].amount`) against `layout`, computing the exact slot
+/// and packed byte range it occupies, reads that slot, and returns the masked/shifted value
+/// formatted according to the resolved variable's type.
+async fn fetch_storage_path_value(
+    provider: RetryProvider,
+    address: Address,
+    block: Option<BlockId>,
+    layout: &StorageLayout,
+    path: &str,
+) -> Result<String> {
+    let layout_json = serde_json::to_value(layout)?;
+    let resolved = resolve_storage_path(&layout_json, path)?;
+
+    let word = provider.get_storage_at(address, H256::from_uint(&resolved.slot), block).await?;
+    let bytes = word.to_fixed_bytes();
+
+    let start = 32usize
+        .checked_sub(resolved.offset + resolved.size)
+        .wrap_err("resolved variable does not fit in a single storage slot")?;
+    let value_bytes = &bytes[start..start + resolved.size];
+
+    Ok(format_storage_value(&resolved.label, value_bytes))
+}
+
+/// The exact location and type of a single storage variable, resolved from a variable path.
+struct ResolvedSlot {
+    slot: U256,
+    /// Byte offset within the 32-byte slot, counted from the right (the least-significant end),
+    /// matching the `offset` convention used in solc's storage layout output.
+    offset: usize,
+    /// Size of the value in bytes.
+    size: usize,
+    /// The Solidity type label of the resolved variable, used to format its value.
+    label: String,
+}
+
+enum PathSegment {
+    Field(String),
+    Index(String),
+}
+
+/// Splits a variable path like `balances[0xabc...].amount` into its base variable name and the
+/// sequence of field accesses (`.amount`) and index accesses (`[0xabc...]`) that follow it.
+fn parse_storage_path(path: &str) -> Result<(String, Vec<PathSegment>)> {
+    let mut chars = path.chars().peekable();
+
+    let mut base = String::new();
+    while let Some(&c) = chars.peek() {
+        if c == '.' || c == '[' {
+            break
+        }
+        base.push(c);
+        chars.next();
+    }
+    if base.is_empty() {
+        eyre::bail!("invalid variable path `{path}`: expected a variable name")
+    }
+
+    let mut segments = Vec::new();
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+                let mut field = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' {
+                        break
+                    }
+                    field.push(c);
+                    chars.next();
+                }
+                if field.is_empty() {
+                    eyre::bail!("invalid variable path `{path}`: expected a field name after `.`")
+                }
+                segments.push(PathSegment::Field(field));
+            }
+            '[' => {
+                chars.next();
+                let mut index = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == ']' {
+                        break
+                    }
+                    index.push(c);
+                    chars.next();
+                }
+                if chars.next() != Some(']') {
+                    eyre::bail!("invalid variable path `{path}`: unterminated `[`")
+                }
+                segments.push(PathSegment::Index(index));
+            }
+            _ => eyre::bail!("invalid variable path `{path}`: unexpected character `{c}`"),
+        }
+    }
+
+    Ok((base, segments))
+}
+
+/// Resolves a variable path against a storage layout (as the `serde_json::Value` solc emits it),
+/// walking mapping/array/struct accesses to compute the final slot, packed byte offset, size, and
+/// type label.
+///
+/// Scope: mapping keys and array bases are limited to elementary value types (addresses,
+/// booleans, fixed-size bytes, and (u)ints); string/bytes mapping keys and nested dynamic types
+/// are not supported.
+fn resolve_storage_path(layout: &Value, path: &str) -> Result<ResolvedSlot> {
+    let (base, segments) = parse_storage_path(path)?;
+
+    let storage =
+        layout.get("storage").and_then(Value::as_array).wrap_err("invalid storage layout")?;
+    let types = layout.get("types").wrap_err("invalid storage layout")?;
+
+    let entry = storage
+        .iter()
+        .find(|s| s.get("label").and_then(Value::as_str) == Some(base.as_str()))
+        .ok_or_else(|| eyre::eyre!("no storage variable named `{base}`"))?;
+
+    let mut slot = U256::from_dec_str(
+        entry.get("slot").and_then(Value::as_str).wrap_err("storage entry missing slot")?,
+    )?;
+    let mut offset = entry.get("offset").and_then(Value::as_u64).unwrap_or(0) as usize;
+    let mut type_id = entry
+        .get("type")
+        .and_then(Value::as_str)
+        .wrap_err("storage entry missing type")?
+        .to_string();
+
+    for segment in segments {
+        let type_def =
+            types.get(&type_id).ok_or_else(|| eyre::eyre!("unknown storage type `{type_id}`"))?;
+        let encoding = type_def.get("encoding").and_then(Value::as_str).unwrap_or("inplace");
+
+        match segment {
+            PathSegment::Field(field) => {
+                if encoding != "inplace" {
+                    eyre::bail!("`{field}` is not a struct member of `{type_id}`")
+                }
+                let members = type_def
+                    .get("members")
+                    .and_then(Value::as_array)
+                    .ok_or_else(|| eyre::eyre!("`{type_id}` is not a struct"))?;
+                let member = members
+                    .iter()
+                    .find(|m| m.get("label").and_then(Value::as_str) == Some(field.as_str()))
+                    .ok_or_else(|| eyre::eyre!("no member named `{field}` on `{type_id}`"))?;
+                let member_slot = U256::from_dec_str(
+                    member.get("slot").and_then(Value::as_str).wrap_err("member missing slot")?,
+                )?;
+                slot += member_slot;
+                offset = member.get("offset").and_then(Value::as_u64).unwrap_or(0) as usize;
+                type_id = member
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .wrap_err("member missing type")?
+                    .to_string();
+            }
+            PathSegment::Index(index) => match encoding {
+                "mapping" => {
+                    let key_type_id = type_def
+                        .get("key")
+                        .and_then(Value::as_str)
+                        .wrap_err("mapping has no key type")?;
+                    let value_type_id = type_def
+                        .get("value")
+                        .and_then(Value::as_str)
+                        .wrap_err("mapping has no value type")?
+                        .to_string();
+                    let key_label = types
+                        .get(key_type_id)
+                        .and_then(|t| t.get("label"))
+                        .and_then(Value::as_str)
+                        .unwrap_or(key_type_id);
+                    let key_bytes = encode_mapping_key(&index, key_label)?;
+
+                    let mut preimage = [0u8; 64];
+                    preimage[..32].copy_from_slice(&key_bytes);
+                    preimage[32..].copy_from_slice(&H256::from_uint(&slot).to_fixed_bytes());
+                    slot = U256::from_big_endian(&keccak256(preimage));
+                    offset = 0;
+                    type_id = value_type_id;
+                }
+                "inplace" | "dynamic_array" if type_id.starts_with("t_array(") => {
+                    let base_type_id = type_def
+                        .get("base")
+                        .and_then(Value::as_str)
+                        .wrap_err("array has no base type")?
+                        .to_string();
+                    let base_size = type_number_of_bytes(types, &base_type_id)?;
+                    let index: u64 =
+                        index.parse().wrap_err("array index must be a non-negative integer")?;
+
+                    let array_base_slot = if encoding == "dynamic_array" {
+                        U256::from_big_endian(&keccak256(H256::from_uint(&slot).to_fixed_bytes()))
+                    } else {
+                        slot
+                    };
+
+                    let (slot_delta, element_offset) =
+                        array_element_location(base_size as usize, index);
+                    slot = array_base_slot + slot_delta;
+                    offset = element_offset;
+                    type_id = base_type_id;
+                }
+                _ => eyre::bail!("`{type_id}` cannot be indexed"),
+            },
+        }
+    }
+
+    let type_def =
+        types.get(&type_id).ok_or_else(|| eyre::eyre!("unknown storage type `{type_id}`"))?;
+    let size = type_number_of_bytes(types, &type_id)? as usize;
+    let label =
+        type_def.get("label").and_then(Value::as_str).unwrap_or(type_id.as_str()).to_string();
+
+    Ok(ResolvedSlot { slot, offset, size, label })
+}
+
+fn type_number_of_bytes(types: &Value, type_id: &str) -> Result<u64> {
+    types
+        .get(type_id)
+        .and_then(|t| t.get("numberOfBytes"))
+        .and_then(Value::as_str)
+        .wrap_err_with(|| format!("unknown storage type `{type_id}`"))?
+        .parse()
+        .wrap_err("invalid numberOfBytes")
+}
+
+/// Returns `(slot_delta, byte_offset)` for the `index`-th element of an array whose elements are
+/// `base_size` bytes wide: elements that fit in 32 bytes are packed several per slot (as solc
+/// does for inplace arrays), while wider elements each occupy their own run of slots.
+fn array_element_location(base_size: usize, index: u64) -> (U256, usize) {
+    if base_size == 0 || base_size > 32 {
+        let slots_per_element = ((base_size.max(1)) as u64 + 31) / 32;
+        (U256::from(index.saturating_mul(slots_per_element.max(1))), 0)
+    } else {
+        let per_slot = (32 / base_size) as u64;
+        (U256::from(index / per_slot), (index % per_slot) as usize * base_size)
+    }
+}
+
+/// Encodes a mapping key of an elementary Solidity type as the 32-byte word solc uses when
+/// hashing `keccak256(key . slot)`: numbers, booleans and addresses are left-padded, fixed-size
+/// `bytesN` keys are right-padded.
+fn encode_mapping_key(index: &str, key_label: &str) -> Result<[u8; 32]> {
+    let mut out = [0u8; 32];
+
+    if key_label == "address" || key_label.starts_with("contract ") {
+        let addr =
+            Address::from_str(index).wrap_err_with(|| format!("invalid address key `{index}`"))?;
+        out[12..].copy_from_slice(addr.as_bytes());
+    } else if key_label == "bool" {
+        if matches!(index, "true" | "1") {
+            out[31] = 1;
+        }
+    } else if key_label.starts_with("bytes") && key_label != "bytes" {
+        let bytes = hex::decode(index.strip_prefix("0x").unwrap_or(index))
+            .wrap_err_with(|| format!("invalid bytes key `{index}`"))?;
+        let len = bytes.len().min(32);
+        out[..len].copy_from_slice(&bytes[..len]);
+    } else if key_label.starts_with("uint") || key_label.starts_with("int") {
+        let n: U256 = Numeric::from_str(index)
+            .map_err(|e| eyre::eyre!("invalid numeric key `{index}`: {e}"))?
+            .into();
+        out = H256::from_uint(&n).to_fixed_bytes();
     } else {
-        let layout = artifact.storage_layout.as_ref().unwrap().clone();
-        let values = fetch_storage_values(provider, address, &layout).await?;
-        print_storage(layout, values, pretty)
+        eyre::bail!(
+            "unsupported mapping key type `{key_label}`: only elementary value types are supported"
+        )
+    }
+
+    Ok(out)
+}
+
+/// Formats a masked storage value according to its resolved Solidity type label: booleans,
+/// addresses and (u)ints are decoded, anything else is printed as hex. This is a minimal,
+/// best-effort formatting rather than a full ABI-aware decode.
+fn format_storage_value(label: &str, bytes: &[u8]) -> String {
+    if label == "bool" {
+        return bytes.iter().any(|&b| b != 0).to_string()
+    }
+
+    if label == "address" || label.starts_with("contract ") {
+        let mut addr = [0u8; 20];
+        let start = bytes.len().saturating_sub(20);
+        addr.copy_from_slice(&bytes[start..]);
+        return format!("{:?}", Address::from(addr))
     }
+
+    if label.starts_with("uint") {
+        return U256::from_big_endian(bytes).to_string()
+    }
+
+    if label.starts_with("int") {
+        let negative = !bytes.is_empty() && bytes[0] & 0x80 != 0;
+        let mut padded = [if negative { 0xffu8 } else { 0 }; 32];
+        padded[32 - bytes.len()..].copy_from_slice(bytes);
+        return I256::from_raw(U256::from_big_endian(&padded)).to_string()
+    }
+
+    format!("0x{}", hex::encode(bytes))
 }
 
 /// Overrides the `value` field in [StorageLayout] with the slot's value to avoid creating new data
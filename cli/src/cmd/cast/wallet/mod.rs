@@ -4,16 +4,19 @@ pub mod vanity;
 
 use crate::{
     cmd::{cast::wallet::vanity::VanityArgs, Cmd},
-    opts::{EthereumOpts, Wallet, WalletType},
+    opts::{EthereumOpts, KeystoreFile, Wallet, WalletType},
 };
 use cast::SimpleCast;
 use clap::Parser;
 use ethers::{
     core::rand::thread_rng,
     signers::{LocalWallet, Signer},
-    types::{Address, Chain, Signature},
+    types::{Address, Chain, Signature, H256},
 };
-use eyre::Context;
+use eyre::{Context, ContextCompat};
+use foundry_common::fs;
+use foundry_config::Config;
+use std::str::FromStr;
 
 /// CLI arguments for `cast send`.
 #[derive(Debug, Parser)]
@@ -45,6 +48,38 @@ pub enum WalletSubcommands {
     },
     #[clap(name = "vanity", visible_alias = "va", about = "Generate a vanity address.")]
     Vanity(VanityArgs),
+    #[clap(
+        name = "import",
+        visible_alias = "i",
+        about = "Import a private key into an encrypted keystore."
+    )]
+    Import {
+        #[clap(
+            help = "The name to save the keystore under, e.g. `my-account`.",
+            value_name = "ACCOUNT_NAME"
+        )]
+        account_name: String,
+        #[clap(
+            long,
+            help = "Use the provided private key.",
+            value_name = "RAW_PRIVATE_KEY",
+            value_parser = foundry_common::clap_helpers::strip_0x_prefix
+        )]
+        private_key: Option<String>,
+        #[clap(
+            long,
+            help = "Password for the JSON keystore in cleartext. This is UNSAFE to use and we recommend using a prompt instead.",
+            env = "CAST_PASSWORD",
+            value_name = "PASSWORD"
+        )]
+        unsafe_password: Option<String>,
+    },
+    #[clap(
+        name = "list",
+        visible_alias = "ls",
+        about = "List all the accounts in the keystore default directory."
+    )]
+    List,
     #[clap(name = "address", visible_aliases = &["a", "addr"], about = "Convert a private key to an address.")]
     Address {
         #[clap(
@@ -60,6 +95,12 @@ pub enum WalletSubcommands {
     Sign {
         #[clap(help = "message to sign", value_name = "MESSAGE")]
         message: String,
+        #[clap(
+            long,
+            help = "Treat the message as a raw, 32-byte hash and sign it directly, without \
+                    applying the EIP-191 personal_sign prefix."
+        )]
+        no_hash: bool,
         #[clap(flatten)]
         wallet: Wallet,
     },
@@ -116,6 +157,73 @@ impl WalletSubcommands {
             WalletSubcommands::Vanity(cmd) => {
                 cmd.run()?;
             }
+            WalletSubcommands::Import { account_name, private_key, unsafe_password } => {
+                let keystores_dir = Config::foundry_keystores_dir()
+                    .wrap_err("Could not find the global foundry keystores directory")?;
+                fs::create_dir_all(&keystores_dir)?;
+                let keystore_path = keystores_dir.join(format!("{account_name}.json"));
+                if keystore_path.exists() {
+                    eyre::bail!(
+                        "Keystore `{account_name}` already exists at `{}`.",
+                        keystore_path.display()
+                    )
+                }
+
+                let private_key = if let Some(private_key) = private_key {
+                    private_key
+                } else {
+                    rpassword::prompt_password("Enter private key: ")?
+                };
+                let private_key = private_key.strip_prefix("0x").unwrap_or(&private_key);
+                let wallet = LocalWallet::from_str(private_key)
+                    .wrap_err("Failed to create wallet from private key")?;
+                let private_key_bytes = hex::decode(private_key)?;
+
+                let password = if let Some(password) = unsafe_password {
+                    password
+                } else {
+                    rpassword::prompt_password("Enter password: ")?
+                };
+
+                let mut rng = thread_rng();
+                eth_keystore::encrypt_key(
+                    &keystores_dir,
+                    &mut rng,
+                    private_key_bytes,
+                    password,
+                    Some(&format!("{account_name}.json")),
+                )?;
+
+                println!(
+                    "`{account_name}` keystore was saved successfully. Address: {:?}",
+                    wallet.address()
+                );
+            }
+            WalletSubcommands::List => {
+                let keystores_dir = Config::foundry_keystores_dir()
+                    .wrap_err("Could not find the global foundry keystores directory")?;
+                if !keystores_dir.is_dir() {
+                    return Ok(())
+                }
+                for entry in std::fs::read_dir(keystores_dir)? {
+                    let path = entry?.path();
+                    if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                        continue
+                    }
+                    let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+                        continue
+                    };
+                    match fs::read_json_file::<KeystoreFile>(&path) {
+                        Ok(keystore) => {
+                            println!(
+                                "{name} ({})",
+                                SimpleCast::to_checksum_address(&keystore.address)
+                            )
+                        }
+                        Err(_) => println!("{name} (could not determine address)"),
+                    }
+                }
+            }
             WalletSubcommands::Address { wallet, private_key_override } => {
                 let wallet = EthereumOpts {
                     wallet: private_key_override
@@ -137,7 +245,7 @@ impl WalletSubcommands {
                 };
                 println!("{}", SimpleCast::to_checksum_address(&addr));
             }
-            WalletSubcommands::Sign { message, wallet } => {
+            WalletSubcommands::Sign { message, no_hash, wallet } => {
                 let wallet = EthereumOpts {
                     wallet,
                     rpc_url: Some("http://localhost:8545".to_string()),
@@ -148,11 +256,28 @@ impl WalletSubcommands {
                 .await?
                 .unwrap();
 
-                let sig = match wallet {
-                    WalletType::Ledger(wallet) => wallet.signer().sign_message(&message).await?,
-                    WalletType::Local(wallet) => wallet.signer().sign_message(&message).await?,
-                    WalletType::Trezor(wallet) => wallet.signer().sign_message(&message).await?,
-                    WalletType::Aws(wallet) => wallet.signer().sign_message(&message).await?,
+                let sig = if no_hash {
+                    let hash: H256 = message
+                        .parse()
+                        .wrap_err("Invalid hash, expected a 32-byte hex encoded string")?;
+                    match wallet {
+                        WalletType::Local(wallet) => wallet.signer().sign_hash(hash),
+                        _ => eyre::bail!(
+                            "Signing a raw hash with --no-hash is only supported for private \
+                             key, mnemonic and keystore wallets."
+                        ),
+                    }
+                } else {
+                    match wallet {
+                        WalletType::Ledger(wallet) => {
+                            wallet.signer().sign_message(&message).await?
+                        }
+                        WalletType::Local(wallet) => wallet.signer().sign_message(&message).await?,
+                        WalletType::Trezor(wallet) => {
+                            wallet.signer().sign_message(&message).await?
+                        }
+                        WalletType::Aws(wallet) => wallet.signer().sign_message(&message).await?,
+                    }
                 };
                 println!("Signature: 0x{sig}");
             }
@@ -173,3 +298,59 @@ impl WalletSubcommands {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::signers::{coins_bip39::English, MnemonicBuilder};
+
+    // private key `1`, a canonical test vector used throughout the Foundry/ethers.js ecosystem
+    // (e.g. `vm.addr(1)` in forge scripts) -- known to derive this exact address.
+    const TEST_PRIVATE_KEY: &str =
+        "0000000000000000000000000000000000000000000000000000000000000001";
+    const TEST_ADDRESS: &str = "0x7e5f4552091a69125d5dfcb7b8c2659029395bdf";
+
+    // the default Hardhat/Anvil test mnemonic, whose index-0 account is this well-known address.
+    const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
+    const TEST_MNEMONIC_ADDRESS: &str = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+    #[test]
+    fn private_key_derives_known_address() {
+        let wallet = LocalWallet::from_str(TEST_PRIVATE_KEY).unwrap();
+        let address: Address = TEST_ADDRESS.parse().unwrap();
+        assert_eq!(wallet.address(), address);
+    }
+
+    #[test]
+    fn mnemonic_index_derives_known_address() {
+        let wallet = MnemonicBuilder::<English>::default()
+            .phrase(TEST_MNEMONIC)
+            .index(0u32)
+            .unwrap()
+            .build()
+            .unwrap();
+        let address: Address = TEST_MNEMONIC_ADDRESS.parse().unwrap();
+        assert_eq!(wallet.address(), address);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn personal_sign_round_trips() {
+        let wallet = LocalWallet::from_str(TEST_PRIVATE_KEY).unwrap();
+        let message = "hello world";
+
+        let sig = wallet.sign_message(message).await.unwrap();
+        // a personal_sign signature is the standard 65-byte r||s||v hex string
+        assert_eq!(sig.to_vec().len(), 65);
+        sig.verify(message, wallet.address()).unwrap();
+    }
+
+    #[test]
+    fn no_hash_sign_round_trips() {
+        let wallet = LocalWallet::from_str(TEST_PRIVATE_KEY).unwrap();
+        let hash = H256::random();
+
+        let sig = wallet.sign_hash(hash);
+        assert_eq!(sig.to_vec().len(), 65);
+        assert_eq!(sig.recover(hash).unwrap(), wallet.address());
+    }
+}
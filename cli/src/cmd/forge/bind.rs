@@ -82,11 +82,19 @@ pub struct BindArgs {
 
     #[clap(
         long = "overwrite",
-        help = "Overwrite existing generated bindings. By default, the command will check that the bindings are correct, and then exit. If --overwrite is passed, it will instead delete and overwrite the bindings."
+        help = "Overwrite existing generated bindings. By default, the command will check that the bindings are correct, and then exit. If --overwrite is passed, it will instead delete and overwrite the bindings.",
+        conflicts_with = "check"
     )]
     #[serde(skip)]
     overwrite: bool,
 
+    #[clap(
+        long = "check",
+        help = "Check if the generated bindings are up to date with the latest artifacts. Fails if the bindings are missing entirely or out of date, without writing anything. Intended for CI."
+    )]
+    #[serde(skip)]
+    check: bool,
+
     #[clap(long = "single-file", help = "Generate bindings as a single file.")]
     #[serde(skip)]
     single_file: bool,
@@ -215,6 +223,16 @@ impl Cmd for BindArgs {
 
         let artifacts = self.try_load_config_emit_warnings()?.out;
 
+        if self.check {
+            let bindings_root = self.bindings_root(&artifacts);
+            eyre::ensure!(
+                bindings_root.is_dir(),
+                "bindings not found at {}. Run `forge bind` to generate them.",
+                bindings_root.display()
+            );
+            return self.check_existing_bindings(&artifacts)
+        }
+
         if !self.overwrite && self.bindings_exist(&artifacts) {
             println!("Bindings found. Checking for consistency.");
             return self.check_existing_bindings(&artifacts)
@@ -2,10 +2,11 @@ use crate::{
     cmd::{forge::build::ProjectPathsArgs, LoadConfig},
     opts::forge::CompilerArgs,
 };
-use clap::{Parser, ValueHint};
+use clap::{ArgAction, Parser, ValueHint};
 use ethers::solc::{
     artifacts::RevertStrings, remappings::Remapping, utils::canonicalized, Project,
 };
+use foundry_common::compile::SkipBuildFilter;
 use foundry_config::{
     figment,
     figment::{
@@ -64,6 +65,15 @@ pub struct CoreBuildArgs {
     #[serde(skip)]
     pub no_auto_detect: bool,
 
+    #[clap(
+        help_heading = "Compiler options",
+        long,
+        num_args(1..),
+        action = ArgAction::Append,
+        help = "Skip building whose names contain SKIP. `test` and `script` are aliases for `.t.sol` and `.s.sol`. (this flag can be used multiple times)")]
+    #[serde(skip)]
+    pub skip: Option<Vec<SkipBuildFilter>>,
+
     /// Specify the solc version, or a path to a local solc, to build with.
     ///
     /// Valid values are in the format `x.y.z`, `solc:x.y.z` or `path/to/solc`.
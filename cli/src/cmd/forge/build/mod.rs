@@ -6,7 +6,7 @@ use crate::cmd::{
     },
     Cmd, LoadConfig,
 };
-use clap::{ArgAction, Parser};
+use clap::Parser;
 use ethers::solc::{Project, ProjectCompileOutput};
 use foundry_common::{
     compile,
@@ -69,12 +69,32 @@ pub struct BuildArgs {
     pub sizes: bool,
 
     #[clap(
-        long,
-        num_args(1..),
-        action = ArgAction::Append,
-        help = "Skip building whose names contain SKIP. `test` and `script` are aliases for `.t.sol` and `.s.sol`. (this flag can be used multiple times)")]
+        help = "Include the init code size in the contract size report, as an extra column. Only applicable with --sizes.",
+        long = "init-code-size"
+    )]
     #[serde(skip)]
-    pub skip: Option<Vec<SkipBuildFilter>>,
+    pub init_code_size: bool,
+
+    #[clap(
+        help = "Print the contract size report (if any) as JSON, instead of a table. Only applicable with --sizes.",
+        long = "json"
+    )]
+    #[serde(skip)]
+    pub json: bool,
+
+    #[clap(
+        help = "Additionally write compiled artifacts in the shape Hardhat expects, alongside the native ones.",
+        long = "hardhat-artifacts"
+    )]
+    #[serde(skip)]
+    pub hardhat_artifacts: bool,
+
+    #[clap(
+        help = "Print a compile-time breakdown (per-solc-version file counts/durations, plus the slowest source files) after a successful build.",
+        long = "timings"
+    )]
+    #[serde(skip)]
+    pub timings: bool,
 
     #[clap(flatten)]
     #[serde(skip)]
@@ -87,6 +107,8 @@ impl Cmd for BuildArgs {
         let mut config = self.try_load_config_emit_warnings()?;
         let mut project = config.project()?;
 
+        install::warn_on_lockfile_drift(&config);
+
         if install::install_missing_dependencies(&mut config, &project, self.args.silent) &&
             config.auto_detect_remappings
         {
@@ -95,12 +117,17 @@ impl Cmd for BuildArgs {
             project = config.project()?;
         }
 
-        let filters = self.skip.unwrap_or_default();
+        let filters = self.args.skip.clone().unwrap_or_default();
 
         if self.args.silent {
             compile::suppress_compile_with_filter(&project, filters)
         } else {
-            let compiler = ProjectCompiler::with_filter(self.names, self.sizes, filters);
+            let compiler = ProjectCompiler::with_filter(self.names, self.sizes, filters)
+                .ignore_warnings_from(config.ignore_warnings_from.clone())
+                .print_sizes_json(self.json)
+                .print_init_code_size(self.init_code_size)
+                .hardhat(self.hardhat_artifacts || config.hardhat_artifacts)
+                .timings(self.timings || config.timings);
             compiler.compile(&project)
         }
     }
@@ -127,7 +154,9 @@ impl BuildArgs {
         // use the path arguments or if none where provided the `src` dir
         self.watch.watchexec_config(|| {
             let config = Config::from(self);
-            vec![config.src, config.test, config.script]
+            let mut paths = vec![config.src, config.test, config.script];
+            paths.extend(config.libs);
+            paths
         })
     }
 }
@@ -151,6 +180,14 @@ impl Provider for BuildArgs {
             dict.insert("sizes".to_string(), true.into());
         }
 
+        if self.hardhat_artifacts {
+            dict.insert("hardhat_artifacts".to_string(), true.into());
+        }
+
+        if self.timings {
+            dict.insert("timings".to_string(), true.into());
+        }
+
         Ok(Map::from([(Config::selected_profile(), dict)]))
     }
 }
@@ -162,16 +199,16 @@ mod tests {
     #[test]
     fn can_parse_build_filters() {
         let args: BuildArgs = BuildArgs::parse_from(["foundry-cli", "--skip", "tests"]);
-        assert_eq!(args.skip, Some(vec![SkipBuildFilter::Tests]));
+        assert_eq!(args.args.skip, Some(vec![SkipBuildFilter::Tests]));
 
         let args: BuildArgs = BuildArgs::parse_from(["foundry-cli", "--skip", "scripts"]);
-        assert_eq!(args.skip, Some(vec![SkipBuildFilter::Scripts]));
+        assert_eq!(args.args.skip, Some(vec![SkipBuildFilter::Scripts]));
 
         let args: BuildArgs =
             BuildArgs::parse_from(["foundry-cli", "--skip", "tests", "--skip", "scripts"]);
-        assert_eq!(args.skip, Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts]));
+        assert_eq!(args.args.skip, Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts]));
 
         let args: BuildArgs = BuildArgs::parse_from(["foundry-cli", "--skip", "tests", "scripts"]);
-        assert_eq!(args.skip, Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts]));
+        assert_eq!(args.args.skip, Some(vec![SkipBuildFilter::Tests, SkipBuildFilter::Scripts]));
     }
 }
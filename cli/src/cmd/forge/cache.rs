@@ -53,19 +53,32 @@ pub struct CleanArgs {
 
     #[clap(long, group = "etherscan-blocks")]
     etherscan: bool,
+
+    /// Only clean cached blocks that haven't been used in longer than this, e.g. `30d`, `12h`,
+    /// `45m`.
+    #[clap(long, value_parser = parse_min_age, value_name = "AGE", group = "etherscan-blocks")]
+    older_than: Option<u64>,
 }
 
 impl Cmd for CleanArgs {
     type Output = ();
 
     fn run(self) -> Result<Self::Output> {
-        let CleanArgs { chains, blocks, etherscan } = self;
+        let CleanArgs { chains, blocks, etherscan, older_than } = self;
 
         for chain_or_all in chains {
             match chain_or_all {
-                ChainOrAll::Chain(chain) => clean_chain_cache(chain, blocks.to_vec(), etherscan)?,
+                ChainOrAll::Chain(chain) => {
+                    clean_chain_cache(chain, blocks.to_vec(), etherscan, older_than)?
+                }
                 ChainOrAll::All => {
-                    if etherscan {
+                    if let Some(min_age) = older_than {
+                        for chain in Config::list_foundry_cache()?.chains {
+                            if let Ok(chain) = Chain::from_str(&chain.name) {
+                                clean_chain_cache(chain, Vec::new(), etherscan, Some(min_age))?;
+                            }
+                        }
+                    } else if etherscan {
                         Config::clean_foundry_etherscan_cache()?;
                     } else {
                         Config::clean_foundry_cache()?
@@ -78,6 +91,22 @@ impl Cmd for CleanArgs {
     }
 }
 
+/// Parses a duration like `30d`, `12h`, `45m` or `90s` into a number of seconds.
+fn parse_min_age(s: &str) -> eyre::Result<u64> {
+    let s = s.trim();
+    let (digits, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let value: u64 = digits.parse().map_err(|_| eyre::eyre!("invalid duration: {s}"))?;
+    let multiplier = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => eyre::bail!("unknown duration unit `{unit}`, expected one of s/m/h/d/w"),
+    };
+    Ok(value * multiplier)
+}
+
 #[derive(Debug, Parser)]
 pub struct LsArgs {
     #[clap(
@@ -132,8 +161,28 @@ fn clean_chain_cache(
     chain: impl Into<FoundryConfigChain>,
     blocks: Vec<u64>,
     etherscan: bool,
+    older_than: Option<u64>,
 ) -> Result<()> {
     let chain = chain.into();
+    if let Some(min_age) = older_than {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let cutoff = now.saturating_sub(min_age);
+        for (block, _, last_used) in Config::list_foundry_chain_cache(chain)?.blocks {
+            if last_used != 0 && last_used < cutoff {
+                if let Ok(number) = block.parse::<u64>() {
+                    Config::clean_foundry_block_cache(chain, number)?;
+                }
+            }
+        }
+        if etherscan {
+            Config::clean_foundry_etherscan_chain_cache(chain)?;
+        }
+        return Ok(())
+    }
+
     if blocks.is_empty() {
         Config::clean_foundry_etherscan_chain_cache(chain)?;
         if etherscan {
@@ -191,4 +240,13 @@ mod tests {
         let args: CacheArgs = CacheArgs::parse_from(["cache", "ls"]);
         assert!(matches!(args.sub, CacheSubcommands::Ls(_)));
     }
+
+    #[test]
+    fn can_parse_min_age() {
+        assert_eq!(parse_min_age("30d").unwrap(), 30 * 60 * 60 * 24);
+        assert_eq!(parse_min_age("12h").unwrap(), 12 * 60 * 60);
+        assert_eq!(parse_min_age("45m").unwrap(), 45 * 60);
+        assert_eq!(parse_min_age("90").unwrap(), 90);
+        assert!(parse_min_age("1y").is_err());
+    }
 }
@@ -0,0 +1,133 @@
+//! clean command
+
+use crate::cmd::{forge::build::ProjectPathsArgs, Cmd, LoadConfig};
+use clap::Parser;
+use foundry_common::fs;
+use std::path::Path;
+
+/// CLI arguments for `forge clean`.
+#[derive(Debug, Clone, Parser)]
+pub struct CleanArgs {
+    /// Remove the compiler cache.
+    #[clap(long)]
+    cache: bool,
+
+    /// Remove the compiled artifacts.
+    #[clap(long)]
+    artifacts: bool,
+
+    /// Remove the persisted fuzz failure corpus.
+    #[clap(long)]
+    fuzz: bool,
+
+    /// Remove the generated coverage report.
+    #[clap(long)]
+    coverage: bool,
+
+    /// Remove the broadcast logs.
+    #[clap(long)]
+    broadcast: bool,
+
+    #[clap(flatten)]
+    opts: ProjectPathsArgs,
+}
+
+foundry_config::impl_figment_convert!(CleanArgs, opts);
+
+impl Cmd for CleanArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        let config = self.try_load_config_emit_warnings()?;
+        let root = &config.__root.0;
+
+        // with no target flags given, clean everything, mirroring the previous `forge clean`
+        // behaviour of nuking the whole project
+        let clean_all =
+            !(self.cache || self.artifacts || self.fuzz || self.coverage || self.broadcast);
+
+        if clean_all || self.cache {
+            remove_path(root, &config.cache_path)?;
+        }
+
+        if clean_all || self.artifacts {
+            remove_path(root, &config.out)?;
+            if let Some(build_info_path) = &config.build_info_path {
+                remove_path(root, build_info_path)?;
+            }
+        }
+
+        if clean_all || self.broadcast {
+            remove_path(root, &config.broadcast)?;
+        }
+
+        if clean_all || self.coverage {
+            remove_path(root, &config.coverage.lcov_file)?;
+        }
+
+        if clean_all || self.fuzz {
+            // this version of forge does not persist fuzz failures to disk (proptest's
+            // `failure_persistence` is disabled), so there is nothing on-disk to remove
+            println!(
+                "No persisted fuzz failure corpus found; fuzz failure persistence is disabled."
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes `path` and prints how much disk space was reclaimed.
+///
+/// Refuses to remove anything that isn't contained in `root`, and is a no-op if `path` doesn't
+/// exist.
+fn remove_path(root: &Path, path: &Path) -> eyre::Result<()> {
+    if !path.exists() {
+        return Ok(())
+    }
+
+    if !path.starts_with(root) {
+        eyre::bail!(
+            "Refusing to clean `{}`: it is outside of the project root `{}`",
+            path.display(),
+            root.display()
+        );
+    }
+
+    let size = dir_size(path);
+    if path.is_dir() {
+        fs::remove_dir_all(path)?;
+    } else {
+        fs::remove_file(path)?;
+    }
+
+    println!("Removed `{}` ({})", path.display(), format_size(size));
+    Ok(())
+}
+
+/// Returns the total size in bytes of all files under `path`, or of `path` itself if it's a file.
+fn dir_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Formats a byte count as a human-readable string, e.g. `1.23 MB`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[unit])
+    } else {
+        format!("{size:.2} {}", UNITS[unit])
+    }
+}
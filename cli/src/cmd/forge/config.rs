@@ -19,6 +19,12 @@ pub struct ConfigArgs {
     #[clap(help = "Attempt to fix any configuration warnings.", long)]
     fix: bool,
 
+    #[clap(
+        help = "Strictly validate the loaded config and exit with code 1 if any warnings were found, e.g. unknown or deprecated keys. Useful for gating CI on configuration typos.",
+        long
+    )]
+    check: bool,
+
     // support nested build arguments
     #[clap(flatten)]
     opts: BuildArgs,
@@ -38,7 +44,15 @@ impl Cmd for ConfigArgs {
             return Ok(())
         }
 
-        let config = self.try_load_config_unsanitized_emit_warnings()?;
+        if self.check {
+            let config = self.try_load_config_unsanitized_emit_warnings()?;
+            if !config.__warnings.is_empty() {
+                std::process::exit(1);
+            }
+            return Ok(())
+        }
+
+        let config = self.try_load_config_unsanitized_emit_warnings()?.redacted();
 
         let s = if self.basic {
             let config = config.into_basic();
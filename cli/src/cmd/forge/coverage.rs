@@ -11,15 +11,17 @@ use ethers::{
     abi::Address,
     prelude::{
         artifacts::{Ast, CompactBytecode, CompactDeployedBytecode},
-        Artifact, Bytes, Project, ProjectCompileOutput, U256,
+        Artifact, ArtifactId, Bytes, Project, ProjectCompileOutput, U256,
     },
     solc::{artifacts::contract::CompactContractBytecode, sourcemap::SourceMap},
+    types::H256,
 };
 use eyre::Context;
 use forge::{
     coverage::{
         analysis::SourceAnalyzer, anchors::find_anchors, ContractId, CoverageReport,
-        CoverageReporter, DebugReporter, ItemAnchor, LcovReporter, SummaryReporter,
+        CoverageReporter, CoverageSummary, DebugReporter, HitMap, ItemAnchor, LcovReporter,
+        SummaryReporter,
     },
     executor::{inspector::CheatsConfig, opts::EvmOpts},
     result::SuiteResult,
@@ -27,10 +29,23 @@ use forge::{
     utils::{build_ic_pc_map, ICPCMap},
     MultiContractRunnerBuilder, TestOptions,
 };
-use foundry_common::{compile::ProjectCompiler, evm::EvmArgs, fs};
-use foundry_config::Config;
+use foundry_common::{
+    compile::{ProjectCompiler, SkipBuildFilter},
+    errors::{ExitCode, ExitCodeError},
+    evm::EvmArgs,
+    fs,
+};
+use foundry_config::{Config, CoverageConfig};
+use rayon::prelude::*;
 use semver::Version;
-use std::{collections::HashMap, sync::mpsc::channel, thread};
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::mpsc::channel,
+    thread,
+    time::Instant,
+};
 use tracing::trace;
 
 // Loads project's figment and merges the build cli arguments into it
@@ -43,11 +58,25 @@ pub struct CoverageArgs {
         long,
         value_enum,
         action = ArgAction::Append,
-        default_value = "summary",
-        help = "The report type to use for coverage. This flag can be used multiple times."
+        help = "The report type to use for coverage. This flag can be used multiple times. Defaults to the `coverage.report` config value, or `summary` if unset."
     )]
     report: Vec<CoverageReportKind>,
 
+    #[clap(long, help = "Glob patterns matching source files to exclude from the report.")]
+    exclude: Vec<String>,
+
+    #[clap(long, help = "Path to the lcov report, relative to the project root.")]
+    lcov_file: Option<PathBuf>,
+
+    #[clap(
+        long,
+        help = "The minimum line coverage percentage required, between 0 and 100. If the actual coverage is lower, `forge coverage` exits with an error."
+    )]
+    minimum_coverage: Option<f64>,
+
+    #[clap(long, help = "Include the project's test files in the coverage report.")]
+    include_tests: bool,
+
     #[clap(flatten)]
     filter: Filter,
 
@@ -56,6 +85,12 @@ pub struct CoverageArgs {
 
     #[clap(flatten)]
     opts: CoreBuildArgs,
+
+    #[clap(
+        long,
+        help = "Fail if any collected hit data couldn't be attributed to a contract or source, instead of just printing a diagnostic."
+    )]
+    strict_coverage: bool,
 }
 
 impl CoverageArgs {
@@ -63,12 +98,42 @@ impl CoverageArgs {
     pub fn build_args(&self) -> &CoreBuildArgs {
         &self.opts
     }
+
+    /// Resolves the coverage settings to use, reading the `config`'s `[coverage]` section
+    /// alongside `EvmOpts`, with any CLI flags that were actually passed taking precedence.
+    fn configure(&self, config: &Config) -> CoverageConfig {
+        let mut coverage = config.coverage.clone();
+        if !self.report.is_empty() {
+            coverage.report = self.report.iter().copied().map(Into::into).collect();
+        }
+        if !self.exclude.is_empty() {
+            coverage.exclude = self.exclude.clone();
+        }
+        if let Some(lcov_file) = self.lcov_file.clone() {
+            coverage.lcov_file = lcov_file;
+        }
+        if self.minimum_coverage.is_some() {
+            coverage.minimum_coverage = self.minimum_coverage;
+        }
+        if self.include_tests {
+            coverage.include_tests = true;
+        }
+        if coverage.report.is_empty() {
+            coverage.report = vec![CoverageReportKind::Summary];
+        }
+        coverage
+    }
 }
 
 impl Cmd for CoverageArgs {
     type Output = ();
 
     fn run(self) -> eyre::Result<Self::Output> {
+        // `--skip tests` would leave nothing for coverage to analyse
+        if self.opts.skip.as_deref().unwrap_or_default().contains(&SkipBuildFilter::Tests) {
+            eyre::bail!("cannot skip test files since they are needed to run tests")
+        }
+
         let (mut config, evm_opts) = self.load_config_and_evm_opts_emit_warnings()?;
         let project = config.project()?;
 
@@ -83,12 +148,14 @@ impl Cmd for CoverageArgs {
         // Set fuzz seed so coverage reports are deterministic
         config.fuzz.seed = Some(U256::from_big_endian(&STATIC_FUZZ_SEED));
 
+        let coverage = self.configure(&config);
+
         let (project, output) = self.build(&config)?;
         p_println!(!self.opts.silent => "Analysing contracts...");
-        let report = self.prepare(&config, output.clone())?;
+        let (report, _, _, contracts) = prepare_coverage_report(&config, &coverage, output)?;
 
         p_println!(!self.opts.silent => "Running tests...");
-        self.collect(project, output, report, config, evm_opts)
+        self.collect(project, contracts, report, coverage, config, evm_opts)
     }
 }
 
@@ -109,160 +176,30 @@ impl CoverageArgs {
             project.solc_config.settings.optimizer.details = None;
             project.solc_config.settings.via_ir = None;
 
+            // Coverage analysis is built on top of the AST, so make sure it's always part of the
+            // output selection even if the project's own `extra_output`/settings narrowed it away.
+            project.solc_config.settings =
+                std::mem::take(&mut project.solc_config.settings).with_ast();
+
             project
         };
 
-        let output = ProjectCompiler::default()
+        let skip = self.opts.skip.clone().unwrap_or_default();
+        let output = ProjectCompiler::with_filter(false, false, skip)
+            .ignore_warnings_from(config.ignore_warnings_from.clone())
             .compile(&project)?
             .with_stripped_file_prefixes(project.root());
 
         Ok((project, output))
     }
 
-    /// Builds the coverage report.
-    #[tracing::instrument(name = "prepare coverage", skip_all)]
-    fn prepare(
-        &self,
-        config: &Config,
-        output: ProjectCompileOutput,
-    ) -> eyre::Result<CoverageReport> {
-        let project_paths = config.project_paths();
-
-        // Extract artifacts
-        let (artifacts, sources) = output.into_artifacts_with_sources();
-        let mut report = CoverageReport::default();
-
-        // Collect ASTs and sources
-        let mut versioned_asts: HashMap<Version, HashMap<usize, Ast>> = HashMap::new();
-        let mut versioned_sources: HashMap<Version, HashMap<usize, String>> = HashMap::new();
-        for (path, mut source_file, version) in sources.into_sources_with_version() {
-            // Filter out dependencies
-            if project_paths.has_library_ancestor(std::path::Path::new(&path)) {
-                continue
-            }
-
-            if let Some(ast) = source_file.ast.take() {
-                versioned_asts
-                    .entry(version.clone())
-                    .or_default()
-                    .insert(source_file.id as usize, ast);
-
-                let file = project_paths.root.join(&path);
-                trace!(root=?project_paths.root, ?file, "reading source file");
-
-                versioned_sources.entry(version.clone()).or_default().insert(
-                    source_file.id as usize,
-                    fs::read_to_string(&file)
-                        .wrap_err("Could not read source code for analysis")?,
-                );
-                report.add_source(version, source_file.id as usize, path);
-            }
-        }
-
-        // Get source maps and bytecodes
-        let (source_maps, bytecodes): (SourceMaps, HashMap<ContractId, (Bytes, Bytes)>) = artifacts
-            .into_iter()
-            .map(|(id, artifact)| (id, CompactContractBytecode::from(artifact)))
-            .filter_map(|(id, artifact)| {
-                let contract_id = ContractId {
-                    version: id.version.clone(),
-                    source_id: *report
-                        .get_source_id(id.version, id.source.to_string_lossy().to_string())?,
-                    contract_name: id.name,
-                };
-                let source_maps = (
-                    contract_id.clone(),
-                    (
-                        artifact.get_source_map()?.ok()?,
-                        artifact
-                            .get_deployed_bytecode()
-                            .as_ref()?
-                            .bytecode
-                            .as_ref()?
-                            .source_map()?
-                            .ok()?,
-                    ),
-                );
-                let bytecodes = (
-                    contract_id,
-                    (
-                        artifact
-                            .get_bytecode()
-                            .and_then(|bytecode| dummy_link_bytecode(bytecode.into_owned()))?,
-                        artifact.get_deployed_bytecode().and_then(|bytecode| {
-                            dummy_link_deployed_bytecode(bytecode.into_owned())
-                        })?,
-                    ),
-                );
-
-                Some((source_maps, bytecodes))
-            })
-            .unzip();
-
-        // Build IC -> PC mappings
-        //
-        // The source maps are indexed by *instruction counters*, which are the indexes of
-        // instructions in the bytecode *minus any push bytes*.
-        //
-        // Since our coverage inspector collects hit data using program counters, the anchors also
-        // need to be based on program counters.
-        // TODO: Index by contract ID
-        let ic_pc_maps: HashMap<ContractId, (ICPCMap, ICPCMap)> = bytecodes
-            .iter()
-            .map(|(id, bytecodes)| {
-                // TODO: Creation bytecode as well
-                (
-                    id.clone(),
-                    (
-                        build_ic_pc_map(SpecId::LATEST, bytecodes.0.as_ref()),
-                        build_ic_pc_map(SpecId::LATEST, bytecodes.1.as_ref()),
-                    ),
-                )
-            })
-            .collect();
-
-        // Add coverage items
-        for (version, asts) in versioned_asts.into_iter() {
-            let source_analysis = SourceAnalyzer::new(
-                version.clone(),
-                asts,
-                versioned_sources.remove(&version).ok_or_else(|| {
-                    eyre::eyre!(
-                        "File tree is missing source code, cannot perform coverage analysis"
-                    )
-                })?,
-            )?
-            .analyze()?;
-            let anchors: HashMap<ContractId, Vec<ItemAnchor>> = source_analysis
-                .contract_items
-                .iter()
-                .filter_map(|(contract_id, item_ids)| {
-                    // TODO: Creation source map/bytecode as well
-                    Some((
-                        contract_id.clone(),
-                        find_anchors(
-                            &bytecodes.get(contract_id)?.1,
-                            &source_maps.get(contract_id)?.1,
-                            &ic_pc_maps.get(contract_id)?.1,
-                            item_ids,
-                            &source_analysis.items,
-                        ),
-                    ))
-                })
-                .collect();
-            report.add_items(version, source_analysis.items);
-            report.add_anchors(anchors);
-        }
-
-        Ok(report)
-    }
-
     /// Runs tests, collects coverage data and generates the final report.
     fn collect(
         self,
         project: Project,
-        output: ProjectCompileOutput,
+        contracts: Vec<(ArtifactId, CompactContractBytecode)>,
         mut report: CoverageReport,
+        coverage: CoverageConfig,
         config: Config,
         evm_opts: EvmOpts,
     ) -> eyre::Result<()> {
@@ -278,8 +215,11 @@ impl CoverageArgs {
             .with_fork(evm_opts.get_fork(&config, env.clone()))
             .with_cheats_config(CheatsConfig::new(&config, &evm_opts))
             .with_test_options(TestOptions { fuzz: config.fuzz, ..Default::default() })
+            .with_libraries(
+                config.parsed_libraries()?.with_applied_remappings(&config.project_paths()),
+            )
             .set_coverage(true)
-            .build(root.clone(), output, env, evm_opts)?;
+            .build_with_contracts(root.clone(), contracts, env, evm_opts)?;
 
         // Run tests
         let known_contracts = runner.known_contracts.clone();
@@ -287,32 +227,90 @@ impl CoverageArgs {
         let handle =
             thread::spawn(move || runner.test(&self.filter, Some(tx), Default::default()).unwrap());
 
-        // Add hit data to the coverage report
-        for (artifact_id, hits) in rx
-            .into_iter()
-            .flat_map(|(_, suite)| suite.test_results.into_values())
-            .filter_map(|mut result| result.coverage.take())
-            .flat_map(|hit_maps| {
-                hit_maps.0.into_values().filter_map(|map| {
-                    Some((known_contracts.find_by_code(map.bytecode.as_ref())?.0, map))
-                })
-            })
-        {
-            // TODO: Note down failing tests
-            if let Some(source_id) = report.get_source_id(
-                artifact_id.version.clone(),
-                artifact_id.source.to_string_lossy().to_string(),
-            ) {
-                let source_id = *source_id;
-                // TODO: Distinguish between creation/runtime in a smart way
-                report.add_hit_map(
-                    &ContractId {
-                        version: artifact_id.version.clone(),
-                        source_id,
-                        contract_name: artifact_id.name.clone(),
-                    },
-                    &hits,
-                );
+        // Add hit data to the coverage report as each suite result streams in off the channel,
+        // processing and dropping it (including its traces) before the next one arrives, so peak
+        // memory grows with the number of contracts rather than the number of tests.
+        let mut unidentified_bytecode_hits: HashMap<H256, u64> = HashMap::new();
+        let mut unmapped_artifact_hits: HashMap<String, u64> = HashMap::new();
+        // A cheap checksum of the last hit map applied per bytecode hash, so a hit map that's
+        // byte-for-byte identical to one a prior test already contributed (e.g. shared
+        // constructor/library code every test exercises the same way) isn't re-walked and
+        // re-summed into `report`'s anchors.
+        let mut last_applied: HashMap<H256, u64> = HashMap::new();
+        let mut tests_passed = 0usize;
+        let mut tests_failed = Vec::new();
+        for (suite_name, suite) in rx {
+            for (test_name, mut result) in suite.test_results {
+                if result.success {
+                    tests_passed += 1;
+                } else {
+                    tests_failed.push(format!("{suite_name}::{test_name}"));
+                }
+
+                let Some(hit_maps) = result.coverage.take() else { continue };
+                // Drop the rest of the test result - traces, logs, labeled addresses - now that
+                // its coverage data has been extracted, instead of holding it until the run ends.
+                drop(result);
+
+                for (code_hash, map) in hit_maps.0 {
+                    let total_hits: u64 = map.hits.values().sum();
+
+                    let Some((artifact_id, _)) =
+                        known_contracts.find_by_code(map.bytecode.as_ref())
+                    else {
+                        *unidentified_bytecode_hits.entry(code_hash).or_default() += total_hits;
+                        continue
+                    };
+
+                    let Some(source_id) = report
+                        .get_source_id(
+                            artifact_id.version.clone(),
+                            artifact_id.source.to_string_lossy().to_string(),
+                        )
+                        .copied()
+                    else {
+                        *unmapped_artifact_hits.entry(artifact_id.identifier()).or_default() +=
+                            total_hits;
+                        continue
+                    };
+
+                    let checksum = hit_map_checksum(&map);
+                    if last_applied.get(&code_hash) == Some(&checksum) {
+                        continue
+                    }
+                    last_applied.insert(code_hash, checksum);
+
+                    // TODO: Distinguish between creation/runtime in a smart way
+                    report.add_hit_map(
+                        &ContractId {
+                            version: artifact_id.version.clone(),
+                            source_id,
+                            contract_name: artifact_id.name.clone(),
+                        },
+                        &map,
+                    );
+                }
+            }
+        }
+
+        if !tests_failed.is_empty() {
+            println!("\n{} test(s) failed while collecting coverage:", tests_failed.len());
+            for test in &tests_failed {
+                println!("  - {test}");
+            }
+        }
+        trace!(tests_passed, tests_failed = tests_failed.len(), "collected coverage");
+
+        if !unidentified_bytecode_hits.is_empty() || !unmapped_artifact_hits.is_empty() {
+            println!("\nSome coverage hit data could not be attributed to a source file:");
+            for (code_hash, hits) in &unidentified_bytecode_hits {
+                println!("  - unidentified bytecode {code_hash:?}: {hits} hits dropped");
+            }
+            for (artifact, hits) in &unmapped_artifact_hits {
+                println!("  - {artifact}: no source map for this artifact, {hits} hits dropped");
+            }
+            if self.strict_coverage {
+                eyre::bail!("coverage data was dropped for one or more contracts (see above)");
             }
         }
 
@@ -320,28 +318,289 @@ impl CoverageArgs {
         let _ = handle.join();
 
         // Output final report
-        for report_kind in self.report {
+        for report_kind in &coverage.report {
             match report_kind {
-                CoverageReportKind::Summary => SummaryReporter::default().report(&report),
-                // TODO: Sensible place to put the LCOV file
-                CoverageReportKind::Lcov => {
-                    LcovReporter::new(&mut fs::create_file(root.join("lcov.info"))?).report(&report)
+                foundry_config::CoverageReportKind::Summary => {
+                    SummaryReporter::default().report(&report)
+                }
+                foundry_config::CoverageReportKind::Lcov => {
+                    LcovReporter::new(&mut fs::create_file(root.join(&coverage.lcov_file))?)
+                        .report(&report)
+                }
+                foundry_config::CoverageReportKind::Debug => {
+                    DebugReporter::default().report(&report)
                 }
-                CoverageReportKind::Debug => DebugReporter::default().report(&report),
             }?;
         }
+
+        if let Some(minimum_coverage) = coverage.minimum_coverage {
+            let mut summary = CoverageSummary::default();
+            for (_, file_summary) in report.summary_by_file() {
+                summary += &file_summary;
+            }
+            let actual = if summary.line_count == 0 {
+                100.0
+            } else {
+                summary.line_hits as f64 / summary.line_count as f64 * 100.0
+            };
+            if actual < minimum_coverage {
+                return Err(ExitCodeError::new(
+                    ExitCode::CoverageThreshold,
+                    eyre::eyre!(
+                        "Line coverage is {actual:.2}%, which is below the minimum of {minimum_coverage:.2}%"
+                    ),
+                ))
+            }
+        }
+
         Ok(())
     }
 }
 
+/// The outcome of reading and filtering a single source file, produced in parallel by
+/// [prepare_coverage_report] before the (non-`Sync`) versioned maps are populated serially.
+enum SourceItem {
+    /// Excluded by a library/test/exclude-pattern filter.
+    Skipped,
+    /// Compiled without an AST attached (e.g. a Vyper source).
+    MissingAst,
+    WithAst { path: String, version: Version, source_id: usize, ast: Ast, content: String },
+}
+
+/// Builds the coverage report: AST-derived coverage items and anchors, plus the per-contract
+/// deployment/runtime source maps and IC->PC maps backing them. Also returns the compiled
+/// `(ArtifactId, CompactContractBytecode)` pairs extracted along the way, so the caller can hand
+/// them to [forge::MultiContractRunnerBuilder::build_with_contracts] instead of cloning the whole
+/// [ProjectCompileOutput] just to re-extract the same artifacts a second time.
+///
+/// Pulled out of [CoverageArgs] so `forge test --gas-report-internal` can reuse the same
+/// AST/source-map analysis to attribute gas to internal functions (see
+/// `forge::coverage::anchors::attribute_gas`) without running a full `forge coverage` pass.
+#[tracing::instrument(name = "prepare coverage", skip_all)]
+pub(crate) fn prepare_coverage_report(
+    config: &Config,
+    coverage: &CoverageConfig,
+    output: ProjectCompileOutput,
+) -> eyre::Result<(
+    CoverageReport,
+    SourceMaps,
+    HashMap<ContractId, (ICPCMap, ICPCMap)>,
+    Vec<(ArtifactId, CompactContractBytecode)>,
+)> {
+    let project_paths = config.project_paths();
+    let exclude: Vec<_> = coverage
+        .exclude
+        .iter()
+        .filter_map(|pattern| globset::Glob::new(pattern).ok())
+        .map(|glob| glob.compile_matcher())
+        .collect();
+
+    // Extract artifacts
+    let (artifacts, sources) = output.into_artifacts_with_sources();
+    let mut report = CoverageReport::default();
+
+    let started_at = Instant::now();
+
+    // Collect ASTs and sources, walking each source file in parallel since a `Visitor` only ever
+    // touches the single source it was handed; the resulting `(path, version, ast, content)`
+    // tuples are inserted into `report`/the versioned maps afterwards, since those are shared
+    // state.
+    let source_items = sources
+        .into_sources_with_version()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(path, mut source_file, version)| -> eyre::Result<SourceItem> {
+            let source_path = std::path::Path::new(&path);
+
+            // Filter out dependencies
+            if project_paths.has_library_ancestor(source_path) {
+                return Ok(SourceItem::Skipped)
+            }
+
+            // Filter out the project's own test files, unless explicitly requested
+            if !coverage.include_tests && source_path.starts_with(&project_paths.tests) {
+                return Ok(SourceItem::Skipped)
+            }
+
+            // Filter out files matching any of the configured exclude patterns
+            if exclude.iter().any(|matcher| matcher.is_match(source_path)) {
+                return Ok(SourceItem::Skipped)
+            }
+
+            let source_id = source_file.id as usize;
+            let Some(ast) = source_file.ast.take() else { return Ok(SourceItem::MissingAst) };
+
+            let file = project_paths.root.join(&path);
+            trace!(root=?project_paths.root, ?file, "reading source file");
+            let content =
+                fs::read_to_string(&file).wrap_err("Could not read source code for analysis")?;
+
+            Ok(SourceItem::WithAst { path, version, source_id, ast, content })
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    let mut versioned_asts: HashMap<Version, HashMap<usize, Ast>> = HashMap::new();
+    let mut versioned_sources: HashMap<Version, HashMap<usize, String>> = HashMap::new();
+    let mut missing_ast = false;
+    let mut num_sources = 0;
+    for item in source_items {
+        match item {
+            SourceItem::Skipped => {}
+            SourceItem::MissingAst => missing_ast = true,
+            SourceItem::WithAst { path, version, source_id, ast, content } => {
+                versioned_asts.entry(version.clone()).or_default().insert(source_id, ast);
+                versioned_sources.entry(version.clone()).or_default().insert(source_id, content);
+                report.add_source(version, source_id, path);
+                num_sources += 1;
+            }
+        }
+    }
+
+    // Convert the artifacts once; `contracts` is handed back to the caller so it can build the
+    // test runner from it directly, instead of cloning the whole `ProjectCompileOutput` just to
+    // re-derive the same pairs a second time.
+    let contracts: Vec<(ArtifactId, CompactContractBytecode)> = artifacts
+        .into_par_iter()
+        .map(|(id, artifact)| (id, CompactContractBytecode::from(artifact)))
+        .collect();
+
+    // Get source maps and bytecodes
+    let (source_maps, bytecodes): (SourceMaps, HashMap<ContractId, (Bytes, Bytes)>) = contracts
+        .par_iter()
+        .filter_map(|(id, artifact)| {
+            let contract_id = ContractId {
+                version: id.version.clone(),
+                source_id: *report
+                    .get_source_id(id.version.clone(), id.source.to_string_lossy().to_string())?,
+                contract_name: id.name.clone(),
+            };
+            let source_maps = (
+                contract_id.clone(),
+                (
+                    artifact.get_source_map()?.ok()?,
+                    artifact
+                        .get_deployed_bytecode()
+                        .as_ref()?
+                        .bytecode
+                        .as_ref()?
+                        .source_map()?
+                        .ok()?,
+                ),
+            );
+            let bytecodes = (
+                contract_id,
+                (
+                    artifact
+                        .get_bytecode()
+                        .and_then(|bytecode| dummy_link_bytecode(bytecode.into_owned()))?,
+                    artifact
+                        .get_deployed_bytecode()
+                        .and_then(|bytecode| dummy_link_deployed_bytecode(bytecode.into_owned()))?,
+                ),
+            );
+
+            Some((source_maps, bytecodes))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .unzip();
+
+    trace!("Analysed {num_sources} sources in {:.1}s", started_at.elapsed().as_secs_f64());
+
+    // Build IC -> PC mappings
+    //
+    // The source maps are indexed by *instruction counters*, which are the indexes of
+    // instructions in the bytecode *minus any push bytes*.
+    //
+    // Since our coverage inspector collects hit data using program counters, the anchors also
+    // need to be based on program counters.
+    // TODO: Index by contract ID
+    let ic_pc_maps: HashMap<ContractId, (ICPCMap, ICPCMap)> = bytecodes
+        .iter()
+        .map(|(id, bytecodes)| {
+            // TODO: Creation bytecode as well
+            (
+                id.clone(),
+                (
+                    build_ic_pc_map(SpecId::LATEST, bytecodes.0.as_ref()),
+                    build_ic_pc_map(SpecId::LATEST, bytecodes.1.as_ref()),
+                ),
+            )
+        })
+        .collect();
+
+    // Add coverage items
+    for (version, asts) in versioned_asts.into_iter() {
+        let source_analysis = SourceAnalyzer::new(
+            version.clone(),
+            asts,
+            versioned_sources.remove(&version).ok_or_else(|| {
+                eyre::eyre!("File tree is missing source code, cannot perform coverage analysis")
+            })?,
+        )?
+        .analyze()?;
+        let anchors: HashMap<ContractId, Vec<ItemAnchor>> = source_analysis
+            .contract_items
+            .iter()
+            .filter_map(|(contract_id, item_ids)| {
+                // TODO: Creation source map/bytecode as well
+                Some((
+                    contract_id.clone(),
+                    find_anchors(
+                        &bytecodes.get(contract_id)?.1,
+                        &source_maps.get(contract_id)?.1,
+                        &ic_pc_maps.get(contract_id)?.1,
+                        item_ids,
+                        &source_analysis.items,
+                    ),
+                ))
+            })
+            .collect();
+        report.add_items(version, source_analysis.items);
+        report.add_anchors(anchors);
+    }
+
+    // If nothing produced any coverage items and at least one source was missing its AST,
+    // this is almost certainly the AST having been compiled away rather than the project
+    // genuinely having no coverable code, so fail loudly instead of printing an empty report.
+    if missing_ast && report.items.values().all(|items| items.is_empty()) {
+        eyre::bail!(
+            "No coverage data could be collected because the compiler output is missing the \
+             AST for one or more sources. Coverage requires the `ast` output selection; check \
+             that the project's `extra_output`/solc `settings` don't remove it."
+        )
+    }
+
+    Ok((report, source_maps, ic_pc_maps, contracts))
+}
+
 // TODO: HTML
-#[derive(Debug, Clone, ValueEnum)]
+#[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum CoverageReportKind {
     Summary,
     Lcov,
     Debug,
 }
 
+impl From<CoverageReportKind> for foundry_config::CoverageReportKind {
+    fn from(kind: CoverageReportKind) -> Self {
+        match kind {
+            CoverageReportKind::Summary => foundry_config::CoverageReportKind::Summary,
+            CoverageReportKind::Lcov => foundry_config::CoverageReportKind::Lcov,
+            CoverageReportKind::Debug => foundry_config::CoverageReportKind::Debug,
+        }
+    }
+}
+
+/// A cheap checksum of a [HitMap]'s hit counts, used to detect when two hit maps for the same
+/// bytecode hash have identical hit counts so the second one can be skipped instead of
+/// re-applied.
+fn hit_map_checksum(map: &HitMap) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    map.hits.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Helper function that will link references in unlinked bytecode to the 0 address.
 ///
 /// This is needed in order to analyze the bytecode for contracts that use libraries.
@@ -6,14 +6,16 @@ use crate::{
         LoadConfig,
     },
     opts::{EthereumOpts, TransactionOpts, WalletType},
+    utils::parse_u256,
 };
-use cast::SimpleCast;
+use cast::{executor::inspector::DEFAULT_CREATE2_DEPLOYER, SimpleCast};
 use clap::{Parser, ValueHint};
 use ethers::{
     abi::{Abi, Constructor, Token},
     prelude::{artifacts::BytecodeObject, ContractFactory, Middleware},
     solc::{info::ContractInfo, utils::canonicalized},
-    types::{transaction::eip2718::TypedTransaction, Chain},
+    types::{transaction::eip2718::TypedTransaction, Chain, U256},
+    utils::{get_create2_address_from_hash, keccak256},
 };
 use eyre::Context;
 use foundry_common::{abi::parse_tokens, compile, estimate_eip1559_fees, try_get_http_provider};
@@ -77,6 +79,22 @@ pub struct CreateArgs {
     )]
     unlocked: bool,
 
+    #[clap(
+        long,
+        help = "Deploy via the canonical CREATE2 factory so the resulting address is deterministic across chains. Requires --salt.",
+        requires = "salt"
+    )]
+    create2: bool,
+
+    #[clap(
+        long,
+        help = "The salt for the CREATE2 deployment, as a hex or decimal uint256. Requires --create2.",
+        value_parser = parse_u256,
+        value_name = "SALT",
+        requires = "create2"
+    )]
+    salt: Option<U256>,
+
     #[clap(flatten)]
     pub verifier: verify::VerifierArgs,
 
@@ -147,12 +165,29 @@ impl CreateArgs {
         // Deploy with signer
         let chain_id = provider.get_chainid().await?;
         match self.eth.signer_with(chain_id, provider).await? {
-            Some(signer) => match signer {
-                WalletType::Ledger(signer) => self.deploy(abi, bin, params, signer).await?,
-                WalletType::Local(signer) => self.deploy(abi, bin, params, signer).await?,
-                WalletType::Trezor(signer) => self.deploy(abi, bin, params, signer).await?,
-                WalletType::Aws(signer) => self.deploy(abi, bin, params, signer).await?,
-            },
+            Some(signer) => {
+                let from = match &signer {
+                    WalletType::Ledger(signer) => signer.address(),
+                    WalletType::Local(signer) => signer.address(),
+                    WalletType::Trezor(signer) => signer.address(),
+                    WalletType::Aws(signer) => signer.address(),
+                };
+
+                // prevent misconfigured hwlib from deploying from an address that defies
+                // user-specified --from
+                if let Some(specified_from) = self.eth.wallet.from {
+                    if specified_from != from {
+                        eyre::bail!("The specified sender via CLI/env vars does not match the sender configured via the hardware wallet's HD Path. Please use the `--hd-path <PATH>` parameter to specify the BIP32 Path which corresponds to the sender. This will be automatically detected in the future: https://github.com/foundry-rs/foundry/issues/2289")
+                    }
+                }
+
+                match signer {
+                    WalletType::Ledger(signer) => self.deploy(abi, bin, params, signer).await?,
+                    WalletType::Local(signer) => self.deploy(abi, bin, params, signer).await?,
+                    WalletType::Trezor(signer) => self.deploy(abi, bin, params, signer).await?,
+                    WalletType::Aws(signer) => self.deploy(abi, bin, params, signer).await?,
+                }
+            }
             None => eyre::bail!("could not find artifact"),
         };
 
@@ -229,6 +264,37 @@ impl CreateArgs {
             deployer.tx.set_value(value);
         }
 
+        // route the deployment through the canonical CREATE2 factory so the resulting address
+        // only depends on the sender, salt and init code, instead of the deployer's nonce
+        let mut create2_address = None;
+        if self.create2 {
+            let salt = self.salt.expect("--salt is required with --create2");
+            let init_code = deployer.tx.data().cloned().unwrap_or_default();
+
+            let mut salt_bytes = [0u8; 32];
+            salt.to_big_endian(&mut salt_bytes);
+            let init_code_hash = keccak256(&init_code);
+            let predicted_address =
+                get_create2_address_from_hash(DEFAULT_CREATE2_DEPLOYER, salt_bytes, init_code_hash);
+
+            println!(
+                "Predicted contract address: {}",
+                SimpleCast::to_checksum_address(&predicted_address)
+            );
+
+            if !provider.get_code(predicted_address, None).await?.is_empty() {
+                println!("A contract is already deployed at the predicted address, skipping CREATE2 deployment.");
+                return Ok(())
+            }
+
+            let mut calldata = Vec::with_capacity(32 + init_code.len());
+            calldata.extend_from_slice(&salt_bytes);
+            calldata.extend_from_slice(&init_code);
+            deployer.tx.set_to(DEFAULT_CREATE2_DEPLOYER);
+            deployer.tx.set_data(calldata.into());
+            create2_address = Some(predicted_address);
+        }
+
         // fill tx first because if you target a lower gas than current base, eth_estimateGas
         // will fail and create will fail
         provider.fill_transaction(&mut deployer.tx, None).await?;
@@ -273,40 +339,69 @@ impl CreateArgs {
             };
         }
 
+        // ABI-encode the constructor arguments up front, both so we can hand them to `--verify`
+        // and so they can be reported in the `--json` output for out-of-band verification.
+        let constructor_args = if !args.is_empty() {
+            // we're passing an empty vec to the `encode_input` of the constructor because we
+            // only need the constructor arguments and the encoded input is
+            // `code + args`
+            let code = Vec::new();
+            let encoded_args = abi
+                .constructor()
+                .ok_or(eyre::eyre!("could not find constructor"))?
+                .encode_input(code, &args)?
+                .to_hex::<String>();
+            Some(encoded_args)
+        } else {
+            None
+        };
+
         // Before we actually deploy the contract we try check if the verify settings are valid
-        let mut constructor_args = None;
         if self.verify {
-            if !args.is_empty() {
-                // we're passing an empty vec to the `encode_input` of the constructor because we
-                // only need the constructor arguments and the encoded input is
-                // `code + args`
-                let code = Vec::new();
-                let encoded_args = abi
-                    .constructor()
-                    .ok_or(eyre::eyre!("could not find constructor"))?
-                    .encode_input(code, &args)?
-                    .to_hex::<String>();
-                constructor_args = Some(encoded_args);
-            }
-
             self.verify_preflight_check(constructor_args.clone(), chain).await?;
         }
 
         // Deploy the actual contract
-        let (deployed_contract, receipt) = deployer.send_with_receipt().await?;
-
-        let address = deployed_contract.address();
+        //
+        // `Deployer::send_with_receipt` reads the deployed address off `receipt.contract_address`,
+        // which is only populated for genuine (`to == None`) contract-creation transactions. A
+        // CREATE2 deployment is a regular call to the factory, so we send it directly and rely on
+        // the address we already predicted instead.
+        let (address, receipt) = if let Some(predicted_address) = create2_address {
+            let pending_tx = provider.send_transaction(deployer.tx.clone(), None).await?;
+            let receipt = pending_tx
+                .await?
+                .ok_or_else(|| eyre::eyre!("Failed to get transaction receipt for deployment"))?;
+            // unlike `send_with_receipt()`, which only extracts `contract_address` from a
+            // successful receipt, we're trusting `predicted_address` as the deployed address
+            // ourselves - so we need our own check that the factory call didn't revert.
+            if receipt.status.unwrap_or_default().is_zero() {
+                eyre::bail!(
+                    "CREATE2 deployment transaction {:?} reverted - no contract was deployed at {}",
+                    receipt.transaction_hash,
+                    SimpleCast::to_checksum_address(&predicted_address)
+                );
+            }
+            (predicted_address, receipt)
+        } else {
+            let (deployed_contract, receipt) = deployer.send_with_receipt().await?;
+            (deployed_contract.address(), receipt)
+        };
         if self.json {
             let output = json!({
                 "deployer": SimpleCast::to_checksum_address(&deployer_address),
                 "deployedTo": SimpleCast::to_checksum_address(&address),
-                "transactionHash": receipt.transaction_hash
+                "transactionHash": receipt.transaction_hash,
+                "constructorArgs": constructor_args.as_deref().unwrap_or("")
             });
             println!("{output}");
         } else {
             println!("Deployer: {}", SimpleCast::to_checksum_address(&deployer_address));
             println!("Deployed to: {}", SimpleCast::to_checksum_address(&address));
             println!("Transaction hash: {:?}", receipt.transaction_hash);
+            if let Some(ref constructor_args) = constructor_args {
+                println!("Constructor args: {constructor_args}");
+            }
         };
 
         if !self.verify {
@@ -373,4 +468,38 @@ mod tests {
         assert_eq!(args.retry.retries, 10);
         assert_eq!(args.retry.delay, 30);
     }
+
+    #[test]
+    fn can_parse_create2() {
+        let args: CreateArgs = CreateArgs::parse_from([
+            "foundry-cli",
+            "src/Domains.sol:Domains",
+            "--create2",
+            "--salt",
+            "0x1",
+        ]);
+        assert!(args.create2);
+        assert_eq!(args.salt, Some(U256::from(1)));
+    }
+
+    #[test]
+    fn create2_requires_salt() {
+        let result =
+            CreateArgs::try_parse_from(["foundry-cli", "src/Domains.sol:Domains", "--create2"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn create2_address_matches_eip1014_vector() {
+        // https://eips.ethereum.org/EIPS/eip-1014
+        let deployer =
+            "0x0000000000000000000000000000000000000000".parse::<ethers::types::Address>().unwrap();
+        let salt = [0u8; 32];
+        let init_code_hash = keccak256([0x00]);
+        let address = get_create2_address_from_hash(deployer, salt, init_code_hash);
+        assert_eq!(
+            address,
+            "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38".parse::<ethers::types::Address>().unwrap()
+        );
+    }
 }
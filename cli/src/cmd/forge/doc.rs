@@ -25,10 +25,17 @@ pub struct DocArgs {
     )]
     out: Option<PathBuf>,
 
-    #[clap(help = "Build the `mdbook` from generated files.", long, short)]
+    #[clap(help = "Build the `mdbook` from generated files.", long, short, conflicts_with = "md")]
     build: bool,
 
-    #[clap(help = "Serve the documentation.", long, short)]
+    #[clap(
+        help = "Output plain markdown only, skipping the mdbook scaffolding (summary, book config, static assets).",
+        long,
+        conflicts_with_all = &["build", "serve"]
+    )]
+    md: bool,
+
+    #[clap(help = "Serve the documentation.", long, short, conflicts_with = "md")]
     serve: bool,
 
     #[clap(help = "Hostname for serving documentation.", long, requires = "serve")]
@@ -78,6 +85,7 @@ impl Cmd for DocArgs {
 
         DocBuilder::new(root.clone(), config.project_paths().sources)
             .with_should_build(self.build)
+            .with_md(self.md)
             .with_config(doc_config.clone())
             .with_fmt(config.fmt)
             .with_preprocessor(ContractInheritance::default())
@@ -3,8 +3,11 @@ use crate::cmd::{
     Cmd, LoadConfig,
 };
 use clap::{Parser, ValueHint};
+use ethers::solc::artifacts::Source;
 use foundry_common::fs;
-use std::path::PathBuf;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::{collections::BTreeMap, path::PathBuf};
 
 /// CLI arguments for `forge flatten`.
 #[derive(Debug, Clone, Parser)]
@@ -38,6 +41,7 @@ impl Cmd for FlattenArgs {
             compiler: Default::default(),
             ignored_error_codes: vec![],
             deny_warnings: false,
+            skip: None,
             no_auto_detect: false,
             use_solc: None,
             offline: false,
@@ -51,12 +55,20 @@ impl Cmd for FlattenArgs {
         };
 
         let config = build_args.try_load_config_emit_warnings()?;
+        let project = config.project()?;
 
-        let paths = config.project_paths();
         let target_path = dunce::canonicalize(target_path)?;
-        let flattened = paths
+        let flattened = project
+            .paths
             .flatten(&target_path)
             .map_err(|err| eyre::Error::msg(format!("Failed to flatten the file: {err}")))?;
+        let flattened = normalize_flattened_source(&flattened)?;
+
+        // the whole point of flattening is to produce something that compiles standalone with the
+        // same compiler as the rest of the project; catch mistakes here instead of shipping them
+        if let Ok(version) = project.solc.version() {
+            check_flattened_compiles(&flattened, &version)?;
+        }
 
         match output {
             Some(output) => {
@@ -70,3 +82,127 @@ impl Cmd for FlattenArgs {
         Ok(())
     }
 }
+
+static SPDX_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\s*//\s*SPDX-License-Identifier:\s*(.+?)\s*$").unwrap());
+static PRAGMA_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\s*pragma\s+solidity\s+([^;]+);\s*$").unwrap());
+static DECLARATION_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^(?:abstract\s+)?(?:contract|library|interface)\s+(\w+)\b").unwrap()
+});
+
+/// Fixes up the output of [`ProjectPathsConfig::flatten`] so it's more likely to actually compile:
+/// hoists a single SPDX comment listing every license encountered, collapses identical `pragma
+/// solidity` statements into one (erroring if they genuinely disagree, since we can't safely pick
+/// a version range for the caller), and collapses duplicate top-level declarations that diamond
+/// imports cause the same file to be inlined more than once for (erroring if two declarations
+/// share a name but aren't byte-identical, since that's a genuine conflict, not a duplicate).
+fn normalize_flattened_source(flattened: &str) -> eyre::Result<String> {
+    let mut licenses: Vec<String> = Vec::new();
+    for cap in SPDX_RE.captures_iter(flattened) {
+        let license = cap[1].trim().to_string();
+        if !licenses.contains(&license) {
+            licenses.push(license);
+        }
+    }
+    let body = SPDX_RE.replace_all(flattened, "");
+
+    let mut pragmas: Vec<String> = Vec::new();
+    for cap in PRAGMA_RE.captures_iter(&body) {
+        let pragma = cap[1].trim().to_string();
+        if !pragmas.contains(&pragma) {
+            pragmas.push(pragma);
+        }
+    }
+    let body = PRAGMA_RE.replace_all(&body, "");
+    if pragmas.len() > 1 {
+        eyre::bail!(
+            "Flattened file has conflicting `pragma solidity` version ranges that can't be safely merged:\n    {}",
+            pragmas.join("\n    ")
+        )
+    }
+
+    let body = dedupe_declarations(&body)?;
+
+    let mut header = String::new();
+    if !licenses.is_empty() {
+        header.push_str(&format!("// SPDX-License-Identifier: {}\n", licenses.join(" AND ")));
+    }
+    if let Some(pragma) = pragmas.first() {
+        header.push_str(&format!("pragma solidity {pragma};\n"));
+    }
+
+    Ok(format!("{header}\n{}", body.trim_start_matches('\n')))
+}
+
+/// Splits `body` into chunks at each top-level `contract`/`library`/`interface` declaration and
+/// drops exact duplicates of the same name, e.g. the same library inlined twice because two
+/// different files import it (a "diamond" import). A name that appears more than once with
+/// differing bodies is a genuine conflict, not a duplicate, so that's reported instead.
+fn dedupe_declarations(body: &str) -> eyre::Result<String> {
+    let starts: Vec<_> =
+        DECLARATION_RE.captures_iter(body).map(|cap| cap.get(0).unwrap().start()).collect();
+    if starts.is_empty() {
+        return Ok(body.to_string());
+    }
+
+    let mut chunks: Vec<(String, String)> = Vec::new();
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(body.len());
+        let chunk = &body[start..end];
+        let name = DECLARATION_RE.captures(chunk).unwrap()[1].to_string();
+        chunks.push((name, chunk.to_string()));
+    }
+
+    let mut seen: BTreeMap<String, String> = BTreeMap::new();
+    let mut order: Vec<String> = Vec::new();
+    for (name, chunk) in chunks {
+        match seen.get(&name) {
+            Some(existing) if existing.trim() == chunk.trim() => {
+                // exact duplicate from a diamond import, drop it
+            }
+            Some(existing) if existing.trim() != chunk.trim() => {
+                eyre::bail!(
+                    "Flattened file has two different declarations named `{name}`, can't be flattened into a single file"
+                )
+            }
+            _ => {
+                seen.insert(name.clone(), chunk);
+                order.push(name);
+            }
+        }
+    }
+
+    let preamble = &body[..starts[0]];
+    let mut out = preamble.to_string();
+    for name in order {
+        out.push_str(&seen[&name]);
+    }
+    Ok(out)
+}
+
+/// Dry-compiles `content` with `version` to catch a flattening bug before it reaches disk.
+fn check_flattened_compiles(content: &str, version: &semver::Version) -> eyre::Result<()> {
+    use ethers::solc::{CompilerInput, Solc};
+
+    let solc = Solc::find_svm_installed_version(version.to_string())?
+        .unwrap_or(Solc::blocking_install(version)?);
+
+    let input = CompilerInput {
+        language: "Solidity".to_string(),
+        sources: BTreeMap::from([("contract.sol".into(), Source { content: content.into() })]),
+        settings: Default::default(),
+    };
+
+    let out = solc.compile(&input)?;
+    if out.has_error() {
+        let mut aggregated = ethers::solc::AggregatedCompilerOutput::default();
+        aggregated.extend(version.clone(), out);
+        eyre::bail!(
+            "Flattened file does not compile with solc {version}:\n\n{}",
+            aggregated.diagnostics(&[], Default::default())
+        )
+    }
+
+    Ok(())
+}
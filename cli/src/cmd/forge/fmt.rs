@@ -3,13 +3,13 @@ use crate::{
     utils::FoundryPathExt,
 };
 use clap::{Parser, ValueHint};
-use console::{style, Style};
-use forge_fmt::{format, parse};
+use forge_fmt::{format, offset_to_line_column, parse};
 use foundry_common::{fs, term::cli_warn};
 use foundry_config::{impl_figment_convert_basic, Config};
 use foundry_utils::glob::expand_globs;
 use rayon::prelude::*;
-use similar::{ChangeTag, TextDiff};
+use similar::TextDiff;
+use solang_parser::diagnostics::Diagnostic;
 use std::{
     fmt::{self, Write},
     io,
@@ -17,6 +17,7 @@ use std::{
     path::{Path, PathBuf},
 };
 use tracing::log::warn;
+use yansi::Paint;
 
 /// CLI arguments for `forge fmt`.
 #[derive(Debug, Clone, Parser)]
@@ -48,6 +49,8 @@ pub struct FmtArgs {
         short
     )]
     raw: bool,
+    #[clap(help = "disable terminal colours in the diff printed by 'check' and stdin modes", long)]
+    no_color: bool,
 }
 
 impl_figment_convert_basic!(FmtArgs);
@@ -114,20 +117,20 @@ impl Cmd for FmtArgs {
             return Ok(())
         }
 
-        let diffs = inputs
+        let outputs = inputs
             .par_iter()
-            .map(|input| {
+            .map(|input| -> eyre::Result<Option<FmtOutput>> {
                 let source = match input {
                     Input::Path(path) => fs::read_to_string(path)?,
                     Input::Stdin(source) => source.to_string()
                 };
 
-                let parsed = parse(&source)
-                    .map_err(|diags| eyre::eyre!(
-                            "Failed to parse Solidity code for {}. Leaving source unchanged.\nDebug info: {:?}",
-                            input,
-                            diags
-                        ))?;
+                let parsed = match parse(&source) {
+                    Ok(parsed) => parsed,
+                    Err(diags) => return Ok(Some(FmtOutput::ParseError(format_diagnostics(
+                        input, &source, &diags,
+                    )))),
+                };
 
                 if !parsed.invalid_inline_config_items.is_empty() {
                     let path = match input {
@@ -148,58 +151,19 @@ impl Cmd for FmtArgs {
                 let mut output = String::new();
                 format(&mut output, parsed, config.fmt.clone()).unwrap();
 
-                solang_parser::parse(&output, 0).map_err(|diags| {
-                    eyre::eyre!(
-                            "Failed to construct valid Solidity code for {}. Leaving source unchanged.\nDebug info: {:?}",
-                            input,
-                            diags
-                        )
-                })?;
+                if let Err(diags) = solang_parser::parse(&output, 0) {
+                    return Ok(Some(FmtOutput::ParseError(format_diagnostics(
+                        input, &output, &diags,
+                    ))))
+                }
 
                 if self.check || matches!(input, Input::Stdin(_)) {
                     if self.raw {
                         print!("{output}");
                     }
 
-                    let diff = TextDiff::from_lines(&source, &output);
-
-                    if diff.ratio() < 1.0 {
-                        let mut diff_summary = String::new();
-
-                        writeln!(diff_summary, "Diff in {input}:")?;
-                        for (j, group) in diff.grouped_ops(3).iter().enumerate() {
-                            if j > 0 {
-                                writeln!(diff_summary, "{:-^1$}", "-", 80)?;
-                            }
-                            for op in group {
-                                for change in diff.iter_inline_changes(op) {
-                                    let (sign, s) = match change.tag() {
-                                        ChangeTag::Delete => ("-", Style::new().red()),
-                                        ChangeTag::Insert => ("+", Style::new().green()),
-                                        ChangeTag::Equal => (" ", Style::new().dim()),
-                                    };
-                                    write!(
-                                        diff_summary,
-                                        "{}{} |{}",
-                                        style(Line(change.old_index())).dim(),
-                                        style(Line(change.new_index())).dim(),
-                                        s.apply_to(sign).bold(),
-                                    )?;
-                                    for (emphasized, value) in change.iter_strings_lossy() {
-                                        if emphasized {
-                                            write!(diff_summary, "{}", s.apply_to(value).underlined().on_black())?;
-                                        } else {
-                                            write!(diff_summary, "{}", s.apply_to(value))?;
-                                        }
-                                    }
-                                    if change.missing_newline() {
-                                        writeln!(diff_summary)?;
-                                    }
-                                }
-                            }
-                        }
-
-                        return Ok(Some(diff_summary))
+                    if let Some(diff) = unified_diff(input, &source, &output, self.no_color)? {
+                        return Ok(Some(FmtOutput::Diff(diff)))
                     }
                 } else if let Input::Path(path) = input {
                     fs::write(path, output)?;
@@ -210,7 +174,23 @@ impl Cmd for FmtArgs {
             .collect::<eyre::Result<Vec<_>>>()?
             .into_iter()
             .flatten()
-            .collect::<Vec<String>>();
+            .collect::<Vec<FmtOutput>>();
+
+        let mut diffs = Vec::new();
+        let mut has_parse_errors = false;
+        for output in outputs {
+            match output {
+                FmtOutput::Diff(diff) => diffs.push(diff),
+                FmtOutput::ParseError(message) => {
+                    has_parse_errors = true;
+                    eprint!("{message}");
+                }
+            }
+        }
+
+        if has_parse_errors {
+            std::process::exit(2);
+        }
 
         if !diffs.is_empty() {
             // This branch is only reachable with stdin or --check
@@ -233,7 +213,63 @@ impl Cmd for FmtArgs {
     }
 }
 
-struct Line(Option<usize>);
+/// The per-input result of a format pass that the caller needs to act on.
+enum FmtOutput {
+    /// A unified diff between the original and formatted source.
+    Diff(String),
+    /// `input` failed to parse, either before or after formatting, with one message per
+    /// diagnostic, already annotated with `file:line:column`.
+    ParseError(String),
+}
+
+/// Renders `diags` as `input:line:column: message` lines, one per diagnostic, so they can be
+/// consumed by editors and CI annotation tools.
+fn format_diagnostics(input: &Input, src: &str, diags: &[Diagnostic]) -> String {
+    let mut out = String::new();
+    for diag in diags {
+        let (line, col) = offset_to_line_column(src, diag.loc.start());
+        let _ = writeln!(out, "{input}:{line}:{col}: {}", diag.message);
+    }
+    out
+}
+
+/// Returns a unified diff between `source` and `output`, or `None` if they're identical.
+fn unified_diff(
+    input: &Input,
+    source: &str,
+    output: &str,
+    no_color: bool,
+) -> eyre::Result<Option<String>> {
+    let diff = TextDiff::from_lines(source, output);
+    if diff.ratio() == 1.0 {
+        return Ok(None)
+    }
+
+    let old_name = format!("a/{input}");
+    let new_name = format!("b/{input}");
+    let text = diff.unified_diff().context_radius(3).header(&old_name, &new_name).to_string();
+
+    if no_color {
+        return Ok(Some(text))
+    }
+
+    let mut colored = String::new();
+    for line in text.lines() {
+        let painted = if line.starts_with("+++") || line.starts_with("---") {
+            Paint::new(line).bold().to_string()
+        } else if line.starts_with("@@") {
+            Paint::cyan(line).to_string()
+        } else if line.starts_with('+') {
+            Paint::green(line).to_string()
+        } else if line.starts_with('-') {
+            Paint::red(line).to_string()
+        } else {
+            line.to_string()
+        };
+        writeln!(colored, "{painted}")?;
+    }
+    Ok(Some(colored))
+}
 
 #[derive(Debug)]
 enum Input {
@@ -249,12 +285,3 @@ impl fmt::Display for Input {
         }
     }
 }
-
-impl fmt::Display for Line {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.0 {
-            None => write!(f, "    "),
-            Some(idx) => write!(f, "{:<4}", idx + 1),
-        }
-    }
-}
@@ -81,8 +81,12 @@ impl UploadSelectorsArgs {
 
             println!("Uploading selectors for {contract}...");
 
-            // upload abi to selector database
-            import_selectors(SelectorImportData::Abi(vec![abi])).await?.describe();
+            // upload abi to selector database, but don't let a network failure on one
+            // contract's batch stop the rest from being uploaded
+            match import_selectors(SelectorImportData::Abi(vec![abi])).await {
+                Ok(response) => response.describe(),
+                Err(err) => eprintln!("Failed to upload selectors for {contract}: {err}"),
+            }
 
             if artifacts.peek().is_some() {
                 println!()
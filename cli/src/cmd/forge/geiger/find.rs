@@ -5,22 +5,31 @@ use forge_fmt::{offset_to_line_column, parse, Visitable};
 use foundry_common::fs;
 use solang_parser::{diagnostics::Diagnostic, pt::Loc};
 use std::{
+    collections::{HashMap, HashSet},
     fmt,
     path::{Path, PathBuf},
 };
 use yansi::Paint;
 
 /// Scan a single file for `unsafe` usage.
-pub fn find_cheatcodes_in_file(path: &Path) -> Result<SolFileMetrics, ScanFileError> {
+///
+/// `extra_cheatcodes` are additional cheatcode names, beyond the built-in unsafe set, to flag.
+pub fn find_cheatcodes_in_file(
+    path: &Path,
+    extra_cheatcodes: &HashSet<String>,
+) -> Result<SolFileMetrics, ScanFileError> {
     let content = fs::read_to_string(path)?;
-    let cheatcodes = find_cheatcodes_in_string(&content)
+    let cheatcodes = find_cheatcodes_in_string(&content, extra_cheatcodes)
         .map_err(|diagnostic| ScanFileError::ParseSol(diagnostic, path.to_path_buf()))?;
     Ok(SolFileMetrics { content, cheatcodes, file: path.to_path_buf() })
 }
 
-pub fn find_cheatcodes_in_string(src: &str) -> Result<CheatcodeCounter, Vec<Diagnostic>> {
+pub fn find_cheatcodes_in_string(
+    src: &str,
+    extra_cheatcodes: &HashSet<String>,
+) -> Result<CheatcodeCounter, Vec<Diagnostic>> {
     let mut parsed = parse(src)?;
-    let mut visitor = CheatcodeVisitor::default();
+    let mut visitor = CheatcodeVisitor::new(extra_cheatcodes.clone());
     let _ = parsed.pt.visit(&mut visitor);
     Ok(visitor.cheatcodes)
 }
@@ -89,6 +98,22 @@ impl<'a, 'b> fmt::Display for SolFileMetricsPrinter<'a, 'b> {
                 "setEnv" => set_env,
                 "deriveKey" => derive_key
             );
+            for (name, locs) in &metrics.cheatcodes.extra {
+                if locs.is_empty() {
+                    continue
+                }
+                writeln!(f, "  {}  {}", Paint::red(locs.len()), Paint::red(name))?;
+                for loc in locs {
+                    let function_call = &metrics.content.as_bytes()[loc.start()..loc.end()];
+                    let (line, col) = offset_to_line_column(&metrics.content, loc.start());
+                    let pos = format!("  --> {}:{}:{}", file.display(), line, col);
+                    writeln!(f, "{}", Paint::red(pos))?;
+                    let content = String::from_utf8_lossy(function_call);
+                    for line in content.lines() {
+                        writeln!(f, "      {}", Paint::red(line))?;
+                    }
+                }
+            }
         } else {
             writeln!(f, "0    {}", file.display())?
         }
@@ -109,6 +134,8 @@ pub struct CheatcodeCounter {
     pub close_file: Vec<Loc>,
     pub set_env: Vec<Loc>,
     pub derive_key: Vec<Loc>,
+    /// Call sites for any configured `--extra-unsafe-cheatcode` names, keyed by cheatcode name.
+    pub extra: HashMap<String, Vec<Loc>>,
 }
 
 impl CheatcodeCounter {
@@ -121,7 +148,8 @@ impl CheatcodeCounter {
             !self.close_file.is_empty() ||
             !self.set_env.is_empty() ||
             !self.derive_key.is_empty() ||
-            !self.remove_file.is_empty()
+            !self.remove_file.is_empty() ||
+            self.extra.values().any(|locs| !locs.is_empty())
     }
 
     pub fn count(&self) -> usize {
@@ -133,7 +161,8 @@ impl CheatcodeCounter {
             self.close_file.len() +
             self.set_env.len() +
             self.derive_key.len() +
-            self.remove_file.len()
+            self.remove_file.len() +
+            self.extra.values().map(Vec::len).sum::<usize>()
     }
 }
 
@@ -152,7 +181,7 @@ mod tests {
         }
         "#;
 
-        let count = find_cheatcodes_in_string(s).unwrap();
+        let count = find_cheatcodes_in_string(s, &HashSet::new()).unwrap();
         assert_eq!(count.ffi.len(), 1);
         assert!(count.has_unsafe());
     }
@@ -168,8 +197,57 @@ mod tests {
         }
         "#;
 
-        let count = find_cheatcodes_in_string(s).unwrap();
+        let count = find_cheatcodes_in_string(s, &HashSet::new()).unwrap();
         assert_eq!(count.ffi.len(), 1);
         assert!(count.has_unsafe());
     }
+
+    #[test]
+    fn can_find_renamed_vm_instance() {
+        let s = r#"
+        contract A {
+            Vm internal myVm = Vm(HEVM_ADDRESS);
+            function do_ffi() public {
+                string[] memory inputs = new string[](1);
+                myVm.ffi(inputs);
+            }
+        }
+        "#;
+
+        let count = find_cheatcodes_in_string(s, &HashSet::new()).unwrap();
+        assert_eq!(count.ffi.len(), 1);
+        assert!(count.has_unsafe());
+    }
+
+    #[test]
+    fn ignores_unrelated_method_with_cheatcode_name() {
+        let s = r#"
+        contract A {
+            function do_ffi() public {
+                string[] memory inputs = new string[](1);
+                logger.ffi(inputs);
+            }
+        }
+        "#;
+
+        let count = find_cheatcodes_in_string(s, &HashSet::new()).unwrap();
+        assert!(count.ffi.is_empty());
+        assert!(!count.has_unsafe());
+    }
+
+    #[test]
+    fn can_find_extra_cheatcodes() {
+        let s = r#"
+        contract A is Test {
+            function do_something() public {
+                vm.customUnsafeThing();
+            }
+        }
+        "#;
+
+        let extra: HashSet<String> = ["customUnsafeThing".to_string()].into_iter().collect();
+        let count = find_cheatcodes_in_string(s, &extra).unwrap();
+        assert_eq!(count.extra.get("customUnsafeThing").map(Vec::len), Some(1));
+        assert!(count.has_unsafe());
+    }
 }
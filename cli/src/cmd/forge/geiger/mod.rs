@@ -7,7 +7,11 @@ use ethers::solc::Graph;
 use eyre::WrapErr;
 use foundry_config::{impl_figment_convert_basic, Config};
 use rayon::prelude::*;
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
 use yansi::Paint;
 
 mod error;
@@ -40,6 +44,12 @@ pub struct GeigerArgs {
     check: bool,
     #[clap(help = "print a full report of all files even if no unsafe functions are found.", long)]
     full: bool,
+    #[clap(
+        help = "Additional cheatcode names to treat as unsafe, beyond the built-in set (ffi, the file cheatcodes, setEnv and deriveKey). Can be passed multiple times.",
+        long = "extra-unsafe-cheatcode",
+        value_name = "NAME"
+    )]
+    extra_unsafe_cheatcode: Vec<String>,
 }
 
 impl_figment_convert_basic!(GeigerArgs);
@@ -73,20 +83,35 @@ impl Cmd for GeigerArgs {
         }
 
         let root = config.__root.0;
+        let extra_unsafe_cheatcodes: HashSet<String> =
+            self.extra_unsafe_cheatcode.iter().cloned().collect();
+        let found_unsafe = AtomicBool::new(false);
 
-        sources.par_iter().map(|file| find_cheatcodes_in_file(file)).for_each(|res| {
-            match res {
-                Ok(metrics) => {
-                    let printer = SolFileMetricsPrinter { metrics: &metrics, root: &root };
-                    if self.full || printer.metrics.cheatcodes.has_unsafe() {
-                        eprint!("{printer}");
+        sources
+            .par_iter()
+            .map(|file| find_cheatcodes_in_file(file, &extra_unsafe_cheatcodes))
+            .for_each(|res| {
+                match res {
+                    Ok(metrics) => {
+                        if metrics.cheatcodes.has_unsafe() {
+                            found_unsafe.store(true, Ordering::Relaxed);
+                        }
+                        let printer = SolFileMetricsPrinter { metrics: &metrics, root: &root };
+                        if self.full || printer.metrics.cheatcodes.has_unsafe() {
+                            eprint!("{printer}");
+                        }
                     }
-                }
-                Err(err) => {
-                    eprintln!("{err}");
-                }
-            };
-        });
+                    Err(err) => {
+                        eprintln!("{err}");
+                    }
+                };
+            });
+
+        if self.check && found_unsafe.into_inner() {
+            eyre::bail!(
+                "unsafe cheat codes were detected, see the report above for the call sites"
+            );
+        }
 
         Ok(())
     }
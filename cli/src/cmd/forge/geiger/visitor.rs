@@ -4,12 +4,47 @@ use solang_parser::pt::{
     ContractDefinition, Expression, FunctionDefinition, IdentifierPath, Loc, Parameter, SourceUnit,
     Statement, TypeDefinition, VariableDeclaration, VariableDefinition,
 };
-use std::convert::Infallible;
+use std::{collections::HashSet, convert::Infallible};
+
+/// Names that are treated as referring to `forge-std`'s cheatcode instance even without seeing a
+/// `Vm`-typed declaration in the file being scanned (most commonly they're inherited from a base
+/// contract like `forge-std`'s `Test`/`Script`, which live in a different file).
+const DEFAULT_VM_VAR_NAMES: [&str; 2] = ["vm", "cheats"];
 
 /// a [`forge_fmt::Visitor` that scans for invocations of cheatcodes
-#[derive(Default)]
 pub struct CheatcodeVisitor {
     pub cheatcodes: CheatcodeCounter,
+    /// Names of variables known to hold the cheatcode (`Vm`) instance, so that a call is only
+    /// flagged if it's actually made against that instance, instead of any variable that happens
+    /// to have a method with a matching name.
+    ///
+    /// Seeded with [DEFAULT_VM_VAR_NAMES] and grown whenever a `Vm`-typed variable with a
+    /// different name is declared in the file being scanned.
+    vm_vars: HashSet<String>,
+    /// Extra cheatcode names to flag, beyond the built-in unsafe set.
+    extra_cheatcodes: HashSet<String>,
+}
+
+impl Default for CheatcodeVisitor {
+    fn default() -> Self {
+        Self::new(HashSet::new())
+    }
+}
+
+impl CheatcodeVisitor {
+    pub fn new(extra_cheatcodes: HashSet<String>) -> Self {
+        Self {
+            cheatcodes: CheatcodeCounter::default(),
+            vm_vars: DEFAULT_VM_VAR_NAMES.iter().map(|s| s.to_string()).collect(),
+            extra_cheatcodes,
+        }
+    }
+
+    /// Whether `ty` refers to the `Vm` interface, i.e. this is a declaration of the cheatcode
+    /// instance.
+    fn is_vm_type(ty: &Expression) -> bool {
+        matches!(ty, Expression::Variable(ident) if ident.name == "Vm")
+    }
 }
 
 impl Visitor for CheatcodeVisitor {
@@ -60,20 +95,26 @@ impl Visitor for CheatcodeVisitor {
                 expr.visit(self)?;
             }
             Expression::FunctionCall(loc, lhs, rhs) => {
-                // all cheatcodes are accessd via <vm>.cheatcode
+                // all cheatcodes are accessed via <vm>.cheatcode, where <vm> resolves to the `Vm`
+                // instance (see `vm_vars`)
                 if let Expression::MemberAccess(_, expr, identifier) = &**lhs {
-                    if let Expression::Variable(_) = &**expr {
-                        match identifier.name.as_str() {
-                            "ffi" => self.cheatcodes.ffi.push(*loc),
-                            "readFile" => self.cheatcodes.read_file.push(*loc),
-                            "writeFile" => self.cheatcodes.write_file.push(*loc),
-                            "readLine" => self.cheatcodes.read_line.push(*loc),
-                            "writeLine" => self.cheatcodes.write_line.push(*loc),
-                            "closeFile" => self.cheatcodes.close_file.push(*loc),
-                            "removeFile" => self.cheatcodes.remove_file.push(*loc),
-                            "setEnv" => self.cheatcodes.set_env.push(*loc),
-                            "deriveKey" => self.cheatcodes.derive_key.push(*loc),
-                            _ => {}
+                    if let Expression::Variable(var_ident) = &**expr {
+                        if self.vm_vars.contains(&var_ident.name) {
+                            match identifier.name.as_str() {
+                                "ffi" => self.cheatcodes.ffi.push(*loc),
+                                "readFile" => self.cheatcodes.read_file.push(*loc),
+                                "writeFile" => self.cheatcodes.write_file.push(*loc),
+                                "readLine" => self.cheatcodes.read_line.push(*loc),
+                                "writeLine" => self.cheatcodes.write_line.push(*loc),
+                                "closeFile" => self.cheatcodes.close_file.push(*loc),
+                                "removeFile" => self.cheatcodes.remove_file.push(*loc),
+                                "setEnv" => self.cheatcodes.set_env.push(*loc),
+                                "deriveKey" => self.cheatcodes.derive_key.push(*loc),
+                                name if self.extra_cheatcodes.contains(name) => {
+                                    self.cheatcodes.extra.entry(name.to_string()).or_default().push(*loc);
+                                }
+                                _ => {}
+                            }
                         }
                     }
                 }
@@ -251,6 +292,11 @@ impl Visitor for CheatcodeVisitor {
     }
 
     fn visit_var_definition(&mut self, var: &mut VariableDefinition) -> Result<(), Self::Error> {
+        if let Some(name) = &var.name {
+            if Self::is_vm_type(&var.ty) {
+                self.vm_vars.insert(name.name.clone());
+            }
+        }
         var.ty.visit(self)?;
         var.initializer.visit(self)
     }
@@ -271,6 +317,11 @@ impl Visitor for CheatcodeVisitor {
         var: &mut VariableDeclaration,
         _is_assignment: bool,
     ) -> Result<(), Self::Error> {
+        if let Some(name) = &var.name {
+            if Self::is_vm_type(&var.ty) {
+                self.vm_vars.insert(name.name.clone());
+            }
+        }
         var.ty.visit(self)
     }
 
@@ -28,7 +28,12 @@ pub struct InitArgs {
         value_name = "ROOT"
     )]
     root: Option<PathBuf>,
-    #[clap(help = "The template to start from.", long, short, value_name = "TEMPLATE")]
+    #[clap(
+        help = "The template to start from, as a `<github org>/<repo>` path or a full git URL.",
+        long,
+        short,
+        value_name = "TEMPLATE"
+    )]
     template: Option<String>,
     #[clap(help = "Do not create a git repository.", conflicts_with = "template", long)]
     no_git: bool,
@@ -43,11 +48,7 @@ pub struct InitArgs {
         visible_alias = "no-deps"
     )]
     offline: bool,
-    #[clap(
-        help = "Create the project even if the specified root directory is not empty.",
-        conflicts_with = "template",
-        long
-    )]
+    #[clap(help = "Create the project even if the specified root directory is not empty.", long)]
     force: bool,
     #[clap(
         help = "Create a .vscode/settings.json file with Solidity settings, and generate a remappings.txt file.",
@@ -70,6 +71,17 @@ impl Cmd for InitArgs {
         }
         let root = dunce::canonicalize(root)?;
 
+        // check if target is empty, regardless of whether we're scaffolding from a template
+        if !force && root.read_dir().map(|mut i| i.next().is_some()).unwrap_or(false) {
+            eprintln!(
+                r#"{}: `forge init` cannot be run on a non-empty directory.
+
+        run `forge init --force` to initialize regardless."#,
+                Paint::red("error")
+            );
+            std::process::exit(1);
+        }
+
         // if a template is provided, then this command clones the template repo, removes the .git
         // folder, and initializes a new git repo—-this ensures there is no history from the
         // template and the template is not set as a remote.
@@ -81,8 +93,16 @@ impl Cmd for InitArgs {
             };
             p_println!(!quiet => "Initializing {} from {}...", root.display(), template);
 
+            // shallow clone: we're about to discard the template's history anyway
             Command::new("git")
-                .args(["clone", "--recursive", &template, &root.display().to_string()])
+                .args([
+                    "clone",
+                    "--recursive",
+                    "--depth",
+                    "1",
+                    &template,
+                    &root.display().to_string(),
+                ])
                 .exec()?;
 
             // Navigate to the newly cloned repo.
@@ -94,6 +114,9 @@ impl Cmd for InitArgs {
                 Command::new("git").args(["rev-parse", "--short", "HEAD"]).output()?.stdout;
             let commit_hash = String::from_utf8(git_output)?;
             std::fs::remove_dir_all(".git")?;
+
+            substitute_template_placeholders(&root)?;
+
             Command::new("git").args(["init"]).exec()?;
             Command::new("git").args(["add", "--all"]).exec()?;
 
@@ -103,17 +126,6 @@ impl Cmd for InitArgs {
             // Navigate back.
             std::env::set_current_dir(initial_dir)?;
         } else {
-            // check if target is empty
-            if !force && root.read_dir().map(|mut i| i.next().is_some()).unwrap_or(false) {
-                eprintln!(
-                    r#"{}: `forge init` cannot be run on a non-empty directory.
-
-        run `forge init --force` to initialize regardless."#,
-                    Paint::red("error")
-                );
-                std::process::exit(1);
-            }
-
             // ensure git status is clean before generating anything
             if !no_git && !no_commit && is_git(&root)? {
                 ensure_git_status_clean(&root)?;
@@ -163,6 +175,8 @@ impl Cmd for InitArgs {
                     Dependency::from_str("https://github.com/foundry-rs/forge-std")
                         .and_then(|dependency| install(&mut config, vec![dependency], opts))?;
                 }
+            } else {
+                p_println!(!quiet => "Skipping forge-std install (--offline). Run `forge install foundry-rs/forge-std` once you're back online.");
             }
             // vscode init
             if vscode {
@@ -175,6 +189,34 @@ impl Cmd for InitArgs {
     }
 }
 
+/// Project-name placeholders that a template's `foundry.toml` may use, substituted with the
+/// resolved project directory name once the template is cloned.
+const PROJECT_NAME_PLACEHOLDERS: &[&str] = &["{{project_name}}", "{{PROJECT_NAME}}"];
+
+/// Replaces any [`PROJECT_NAME_PLACEHOLDERS`] found in the template's `foundry.toml` with the name
+/// of the directory the project was initialized into.
+fn substitute_template_placeholders(root: &Path) -> eyre::Result<()> {
+    let foundry_toml = root.join(Config::FILE_NAME);
+    if !foundry_toml.exists() {
+        return Ok(())
+    }
+
+    let project_name = root.file_name().and_then(|name| name.to_str()).unwrap_or("foundry-project");
+    let mut contents = fs::read_to_string(&foundry_toml)?;
+    let mut replaced = false;
+    for placeholder in PROJECT_NAME_PLACEHOLDERS {
+        if contents.contains(placeholder) {
+            contents = contents.replace(placeholder, project_name);
+            replaced = true;
+        }
+    }
+    if replaced {
+        fs::write(foundry_toml, contents)?;
+    }
+
+    Ok(())
+}
+
 /// Returns `true` if `root` is already in an existing git repository
 fn is_git(root: &Path) -> eyre::Result<bool> {
     let is_git = Command::new("git")
@@ -86,6 +86,25 @@ impl Cmd for InspectArgs {
             compile::suppress_compile(&project)
         }?;
 
+        // When resolving by name alone, make sure the name isn't ambiguous across multiple
+        // source files before picking one for the caller - `find_contract` below has no way to
+        // surface that a pick was made rather than the name being genuinely unique.
+        if contract.path.is_none() {
+            let candidates: Vec<String> = outcome
+                .clone()
+                .into_artifacts_with_files()
+                .filter(|(_, name, _)| name == &contract.name)
+                .map(|(file, name, _)| format!("{file}:{name}"))
+                .collect();
+            if candidates.len() > 1 {
+                eyre::bail!(
+                    "contract `{}` exists in more than one file, please specify the path:\n    {}",
+                    contract.name,
+                    candidates.join("\n    ")
+                )
+            }
+        }
+
         // Find the artifact
         let found_artifact = outcome.find_contract(&contract);
 
@@ -99,7 +118,7 @@ impl Cmd for InspectArgs {
         // Match on ContractArtifactFields and Pretty Print
         match field {
             ContractArtifactFields::Abi => {
-                println!("{}", serde_json::to_string_pretty(&to_value(&artifact.abi)?)?);
+                print_json(&to_value(&artifact.abi)?, pretty)?;
             }
             ContractArtifactFields::Bytecode => {
                 let tval: Value = to_value(&artifact.bytecode)?;
@@ -128,19 +147,16 @@ impl Cmd for InspectArgs {
                 );
             }
             ContractArtifactFields::MethodIdentifiers => {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&to_value(&artifact.method_identifiers)?)?
-                );
+                print_json(&to_value(&artifact.method_identifiers)?, pretty)?;
             }
             ContractArtifactFields::GasEstimates => {
-                println!("{}", serde_json::to_string_pretty(&to_value(&artifact.gas_estimates)?)?);
+                print_json(&to_value(&artifact.gas_estimates)?, pretty)?;
             }
             ContractArtifactFields::StorageLayout => {
                 print_storage_layout(&artifact.storage_layout, pretty)?;
             }
             ContractArtifactFields::DevDoc => {
-                println!("{}", serde_json::to_string_pretty(&to_value(&artifact.devdoc)?)?);
+                print_json(&to_value(&artifact.devdoc)?, pretty)?;
             }
             ContractArtifactFields::Ir => {
                 println!(
@@ -159,10 +175,10 @@ impl Cmd for InspectArgs {
                 );
             }
             ContractArtifactFields::Metadata => {
-                println!("{}", serde_json::to_string_pretty(&to_value(&artifact.metadata)?)?);
+                print_json(&to_value(&artifact.metadata)?, pretty)?;
             }
             ContractArtifactFields::UserDoc => {
-                println!("{}", serde_json::to_string_pretty(&to_value(&artifact.userdoc)?)?);
+                print_json(&to_value(&artifact.userdoc)?, pretty)?;
             }
             ContractArtifactFields::Ewasm => {
                 println!(
@@ -186,7 +202,7 @@ impl Cmd for InspectArgs {
                         );
                     }
                 }
-                println!("{}", serde_json::to_string_pretty(&out)?);
+                print_json(&to_value(&out)?, pretty)?;
             }
         };
 
@@ -194,6 +210,14 @@ impl Cmd for InspectArgs {
     }
 }
 
+/// Prints a JSON value, indented if `pretty` is set or compact (and thus pipeable) otherwise.
+fn print_json(value: &Value, pretty: bool) -> eyre::Result<()> {
+    let s =
+        if pretty { serde_json::to_string_pretty(value)? } else { serde_json::to_string(value)? };
+    println!("{s}");
+    Ok(())
+}
+
 pub fn print_storage_layout(
     storage_layout: &Option<StorageLayout>,
     pretty: bool,
@@ -205,7 +229,7 @@ pub fn print_storage_layout(
     let storage_layout = storage_layout.as_ref().unwrap();
 
     if !pretty {
-        println!("{}", serde_json::to_string_pretty(&to_value(storage_layout)?)?);
+        println!("{}", serde_json::to_string(&to_value(storage_layout)?)?);
         return Ok(())
     }
 
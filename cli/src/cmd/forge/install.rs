@@ -1,6 +1,9 @@
 //! Install command
 use crate::{
-    cmd::{Cmd, LoadConfig},
+    cmd::{
+        forge::lockfile::{resolve_commit, Lockfile},
+        Cmd, LoadConfig,
+    },
     opts::Dependency,
     utils::{p_println, CommandUtils},
 };
@@ -137,9 +140,14 @@ pub(crate) fn install(
         ]);
         trace!(?cmd, "updating submodules");
         cmd.exec()?;
+
+        // no dependencies were given, so restore every locked dependency to its pinned commit
+        restore_locked_commits(&root, &libs)?;
     }
     fs::create_dir_all(&libs)?;
 
+    let mut lockfile = Lockfile::read(&root)?;
+
     for dep in dependencies {
         if dep.url.is_none() {
             eyre::bail!("Could not determine URL for dependency \"{}\"!", dep.name);
@@ -150,9 +158,13 @@ pub(crate) fn install(
 
         // this tracks the actual installed tag
         let installed_tag;
+        // the commit actually checked out, resolved before any `.git` directory is removed
+        let mut installed_rev = None;
 
         if no_git {
-            installed_tag = install_as_folder(&dep, &libs, target_dir)?;
+            let (tag, rev) = install_as_folder(&dep, &libs, target_dir)?;
+            installed_tag = tag;
+            installed_rev = Some(rev);
         } else {
             if !no_commit {
                 ensure_git_status_clean(&root)?;
@@ -179,13 +191,27 @@ pub(crate) fn install(
                     Command::new("git").current_dir(&root).args(["add", ".gitmodules"]).exec()?;
                 }
             }
+        }
 
-            // commit the installation
-            if !no_commit {
-                commit_after_install(&libs, target_dir, installed_tag.as_deref())?;
+        // record the commit that actually ended up checked out, for restoring later
+        let installed_rev = installed_rev.or_else(|| resolve_commit(libs.join(target_dir)).ok());
+        if let Some(rev) = installed_rev {
+            lockfile.set(target_dir.clone(), dep.url.clone().unwrap(), rev, installed_tag.clone());
+            lockfile.write(&root)?;
+            if !no_git && !no_commit {
+                trace!("git add foundry.lock");
+                Command::new("git")
+                    .current_dir(&root)
+                    .args(["add", Lockfile::path(&root).to_str().unwrap()])
+                    .exec()?;
             }
         }
 
+        // commit the installation, now that the lockfile update is staged alongside it
+        if !no_git && !no_commit {
+            commit_after_install(&libs, target_dir, installed_tag.as_deref())?;
+        }
+
         // constructs the message `Installed <name> <branch>?`
         let mut msg = format!("    {} {}", Paint::green("Installed"), dep.name);
 
@@ -205,6 +231,56 @@ pub(crate) fn install(
     Ok(())
 }
 
+/// Checks out every dependency recorded in `foundry.lock` at its locked commit.
+///
+/// Used by `forge install` (no arguments) to make a fresh checkout reproduce exactly the commits
+/// the lockfile pinned, rather than whatever `git submodule update --init --recursive` happened to
+/// leave checked out (e.g. if a pinned branch has since moved).
+fn restore_locked_commits(root: &Path, libs: &Path) -> eyre::Result<()> {
+    let lockfile = Lockfile::read(root)?;
+    for (target_dir, entry) in &lockfile.dependencies {
+        let dir = libs.join(target_dir);
+        if !dir.exists() {
+            continue
+        }
+        let output =
+            Command::new("git").args(["checkout", &entry.rev]).current_dir(&dir).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            warn!(%target_dir, rev = %entry.rev, %stderr, "failed to restore locked commit");
+        }
+    }
+    Ok(())
+}
+
+/// Warns if any installed dependency's checked out commit disagrees with `foundry.lock`.
+///
+/// Drift happens when a pinned branch moves upstream, or a dependency is updated by hand without
+/// going through `forge update`. This only warns; it never modifies the checkout.
+pub fn warn_on_lockfile_drift(config: &Config) {
+    let root = &config.__root.0;
+    let libs = root.join(config.install_lib_dir());
+    let Ok(lockfile) = Lockfile::read(root) else { return };
+
+    for (target_dir, entry) in &lockfile.dependencies {
+        let dir = libs.join(target_dir);
+        if !dir.exists() {
+            continue
+        }
+        if let Ok(rev) = resolve_commit(&dir) {
+            if rev != entry.rev {
+                eprintln!(
+                    "{}",
+                    Paint::yellow(format!(
+                        "Warning: dependency \"{target_dir}\" is checked out at {rev} but foundry.lock pins it to {}. Run `forge install` to restore the locked commit, or `forge update {target_dir}` to re-pin it.",
+                        entry.rev
+                    ))
+                );
+            }
+        }
+    }
+}
+
 /// Checks if any submodules have not been initialized yet.
 ///
 /// `git submodule status <lib dir>` will return a new line per submodule in the repository. If any
@@ -222,11 +298,14 @@ pub fn has_missing_dependencies(root: impl AsRef<Path>, lib_dir: impl AsRef<Path
 }
 
 /// Installs the dependency as an ordinary folder instead of a submodule
+///
+/// Returns the installed tag/branch (if any) and the commit that ended up checked out, resolved
+/// before the `.git` directory is stripped.
 fn install_as_folder(
     dep: &Dependency,
     libs: &Path,
     target_dir: &str,
-) -> eyre::Result<Option<String>> {
+) -> eyre::Result<(Option<String>, String)> {
     let repo = git_clone(dep, libs, target_dir)?;
     let mut dep = dep.clone();
 
@@ -238,10 +317,12 @@ fn install_as_folder(
     // checkout the tag if necessary
     git_checkout(&dep, libs, target_dir, false)?;
 
+    let rev = resolve_commit(&repo)?;
+
     // remove git artifacts
     fs::remove_dir_all(repo.join(".git"))?;
 
-    Ok(dep.tag.take())
+    Ok((dep.tag.take(), rev))
 }
 
 /// Installs the dependency as new submodule.
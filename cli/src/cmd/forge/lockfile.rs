@@ -0,0 +1,105 @@
+//! `foundry.lock` dependency lockfile
+//!
+//! Records the exact commit each `lib/` submodule was resolved to, so `forge install` (with no
+//! arguments) can restore the same commits on a fresh checkout instead of whatever `forge update`
+//! or a plain `git submodule update --remote` happened to leave checked out.
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Name of the lockfile, stored at the project root.
+pub const LOCKFILE_NAME: &str = "foundry.lock";
+
+/// A single locked dependency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockEntry {
+    /// The git url the dependency was installed from.
+    pub url: String,
+    /// The resolved commit the dependency is pinned to.
+    pub rev: String,
+    /// The tag or branch that `rev` was resolved from, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+/// `foundry.lock`: a map of `lib/<target_dir>` to the [`LockEntry`] it was resolved to.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    #[serde(flatten)]
+    pub dependencies: BTreeMap<String, LockEntry>,
+}
+
+impl Lockfile {
+    /// Path of the lockfile for a project at `root`.
+    pub fn path(root: impl AsRef<Path>) -> PathBuf {
+        root.as_ref().join(LOCKFILE_NAME)
+    }
+
+    /// Reads the lockfile at the project root, returning an empty lockfile if none exists yet.
+    pub fn read(root: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = Self::path(&root);
+        if !path.exists() {
+            return Ok(Self::default())
+        }
+        let contents = foundry_common::fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Writes the lockfile to the project root.
+    pub fn write(&self, root: impl AsRef<Path>) -> eyre::Result<()> {
+        let contents = toml::to_string_pretty(self)?;
+        foundry_common::fs::write(Self::path(root), contents)?;
+        Ok(())
+    }
+
+    /// Records (or overwrites) the resolved commit for `target_dir`.
+    pub fn set(&mut self, target_dir: impl Into<String>, url: String, rev: String, tag: Option<String>) {
+        self.dependencies.insert(target_dir.into(), LockEntry { url, rev, tag });
+    }
+}
+
+/// Returns the commit currently checked out at `path`.
+pub fn resolve_commit(path: impl AsRef<Path>) -> eyre::Result<String> {
+    let path = path.as_ref();
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).current_dir(path).output()?;
+    if !output.status.success() {
+        eyre::bail!(
+            "Failed to resolve the checked out commit for \"{}\": {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use foundry_cli_test_utils::tempfile::tempdir;
+
+    #[test]
+    fn roundtrips_lockfile() {
+        let dir = tempdir().unwrap();
+        let mut lock = Lockfile::default();
+        lock.set(
+            "solmate",
+            "https://github.com/transmissions11/solmate".to_string(),
+            "8e8128".to_string(),
+            Some("v6".to_string()),
+        );
+        lock.write(dir.path()).unwrap();
+
+        let read = Lockfile::read(dir.path()).unwrap();
+        assert_eq!(read, lock);
+    }
+
+    #[test]
+    fn missing_lockfile_reads_as_empty() {
+        let dir = tempdir().unwrap();
+        let lock = Lockfile::read(dir.path()).unwrap();
+        assert!(lock.dependencies.is_empty());
+    }
+}
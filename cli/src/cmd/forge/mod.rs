@@ -42,6 +42,7 @@
 pub mod bind;
 pub mod build;
 pub mod cache;
+pub mod clean;
 pub mod config;
 pub mod coverage;
 pub mod create;
@@ -54,9 +55,11 @@ pub mod geiger;
 pub mod init;
 pub mod inspect;
 pub mod install;
+pub mod lockfile;
 pub mod remappings;
 pub mod remove;
 pub mod script;
+pub mod selectors;
 pub mod snapshot;
 pub mod test;
 pub mod tree;
@@ -2,8 +2,9 @@
 
 use crate::cmd::{Cmd, LoadConfig};
 use clap::{Parser, ValueHint};
-use foundry_config::impl_figment_convert_basic;
-use std::path::PathBuf;
+use ethers::solc::remappings::Remapping;
+use foundry_config::{impl_figment_convert_basic, remappings_from_env_var, Config};
+use std::{collections::HashMap, fs, path::PathBuf};
 
 /// CLI arguments for `forge remappings`.
 #[derive(Debug, Clone, Parser)]
@@ -15,6 +16,9 @@ pub struct RemappingArgs {
         value_name = "PATH"
     )]
     root: Option<PathBuf>,
+
+    #[clap(help = "Group the remappings by the library directory they come from.", long)]
+    pretty: bool,
 }
 impl_figment_convert_basic!(RemappingArgs);
 
@@ -23,7 +27,66 @@ impl Cmd for RemappingArgs {
 
     fn run(self) -> eyre::Result<Self::Output> {
         let config = self.try_load_config_emit_warnings()?;
-        config.remappings.iter().for_each(|x| println!("{x}"));
+
+        // this is exactly what the compiler invocation uses: `config.remappings` is the fully
+        // resolved, deduped set produced by the same provider that feeds `project()`.
+        if !self.pretty {
+            config.remappings.iter().for_each(|x| println!("{x}"));
+            return Ok(())
+        }
+
+        let sources = remapping_sources(&config);
+        let mut by_group: Vec<(String, Vec<String>)> = Vec::new();
+        for r in &config.remappings {
+            let source = sources.get(&r.name).cloned().unwrap_or_else(|| "foundry.toml".into());
+            match by_group.iter_mut().find(|(group, _)| group == &source) {
+                Some((_, entries)) => entries.push(r.to_string()),
+                None => by_group.push((source, vec![r.to_string()])),
+            }
+        }
+        by_group.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (source, entries) in by_group {
+            println!("{source}:");
+            for entry in entries {
+                println!("  {entry}");
+            }
+        }
+
         Ok(())
     }
 }
+
+/// Best-effort mapping of each remapping's name to where it was found, for `--pretty` output.
+/// This recomputes the same inputs `RemappingsProvider` resolves from (env var, `remappings.txt`,
+/// auto-detected library directories) and matches them against the final, already-resolved
+/// `config.remappings` by name - it does not change what's actually used to compile.
+fn remapping_sources(config: &Config) -> HashMap<String, String> {
+    let mut sources = HashMap::new();
+
+    if let Some(Ok(remappings)) = remappings_from_env_var("DAPP_REMAPPINGS")
+        .or_else(|| remappings_from_env_var("FOUNDRY_REMAPPINGS"))
+    {
+        for r in remappings {
+            sources.insert(r.name, "environment variable".to_string());
+        }
+    }
+
+    if let Ok(content) = fs::read_to_string(config.__root.0.join("remappings.txt")) {
+        for r in content.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some((name, _)) = r.split_once('=') {
+                sources.insert(name.to_string(), "remappings.txt".to_string());
+            }
+        }
+    }
+
+    if config.auto_detect_remappings {
+        for lib in &config.libs {
+            for r in Remapping::find_many(config.__root.0.join(lib)) {
+                sources.entry(r.name).or_insert_with(|| lib.display().to_string());
+            }
+        }
+    }
+
+    sources
+}
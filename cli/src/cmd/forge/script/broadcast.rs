@@ -19,7 +19,8 @@ use eyre::{bail, ContextCompat, WrapErr};
 use foundry_common::{estimate_eip1559_fees, try_get_http_provider, RetryProvider};
 use futures::StreamExt;
 use std::{cmp::min, collections::HashSet, ops::Mul, sync::Arc};
-use tracing::trace;
+use tracing::{trace, warn};
+use yansi::Paint;
 
 impl ScriptArgs {
     /// Sends the transactions which haven't been broadcasted yet.
@@ -112,7 +113,8 @@ impl ScriptArgs {
                                 let eip1559_fees =
                                     eip1559_fees.expect("Could not get eip1559 fee estimation.");
                                 inner.max_fee_per_gas = Some(eip1559_fees.0);
-                                inner.max_priority_fee_per_gas = Some(eip1559_fees.1);
+                                inner.max_priority_fee_per_gas =
+                                    Some(self.priority_gas_price.unwrap_or(eip1559_fees.1));
                             }
                         }
                     }
@@ -153,8 +155,14 @@ impl ScriptArgs {
                         update_progress!(pb, (index + already_broadcasted));
                         index += 1;
 
-                        clear_pendings(provider.clone(), deployment_sequence, Some(vec![tx_hash]))
-                            .await?;
+                        clear_pendings(
+                            provider.clone(),
+                            deployment_sequence,
+                            Some(vec![tx_hash]),
+                            self.confirmations,
+                            self.timeout,
+                        )
+                        .await?;
                     } else {
                         pending_transactions.push(tx_hash);
                     }
@@ -176,7 +184,14 @@ impl ScriptArgs {
 
                     if !sequential_broadcast {
                         println!("##\nWaiting for receipts.");
-                        clear_pendings(provider.clone(), deployment_sequence, None).await?;
+                        clear_pendings(
+                            provider.clone(),
+                            deployment_sequence,
+                            None,
+                            self.confirmations,
+                            self.timeout,
+                        )
+                        .await?;
                     }
                 }
 
@@ -209,6 +224,38 @@ impl ScriptArgs {
         Ok(())
     }
 
+    /// Checks that a signer is available for every sender still awaiting broadcast on
+    /// `deployment_sequence`, without sending anything. Used ahead of multi-chain broadcasts so
+    /// that a chain with no configured signer is caught before any other chain's transactions
+    /// have gone out, instead of leaving the deployment half-broadcasted.
+    pub async fn check_signers(
+        &self,
+        deployment_sequence: &ScriptSequence,
+        fork_url: &str,
+        script_wallets: &[LocalWallet],
+    ) -> eyre::Result<()> {
+        let already_broadcasted = deployment_sequence.receipts.len();
+
+        if already_broadcasted < deployment_sequence.transactions.len() {
+            if self.unlocked {
+                self.evm_opts.sender.wrap_err("--sender must be set with --unlocked")?;
+                return Ok(())
+            }
+
+            let provider = Arc::new(try_get_http_provider(fork_url)?);
+            let required_addresses = deployment_sequence
+                .typed_transactions()
+                .into_iter()
+                .skip(already_broadcasted)
+                .map(|(_, tx)| *tx.from().expect("No sender for onchain transaction!"))
+                .collect();
+
+            self.wallets.find_all(provider, required_addresses, script_wallets).await?;
+        }
+
+        Ok(())
+    }
+
     async fn send_transaction(
         &self,
         provider: Arc<RetryProvider>,
@@ -227,7 +274,17 @@ impl ScriptArgs {
             let tx_nonce = tx.nonce().expect("no nonce");
 
             if nonce != *tx_nonce {
-                bail!("EOA nonce changed unexpectedly while sending transactions.")
+                // The chain moved since the transaction was created (e.g. `--resume` after other
+                // activity from the same account), so the recorded nonce is stale. Re-nonce to
+                // the current on-chain value instead of failing outright.
+                println!(
+                    "{}",
+                    Paint::yellow(format!(
+                        "The nonce for {from:?} has changed from {tx_nonce} to {nonce} since this \
+                         transaction was created; re-nonce-ing it to {nonce}."
+                    ))
+                );
+                tx.set_nonce(nonce);
             }
         }
 
@@ -284,6 +341,14 @@ impl ScriptArgs {
                     )
                     .await?;
 
+                if self.json {
+                    let transactions = deployments
+                        .iter()
+                        .flat_map(|sequence| sequence.transactions.clone())
+                        .collect();
+                    self.show_json_transactions(&script_config, &result, transactions)?;
+                }
+
                 if script_config.has_multiple_rpcs() {
                     trace!(target: "script", "broadcasting multi chain deployment");
 
@@ -317,10 +382,23 @@ impl ScriptArgs {
                 }
 
                 if !self.broadcast {
-                    println!("\nSIMULATION COMPLETE. To broadcast these transactions, add --broadcast and wallet configuration(s) to the previous command. See forge script --help for more.");
+                    let msg = "\nSIMULATION COMPLETE. To broadcast these transactions, add --broadcast and wallet configuration(s) to the previous command. See forge script --help for more.";
+                    if self.json {
+                        eprintln!("{msg}");
+                    } else {
+                        println!("{msg}");
+                    }
                 }
             } else {
-                println!("\nIf you wish to simulate on-chain transactions pass a RPC URL.");
+                let msg = "\nIf you wish to simulate on-chain transactions pass a RPC URL.";
+                if self.json {
+                    // `run` leaves the JSON output to this function when transactions were
+                    // collected; nothing was simulated here, so print the plain result instead
+                    self.show_json(&script_config, &result)?;
+                    eprintln!("{msg}");
+                } else {
+                    println!("{msg}");
+                }
             }
         }
         Ok(())
@@ -395,7 +473,11 @@ impl ScriptArgs {
         known_contracts: &ContractsByArtifact,
     ) -> eyre::Result<VecDeque<TransactionWithMetadata>> {
         let gas_filled_txs = if self.skip_simulation {
-            println!("\nSKIPPING ON CHAIN SIMULATION.");
+            if self.json {
+                eprintln!("\nSKIPPING ON CHAIN SIMULATION.");
+            } else {
+                println!("\nSKIPPING ON CHAIN SIMULATION.");
+            }
             txs.into_iter()
                 .map(|btx| {
                     let mut tx = TransactionWithMetadata::from_typed_transaction(btx.transaction);
@@ -490,8 +572,17 @@ impl ScriptArgs {
                     }
                 }
 
+                let padded_gas = *typed_tx.gas().expect("gas is set");
                 let total_gas = total_gas_per_rpc.entry(tx_rpc.clone()).or_insert(U256::zero());
-                *total_gas += *typed_tx.gas().expect("gas is set");
+                *total_gas += padded_gas;
+
+                if let Some(simulated_gas) = tx.simulated_gas {
+                    println!(
+                        "{} simulated gas: {simulated_gas}, padded gas ({}%): {padded_gas}",
+                        tx.contract_name.as_deref().unwrap_or("Transaction"),
+                        self.gas_estimate_multiplier
+                    );
+                }
             }
 
             new_sequence.push_back(tx);
@@ -567,12 +658,20 @@ impl ScriptArgs {
         // Chains which use `eth_estimateGas` are being sent sequentially and require their gas
         // to be re-estimated right before broadcasting.
         if has_different_gas_calc(signer.signer().chain_id()) || self.skip_simulation {
+            // Keep the simulated gas around in case re-estimation against the live RPC fails, so
+            // we have something reasonable to fall back to instead of aborting the broadcast.
+            let simulated_gas = legacy_or_1559.gas().copied();
+
             // if already set, some RPC endpoints might simply return the gas value that is
             // already set in the request and omit the estimate altogether, so
             // we remove it here
             let _ = legacy_or_1559.gas_mut().take();
 
-            self.estimate_gas(&mut legacy_or_1559, signer.provider()).await?;
+            if let Err(err) = self.estimate_gas(&mut legacy_or_1559, signer.provider()).await {
+                let Some(simulated_gas) = simulated_gas else { return Err(err) };
+                warn!("Failed to estimate gas for the live transaction, falling back to the simulated gas limit ({simulated_gas}): {err}");
+                legacy_or_1559.set_gas(simulated_gas);
+            }
         }
 
         // Signing manually so we skip `fill_transaction` and its `eth_createAccessList`
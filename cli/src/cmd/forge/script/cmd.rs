@@ -104,7 +104,12 @@ impl ScriptArgs {
         }
 
         if self.json {
-            self.show_json(&script_config, &result)?;
+            // If the script collected any broadcastable transactions, defer printing until
+            // `handle_broadcastable_transactions` has simulated and gas-filled them, so the
+            // emitted JSON includes the full transaction plan instead of just the return value.
+            if result.transactions.is_none() {
+                self.show_json(&script_config, &result)?;
+            }
         } else {
             self.show_traces(&script_config, &decoder, &mut result).await?;
         }
@@ -255,9 +260,16 @@ impl ScriptArgs {
             Err(err) => eyre::bail!(err),
         };
 
-        receipts::wait_for_pending(provider, &mut deployment_sequence).await?;
+        receipts::wait_for_pending(
+            provider,
+            &mut deployment_sequence,
+            self.confirmations,
+            self.timeout,
+        )
+        .await?;
 
         if self.resume {
+            self.check_resume_matches_journal(&deployment_sequence, &result)?;
             self.send_transactions(&mut deployment_sequence, fork_url, &result.script_wallets)
                 .await?;
         }
@@ -282,6 +294,54 @@ impl ScriptArgs {
         Ok(())
     }
 
+    /// Checks that the transactions the script just produced (by re-simulating it) still match
+    /// the ones persisted in the broadcast journal we're about to resume, so that a script whose
+    /// code changed since the interrupted run doesn't silently continue broadcasting a stale
+    /// sequence. Bypassed by `--force`.
+    fn check_resume_matches_journal(
+        &self,
+        deployment_sequence: &ScriptSequence,
+        result: &ScriptResult,
+    ) -> eyre::Result<()> {
+        if self.opts.args.force {
+            return Ok(())
+        }
+
+        let fresh = result.transactions.clone().unwrap_or_default();
+        let journaled = &deployment_sequence.transactions;
+
+        if fresh.len() != journaled.len() {
+            eyre::bail!(
+                "The script now produces {} transaction(s), but the broadcast journal at {} has \
+                 {}. The script may have changed since the deployment was started; pass --force \
+                 to resume anyway.",
+                fresh.len(),
+                deployment_sequence.path.display(),
+                journaled.len(),
+            )
+        }
+
+        let diffs: Vec<_> = fresh
+            .iter()
+            .zip(journaled.iter())
+            .enumerate()
+            .filter(|(_, (new_tx, old_tx))| new_tx.transaction.data() != old_tx.typed_tx().data())
+            .map(|(i, _)| format!("  transaction #{i}: calldata differs"))
+            .collect();
+
+        if !diffs.is_empty() {
+            eyre::bail!(
+                "The script's calldata no longer matches the broadcast journal at {}:\n{}\n\nThe \
+                 script may have changed since the deployment was started; pass --force to \
+                 resume anyway.",
+                deployment_sequence.path.display(),
+                diffs.join("\n"),
+            )
+        }
+
+        Ok(())
+    }
+
     /// Reruns the execution with a new sender and relinks the libraries accordingly
     async fn rerun_with_new_deployer(
         &mut self,
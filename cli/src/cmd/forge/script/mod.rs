@@ -144,10 +144,12 @@ pub struct ScriptArgs {
 
     /// Resumes submitting transactions that failed or timed-out previously.
     ///
-    /// It DOES NOT simulate the script again and it expects nonces to have remained the same.
-    ///
-    /// Example: If transaction N has a nonce of 22, then the account should have a nonce of 22,
-    /// otherwise it fails.
+    /// On a single-chain resume, the script is simulated again to make sure it still produces the
+    /// same transactions, and the run fails if it doesn't (pass the existing `--force` flag to
+    /// resume anyway); a `--multi` resume does not make this check.
+    /// When transactions are sent one at a time (e.g. with `--slow` or several signers), a
+    /// transaction whose nonce has drifted from what was recorded is re-nonced to the current
+    /// on-chain value rather than failing outright; batched sends keep the recorded nonces.
     #[clap(long)]
     pub resume: bool,
 
@@ -160,12 +162,35 @@ pub struct ScriptArgs {
     #[clap(long, help = "Open the script in the debugger. Takes precedence over broadcast.")]
     pub debug: bool,
 
+    #[clap(
+        long,
+        help = "Opens the debugger at the step that reverted, if any. Requires --debug.",
+        requires = "debug"
+    )]
+    pub jump_to_revert: bool,
+
     #[clap(
         long,
         help = "Makes sure a transaction is sent, only after its previous one has been confirmed and succeeded."
     )]
     pub slow: bool,
 
+    #[clap(
+        long,
+        help = "Number of block confirmations to wait for each transaction before sending the next one.",
+        default_value = "1",
+        value_name = "CONFIRMATIONS"
+    )]
+    pub confirmations: usize,
+
+    #[clap(
+        long,
+        help = "Timeout for each transaction confirmation, in seconds.",
+        default_value = "120",
+        value_name = "TIMEOUT"
+    )]
+    pub timeout: u64,
+
     #[clap(long, env = "ETHERSCAN_API_KEY", value_name = "KEY")]
     pub etherscan_api_key: Option<String>,
 
@@ -190,6 +215,15 @@ pub struct ScriptArgs {
     )]
     pub with_gas_price: Option<U256>,
 
+    #[clap(
+        long,
+        help = "Max priority fee per gas for EIP1559 transactions.",
+        env = "ETH_PRIORITY_GAS_PRICE",
+        value_parser = parse_ether_value,
+        value_name = "PRICE"
+    )]
+    pub priority_gas_price: Option<U256>,
+
     #[clap(flatten)]
     pub retry: RetryArgs,
 }
@@ -210,10 +244,10 @@ impl ScriptArgs {
         )?;
 
         let mut local_identifier = LocalTraceIdentifier::new(known_contracts);
-        let mut decoder = CallTraceDecoderBuilder::new()
-            .with_labels(result.labeled_addresses.clone())
-            .with_verbosity(verbosity)
-            .build();
+        let mut labels = script_config.config.labels.clone();
+        labels.extend(result.labeled_addresses.clone());
+        let mut decoder =
+            CallTraceDecoderBuilder::new().with_labels(labels).with_verbosity(verbosity).build();
 
         decoder.add_signature_identifier(SignaturesIdentifier::new(
             Config::foundry_cache_dir(),
@@ -349,7 +383,37 @@ impl ScriptArgs {
         let returns = self.get_returns(script_config, &result.returned)?;
 
         let console_logs = decode_console_logs(&result.logs);
-        let output = JsonResult { logs: console_logs, gas_used: result.gas_used, returns };
+        let output = JsonResult {
+            logs: console_logs,
+            gas_used: result.gas_used,
+            returns,
+            transactions: None,
+        };
+        let j = serde_json::to_string(&output)?;
+        println!("{j}");
+
+        Ok(())
+    }
+
+    /// Prints the same [`JsonResult`] shape as [`Self::show_json`], but including the
+    /// transaction plan the script collected. Used instead of `show_json` once the
+    /// broadcastable transactions have been simulated and gas-filled, so the plan reported
+    /// matches what `--broadcast` would actually send.
+    pub fn show_json_transactions(
+        &self,
+        script_config: &ScriptConfig,
+        result: &ScriptResult,
+        transactions: Vec<TransactionWithMetadata>,
+    ) -> eyre::Result<()> {
+        let returns = self.get_returns(script_config, &result.returned)?;
+
+        let console_logs = decode_console_logs(&result.logs);
+        let output = JsonResult {
+            logs: console_logs,
+            gas_used: result.gas_used,
+            returns,
+            transactions: Some(transactions),
+        };
         let j = serde_json::to_string(&output)?;
         println!("{j}");
 
@@ -443,9 +507,22 @@ impl ScriptArgs {
             .map(|(addr, identifier)| (*addr, get_contract_name(identifier).to_string()))
             .collect();
 
+        let (call_index, step_index) = if self.jump_to_revert {
+            match Tui::find_revert(&flattened) {
+                Some(location) => location,
+                None => {
+                    println!("Nothing reverted, opening debugger at the start");
+                    (0, 0)
+                }
+            }
+        } else {
+            (0, 0)
+        };
+
         let tui = Tui::new(
             flattened,
-            0,
+            step_index,
+            call_index,
             identified_contracts,
             artifacts,
             highlevel_known_contracts
@@ -632,6 +709,11 @@ pub struct JsonResult {
     pub logs: Vec<String>,
     pub gas_used: u64,
     pub returns: HashMap<String, NestedValue>,
+    /// The ordered list of transactions the script would broadcast, present when the script
+    /// collected any, with or without `--broadcast`. Absent (rather than an empty array) for
+    /// scripts that don't broadcast anything, to keep the common `--json` case unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<Vec<TransactionWithMetadata>>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -136,7 +136,8 @@ impl ScriptArgs {
                     let provider = Arc::new(get_http_provider(
                         sequence.typed_transactions().first().unwrap().0.clone(),
                     ));
-                    receipts::wait_for_pending(provider, sequence).await
+                    receipts::wait_for_pending(provider, sequence, self.confirmations, self.timeout)
+                        .await
                 })
                 .collect::<Vec<_>>();
 
@@ -150,6 +151,14 @@ impl ScriptArgs {
 
         trace!(target: "script", "broadcasting multi chain deployments");
 
+        // Make sure every chain has a configured signer before broadcasting to any of them - a
+        // deployment half-sent because a later chain turned out to have no matching private key
+        // is much worse than failing upfront.
+        for sequence in &deployments.deployments {
+            let fork_url = sequence.typed_transactions().first().unwrap().0.clone();
+            self.check_signers(sequence, &fork_url, &script_wallets).await?;
+        }
+
         let futs = deployments
             .deployments
             .iter_mut()
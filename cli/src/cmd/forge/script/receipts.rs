@@ -5,11 +5,11 @@ use crate::{
 use ethers::{
     prelude::{PendingTransaction, TxHash},
     providers::Middleware,
-    types::TransactionReceipt,
+    types::{TransactionReceipt, U256},
 };
 use foundry_common::RetryProvider;
 use futures::StreamExt;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use tracing::{trace, warn};
 
 /// Convenience enum for internal signalling of transaction status
@@ -35,12 +35,14 @@ impl From<TransactionReceipt> for TxStatus {
 pub async fn wait_for_pending(
     provider: Arc<RetryProvider>,
     deployment_sequence: &mut ScriptSequence,
+    confirmations: usize,
+    timeout: u64,
 ) -> eyre::Result<()> {
     if deployment_sequence.pending.is_empty() {
         return Ok(())
     }
     println!("##\nChecking previously pending transactions.");
-    clear_pendings(provider, deployment_sequence, None).await
+    clear_pendings(provider, deployment_sequence, None, confirmations, timeout).await
 }
 
 /// Traverses a set of pendings and either finds receipts, or clears them from
@@ -58,6 +60,8 @@ pub async fn clear_pendings(
     provider: Arc<RetryProvider>,
     deployment_sequence: &mut ScriptSequence,
     tx_hashes: Option<Vec<TxHash>>,
+    confirmations: usize,
+    timeout: u64,
 ) -> eyre::Result<()> {
     let to_query = tx_hashes.unwrap_or_else(|| deployment_sequence.pending.clone());
 
@@ -65,7 +69,18 @@ pub async fn clear_pendings(
 
     trace!("Checking status of {count} pending transactions");
 
-    let futs = to_query.iter().copied().map(|tx| check_tx_status(&provider, tx));
+    // Map each pending tx hash back to its nonce, so a timeout or failure can tell the user
+    // exactly which transaction to inspect or replace.
+    let nonces: HashMap<TxHash, U256> = deployment_sequence
+        .transactions
+        .iter()
+        .filter_map(|tx| {
+            tx.hash.map(|hash| (hash, *tx.transaction.nonce().unwrap_or(&U256::zero())))
+        })
+        .collect();
+
+    let futs =
+        to_query.iter().copied().map(|tx| check_tx_status(&provider, tx, confirmations, timeout));
     let mut tasks = futures::stream::iter(futs).buffer_unordered(10);
 
     let mut errors: Vec<String> = vec![];
@@ -76,10 +91,18 @@ pub async fn clear_pendings(
     let pb = init_progress!(deployment_sequence.pending, "receipts");
     pb.set_position(pos);
 
+    let total = deployment_sequence.transactions.len();
+    let mut confirmed = deployment_sequence.receipts.len();
+
     while let Some((tx_hash, result)) = tasks.next().await {
         match result {
             Err(err) => {
-                errors.push(format!("Failure on receiving a receipt for {tx_hash:?}:\n{err}"))
+                let nonce = nonces
+                    .get(&tx_hash)
+                    .map(|nonce| format!(", nonce {nonce}"))
+                    .unwrap_or_default();
+                errors
+                    .push(format!("Failure on receiving a receipt for {tx_hash:?}{nonce}:\n{err}"))
             }
             Ok(TxStatus::Dropped) => {
                 // We want to remove it from pending so it will be re-broadcast.
@@ -89,6 +112,17 @@ pub async fn clear_pendings(
             Ok(TxStatus::Success(receipt)) => {
                 trace!(tx_hash = ?tx_hash, "received tx receipt");
                 deployment_sequence.remove_pending(receipt.transaction_hash);
+                confirmed += 1;
+                println!(
+                    "tx {confirmed}/{total} confirmed in block {} (gas used {})",
+                    receipt.block_number.unwrap_or_default(),
+                    receipt.gas_used.unwrap_or_default()
+                );
+                // Record the receipt (and checkpoint to disk) as soon as it arrives, rather than
+                // waiting for the whole batch, so `--resume` has accurate state even if a later
+                // transaction in this batch times out.
+                deployment_sequence.add_receipt(receipt.clone());
+                deployment_sequence.save()?;
                 receipts.push(receipt);
             }
             Ok(TxStatus::Revert(receipt)) => {
@@ -111,7 +145,6 @@ pub async fn clear_pendings(
     // print all receipts
     for receipt in receipts {
         print_receipt(deployment_sequence.chain.into(), &receipt);
-        deployment_sequence.add_receipt(receipt);
     }
 
     // print any erros
@@ -128,14 +161,18 @@ pub async fn clear_pendings(
 }
 
 /// Checks the status of a txhash by first polling for a receipt, then for
-/// mempool inclusion. Returns the tx hash, and a status
+/// mempool inclusion, waiting for `confirmations` blocks on top. Bails with a
+/// descriptive timeout error (including the tx hash, so it can be inspected or replaced) if
+/// nothing comes back within `timeout` seconds. Returns the tx hash, and a status
 async fn check_tx_status(
     provider: &RetryProvider,
     hash: TxHash,
+    confirmations: usize,
+    timeout: u64,
 ) -> (TxHash, Result<TxStatus, eyre::Report>) {
     // We use the inner future so that we can use ? operator in the future, but
     // still neatly return the tuple
-    let result = async move {
+    let fut = async move {
         // First check if there's a receipt
         let receipt_opt = provider.get_transaction_receipt(hash).await?;
         if let Some(receipt) = receipt_opt {
@@ -144,13 +181,22 @@ async fn check_tx_status(
 
         // If the tx is present in the mempool, run the pending tx future, and
         // assume the next drop is really really real
-        let pending_res = PendingTransaction::new(hash, provider).await?;
+        let pending_res =
+            PendingTransaction::new(hash, provider).confirmations(confirmations).await?;
         match pending_res {
             Some(receipt) => Ok(receipt.into()),
             None => Ok(TxStatus::Dropped),
         }
-    }
-    .await;
+    };
+
+    let result = match tokio::time::timeout(Duration::from_secs(timeout), fut).await {
+        Ok(result) => result,
+        Err(_) => Err(eyre::eyre!(
+            "Timed out after {timeout}s waiting for a receipt for {hash:?}. It may still land \
+             later - inspect it with `cast tx {hash:?}` or replace it by resubmitting its nonce \
+             with a higher gas price."
+        )),
+    };
 
     (hash, result)
 }
@@ -27,6 +27,12 @@ use yansi::Paint;
 
 pub const DRY_RUN_DIR: &str = "dry-run";
 
+/// The current version of the broadcast journal schema. Consumers that read `run-latest.json`
+/// files directly (e.g. the `getDeployment` cheatcode) key their parsing off this field. Bump it
+/// whenever a breaking change is made to the persisted format, and keep `version` defaulted via
+/// serde so journals written before this field existed keep loading.
+pub const SCRIPT_SEQUENCE_VERSION: u32 = 1;
+
 /// Helper that saves the transactions sequence and its state on which transactions have been
 /// broadcasted
 #[derive(Deserialize, Serialize, Clone, Default)]
@@ -43,6 +49,9 @@ pub struct ScriptSequence {
     /// If `True`, the sequence belongs to a `MultiChainSequence` and won't save to disk as usual.
     pub multi: bool,
     pub commit: Option<String>,
+    /// Schema version of this journal. Missing (i.e. pre-versioning) journals default to `0`.
+    #[serde(default)]
+    pub version: u32,
 }
 
 impl ScriptSequence {
@@ -80,6 +89,7 @@ impl ScriptSequence {
             chain,
             multi: is_multi,
             commit,
+            version: SCRIPT_SEQUENCE_VERSION,
         })
     }
 
@@ -111,7 +121,9 @@ impl ScriptSequence {
                 &self,
             )?;
 
-            println!("\nTransactions saved to: {path}\n");
+            // This is a notice about a side effect (writing to disk), not part of the script's
+            // output, so it goes to stderr and doesn't pollute `--json` output on stdout.
+            eprintln!("\nTransactions saved to: {path}\n");
         }
 
         Ok(())
@@ -315,4 +327,36 @@ mod tests {
             "522bb704"
         );
     }
+
+    #[test]
+    fn can_read_pre_versioning_journal() {
+        // A `run-latest.json` persisted before the `version` field was introduced should still
+        // load, with `version` defaulting to `0`.
+        let sequence: ScriptSequence = serde_json::from_str(
+            r#"{
+                "transactions": [],
+                "receipts": [],
+                "libraries": [],
+                "pending": [],
+                "path": "broadcast/Deploy.s.sol/1/run-latest.json",
+                "returns": {},
+                "timestamp": 0,
+                "chain": 1,
+                "multi": false,
+                "commit": null
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(sequence.version, 0);
+    }
+
+    #[test]
+    fn new_sequences_are_stamped_with_the_current_version() {
+        let sequence =
+            ScriptSequence { chain: 1, version: SCRIPT_SEQUENCE_VERSION, ..Default::default() };
+
+        let serialized = serde_json::to_value(&sequence).unwrap();
+        assert_eq!(serialized["version"], SCRIPT_SEQUENCE_VERSION);
+    }
 }
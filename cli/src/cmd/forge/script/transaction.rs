@@ -4,7 +4,7 @@ use ethers::{
     abi,
     abi::Address,
     prelude::{NameOrAddress, H256 as TxHash},
-    types::transaction::eip2718::TypedTransaction,
+    types::{transaction::eip2718::TypedTransaction, U256},
 };
 use eyre::{ContextCompat, WrapErr};
 use foundry_common::{abi::format_token, RpcUrl, SELECTOR_LEN};
@@ -40,6 +40,11 @@ pub struct TransactionWithMetadata {
     pub rpc: Option<RpcUrl>,
     pub transaction: TypedTransaction,
     pub additional_contracts: Vec<AdditionalContract>,
+    /// The raw gas used during simulation, before the `--gas-estimate-multiplier` padding was
+    /// applied to `transaction`'s gas limit. Kept around so the broadcast summary can show both
+    /// numbers. Absent for sequences recorded before this field existed.
+    #[serde(default)]
+    pub simulated_gas: Option<U256>,
 }
 
 fn default_string() -> Option<String> {
@@ -67,7 +72,12 @@ impl TransactionWithMetadata {
         decoder: &CallTraceDecoder,
         additional_contracts: Vec<AdditionalContract>,
     ) -> eyre::Result<Self> {
-        let mut metadata = Self { transaction, rpc, ..Default::default() };
+        let mut metadata = Self {
+            transaction,
+            rpc,
+            simulated_gas: Some(U256::from(result.gas_used)),
+            ..Default::default()
+        };
 
         // Specify if any contract was directly created with this transaction
         if let Some(NameOrAddress::Address(to)) = metadata.transaction.to().cloned() {
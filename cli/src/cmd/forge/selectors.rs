@@ -0,0 +1,231 @@
+//! selectors command
+use crate::{
+    cmd::{
+        forge::{
+            build::{CoreBuildArgs, ProjectPathsArgs},
+            fourbyte::UploadSelectorsArgs,
+        },
+        Cmd,
+    },
+    opts::forge::CompilerArgs,
+    utils::FoundryPathExt,
+};
+use clap::{Parser, Subcommand};
+use comfy_table::{presets::ASCII_MARKDOWN, Table};
+use ethers::{prelude::artifacts::output_selection::ContractOutputSelection, utils::keccak256};
+use foundry_common::compile;
+use std::collections::BTreeMap;
+
+/// CLI arguments for `forge selectors`.
+#[derive(Debug, Parser)]
+pub struct SelectorsArgs {
+    #[clap(subcommand)]
+    pub sub: SelectorsSubcommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SelectorsSubcommands {
+    #[clap(about = "Upload selectors to the registry")]
+    Upload(UploadSelectorsArgs),
+
+    #[clap(about = "List selectors from current workspace")]
+    List(ListSelectorsArgs),
+
+    #[clap(about = "Find collisions among the project's selectors")]
+    Collision(CollisionArgs),
+}
+
+impl SelectorsArgs {
+    pub async fn run(self) -> eyre::Result<()> {
+        match self.sub {
+            SelectorsSubcommands::Upload(cmd) => cmd.run().await,
+            SelectorsSubcommands::List(cmd) => cmd.run(),
+            SelectorsSubcommands::Collision(cmd) => cmd.run(),
+        }
+    }
+}
+
+/// CLI arguments for `forge selectors list`.
+#[derive(Debug, Clone, Parser)]
+pub struct ListSelectorsArgs {
+    #[clap(help = "The name of the contract to list selectors for. Defaults to every contract in the project.")]
+    pub contract: Option<String>,
+
+    #[clap(flatten)]
+    pub project_paths: ProjectPathsArgs,
+}
+
+impl Cmd for ListSelectorsArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        let ListSelectorsArgs { contract, project_paths } = self;
+        let contracts = collect_contract_abis(contract, project_paths)?;
+
+        let mut table = Table::new();
+        table.load_preset(ASCII_MARKDOWN);
+        table.set_header(vec!["Contract", "Type", "Signature", "Selector"]);
+
+        for (name, selectors) in &contracts {
+            for selector in selectors {
+                table.add_row(vec![
+                    name.clone(),
+                    selector.kind.to_string(),
+                    selector.signature.clone(),
+                    selector.selector.clone(),
+                ]);
+            }
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
+}
+
+/// CLI arguments for `forge selectors collision`.
+#[derive(Debug, Clone, Parser)]
+pub struct CollisionArgs {
+    #[clap(flatten)]
+    pub project_paths: ProjectPathsArgs,
+}
+
+impl Cmd for CollisionArgs {
+    type Output = ();
+
+    fn run(self) -> eyre::Result<Self::Output> {
+        let CollisionArgs { project_paths } = self;
+        let contracts = collect_contract_abis(None, project_paths)?;
+
+        let mut by_selector: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+        for (name, selectors) in &contracts {
+            for selector in selectors {
+                by_selector
+                    .entry(selector.selector.clone())
+                    .or_default()
+                    .push((name.clone(), selector.signature.clone()));
+            }
+        }
+
+        let mut found = false;
+        for (selector, occurrences) in by_selector {
+            let mut contracts: Vec<_> = occurrences.iter().map(|(name, _)| name.clone()).collect();
+            contracts.dedup();
+            if contracts.len() > 1 {
+                found = true;
+                println!("Selector {selector} collides across contracts:");
+                for (name, signature) in &occurrences {
+                    println!("  - {name}: {signature}");
+                }
+            }
+        }
+
+        if !found {
+            println!("No selector collisions found.");
+        }
+
+        Ok(())
+    }
+}
+
+/// A single function, event or error selector belonging to a contract.
+struct ContractSelector {
+    kind: SelectorKind,
+    signature: String,
+    selector: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SelectorKind {
+    Function,
+    Event,
+    Error,
+}
+
+impl std::fmt::Display for SelectorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectorKind::Function => f.write_str("function"),
+            SelectorKind::Event => f.write_str("event"),
+            SelectorKind::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// Compiles the project and collects the function, event and error selectors for `contract`, or
+/// every contract in the project's `sources` directory if `contract` is `None`.
+fn collect_contract_abis(
+    contract: Option<String>,
+    project_paths: ProjectPathsArgs,
+) -> eyre::Result<Vec<(String, Vec<ContractSelector>)>> {
+    let build_args = CoreBuildArgs {
+        project_paths,
+        compiler: CompilerArgs {
+            extra_output: vec![ContractOutputSelection::Abi],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let project = build_args.project()?;
+    let outcome = compile::suppress_compile(&project)?;
+
+    let artifacts: Vec<_> = if let Some(contract) = contract {
+        let artifact = outcome
+            .find_first(&contract)
+            .ok_or_else(|| {
+                eyre::eyre!("Could not find artifact `{contract}` in the compiled artifacts")
+            })?
+            .clone();
+        vec![(contract, artifact)]
+    } else {
+        outcome
+            .into_artifacts_with_files()
+            .filter(|(file, _, _)| {
+                let is_sources_path =
+                    file.starts_with(&project.paths.sources.to_string_lossy().to_string());
+                let is_test = file.is_sol_test();
+                is_sources_path && !is_test
+            })
+            .map(|(_, contract, artifact)| (contract, artifact))
+            .collect()
+    };
+
+    let mut out = Vec::new();
+    for (name, artifact) in artifacts {
+        let Some(abi) = artifact.abi else { continue };
+        let mut selectors = Vec::new();
+
+        for func in abi.abi.functions() {
+            selectors.push(ContractSelector {
+                kind: SelectorKind::Function,
+                signature: func.signature(),
+                selector: format!("0x{}", hex::encode(func.short_signature())),
+            });
+        }
+
+        for event in abi.abi.events() {
+            let types = event.inputs.iter().map(|p| p.kind.to_string()).collect::<Vec<_>>();
+            selectors.push(ContractSelector {
+                kind: SelectorKind::Event,
+                signature: format!("{}({})", event.name, types.join(",")),
+                selector: format!("{:?}", event.signature()),
+            });
+        }
+
+        for error in abi.abi.errors.values().flatten() {
+            let types = error.inputs.iter().map(|p| p.kind.to_string()).collect::<Vec<_>>();
+            let signature = format!("{}({})", error.name, types.join(","));
+            let selector = &keccak256(signature.as_bytes())[..4];
+            selectors.push(ContractSelector {
+                kind: SelectorKind::Error,
+                signature,
+                selector: format!("0x{}", hex::encode(selector)),
+            });
+        }
+
+        out.push((name, selectors));
+    }
+
+    Ok(out)
+}
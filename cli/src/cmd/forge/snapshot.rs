@@ -326,58 +326,70 @@ impl SnapshotDiff {
 
 /// Compares the set of tests with an existing snapshot
 ///
-/// Returns true all tests match
+/// Returns true if no test regressed beyond `tolerance`. New and removed tests are reported but
+/// don't count as regressions.
 fn check(tests: Vec<Test>, snaps: Vec<SnapshotEntry>, tolerance: Option<u32>) -> bool {
-    let snaps = snaps
+    let mut snaps = snaps
         .into_iter()
         .map(|s| ((s.contract_name, s.signature), s.gas_used))
         .collect::<HashMap<_, _>>();
     let mut has_diff = false;
-    for test in tests {
-        if let Some(target_gas) =
-            snaps.get(&(test.contract_name().to_string(), test.signature.clone())).cloned()
-        {
-            let source_gas = test.result.kind.report();
-            if !within_tolerance(source_gas.gas(), target_gas.gas(), tolerance) {
+    for test in &tests {
+        let key = (test.contract_name().to_string(), test.signature.clone());
+        match snaps.remove(&key) {
+            Some(target_gas) => {
+                let source_gas = test.result.kind.report();
+                if !within_tolerance(source_gas.gas(), target_gas.gas(), tolerance) {
+                    eprintln!(
+                        "Diff in \"{}::{}\": consumed \"{}\" gas, expected \"{}\" gas ",
+                        test.contract_name(),
+                        test.signature,
+                        source_gas,
+                        target_gas
+                    );
+                    has_diff = true;
+                }
+            }
+            None => {
                 eprintln!(
-                    "Diff in \"{}::{}\": consumed \"{}\" gas, expected \"{}\" gas ",
+                    "No matching snapshot entry found for \"{}::{}\" in snapshot file - new test",
                     test.contract_name(),
-                    test.signature,
-                    source_gas,
-                    target_gas
+                    test.signature
                 );
-                has_diff = true;
             }
-        } else {
-            eprintln!(
-                "No matching snapshot entry found for \"{}::{}\" in snapshot file",
-                test.contract_name(),
-                test.signature
-            );
-            has_diff = true;
         }
     }
+    // whatever's left in `snaps` no longer has a matching test
+    for (contract_name, signature) in snaps.keys() {
+        eprintln!(
+            "Snapshot entry \"{contract_name}::{signature}\" has no matching test - test removed"
+        );
+    }
     !has_diff
 }
 
 /// Compare the set of tests with an existing snapshot
 fn diff(tests: Vec<Test>, snaps: Vec<SnapshotEntry>) -> eyre::Result<()> {
-    let snaps = snaps
+    let mut snaps = snaps
         .into_iter()
         .map(|s| ((s.contract_name, s.signature), s.gas_used))
         .collect::<HashMap<_, _>>();
     let mut diffs = Vec::with_capacity(tests.len());
+    let mut new_tests = Vec::new();
     for test in tests.into_iter() {
-        if let Some(target_gas_used) =
-            snaps.get(&(test.contract_name().to_string(), test.signature.clone())).cloned()
-        {
-            diffs.push(SnapshotDiff {
+        let key = (test.contract_name().to_string(), test.signature.clone());
+        match snaps.remove(&key) {
+            Some(target_gas_used) => diffs.push(SnapshotDiff {
                 source_gas_used: test.result.kind.report(),
                 signature: test.signature,
                 target_gas_used,
-            });
+            }),
+            None => new_tests.push(test.signature),
         }
     }
+    // whatever's left in `snaps` no longer has a matching test
+    let removed_tests = snaps.into_keys().map(|(_, signature)| signature).collect::<Vec<_>>();
+
     let mut overall_gas_change = 0i128;
     let mut overall_gas_diff = 0f64;
 
@@ -403,6 +415,13 @@ fn diff(tests: Vec<Test>, snaps: Vec<SnapshotEntry>) -> eyre::Result<()> {
         fmt_change(overall_gas_change),
         fmt_pct_change(overall_gas_diff)
     );
+
+    if !new_tests.is_empty() {
+        println!("New tests: {}", new_tests.join(", "));
+    }
+    if !removed_tests.is_empty() {
+        println!("Removed tests: {}", removed_tests.join(", "));
+    }
     Ok(())
 }
 
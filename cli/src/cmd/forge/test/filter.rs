@@ -74,6 +74,17 @@ pub struct Filter {
 }
 
 impl Filter {
+    /// Returns `true` if no filter was set, i.e. this would match all tests
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_none() &&
+            self.test_pattern.is_none() &&
+            self.test_pattern_inverse.is_none() &&
+            self.contract_pattern.is_none() &&
+            self.contract_pattern_inverse.is_none() &&
+            self.path_pattern.is_none() &&
+            self.path_pattern_inverse.is_none()
+    }
+
     /// Merges the set filter globs with the config's values
     pub fn with_merged_config(&self, config: &Config) -> Self {
         let mut filter = self.clone();
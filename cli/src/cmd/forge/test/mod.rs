@@ -1,33 +1,47 @@
 //! Test command
 use crate::{
     cmd::{
-        forge::{build::CoreBuildArgs, debug::DebugArgs, install, watch::WatchArgs},
+        forge::{
+            build::CoreBuildArgs,
+            coverage::{prepare_coverage_report, SourceMaps},
+            debug::DebugArgs,
+            install,
+            watch::WatchArgs,
+        },
         Cmd, LoadConfig,
     },
     suggestions, utils,
 };
 use cast::fuzz::CounterExample;
-use clap::Parser;
+use clap::{ArgAction, Parser};
 use ethers::{solc::utils::RuntimeOrHandle, types::U256};
 use forge::{
+    coverage::{anchors::attribute_gas, ContractId, CoverageItemKind, CoverageReport},
     decode::decode_console_logs,
     executor::inspector::CheatsConfig,
-    gas_report::GasReport,
+    gas_report::{GasReport, InternalGasReport},
     result::{SuiteResult, TestKind, TestResult},
     trace::{
         identifier::{EtherscanIdentifier, LocalTraceIdentifier, SignaturesIdentifier},
         CallTraceDecoderBuilder, TraceKind,
     },
+    utils::ICPCMap,
     MultiContractRunner, MultiContractRunnerBuilder, TestOptions,
 };
 use foundry_common::{
-    compile::{self, ProjectCompiler},
+    compile::{self, AndFilter, ProjectCompiler, SkipBuildFilter, SkipBuildFilters},
     evm::EvmArgs,
     get_contract_name, get_file_name,
 };
 use foundry_config::{figment, Config};
 use regex::Regex;
-use std::{collections::BTreeMap, path::PathBuf, sync::mpsc::channel, thread, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::mpsc::channel,
+    thread,
+    time::Duration,
+};
 use tracing::trace;
 use watchexec::config::{InitConfig, RuntimeConfig};
 use yansi::Paint;
@@ -68,8 +82,17 @@ pub struct TestArgs {
     debug: Option<Regex>,
 
     /// Print a gas report.
-    #[clap(long, env = "FORGE_GAS_REPORT")]
-    gas_report: bool,
+    ///
+    /// Can optionally be passed a list of contract names to include in the report, which
+    /// overrides the `gas_reports` config value for this run.
+    #[clap(
+        long,
+        env = "FORGE_GAS_REPORT",
+        num_args(0..),
+        action = ArgAction::Append,
+        value_name = "CONTRACTS"
+    )]
+    gas_report: Option<Vec<String>>,
 
     /// Exit with code 0 even if a test fails.
     #[clap(long, env = "FORGE_ALLOW_FAILURE")]
@@ -106,6 +129,34 @@ pub struct TestArgs {
         value_parser =  utils::parse_u256
     )]
     pub fuzz_seed: Option<U256>,
+
+    /// Collapse call frames deeper than this into a one-line summary.
+    #[clap(long, value_name = "N", help_heading = "Display options")]
+    max_trace_depth: Option<usize>,
+
+    /// Write a folded-stacks file aggregating gas usage across every collected trace, suitable
+    /// for rendering with inferno or speedscope.
+    #[clap(long, value_name = "PATH", help_heading = "Display options")]
+    flamegraph: Option<PathBuf>,
+
+    /// Force a full compile of the entire project.
+    ///
+    /// By default, when a test filter like `--match-path`/`--match-contract` is set, only the
+    /// dependency closure of the matched sources is compiled. Pass this to opt out and compile
+    /// everything, e.g. if sparse compilation misses something it shouldn't.
+    #[clap(long)]
+    no_sparse: bool,
+
+    /// Print a gas report that attributes gas usage to internal (non-externally-called)
+    /// functions, in addition to `--gas-report`'s external-call-level report.
+    ///
+    /// This works by reusing `forge coverage`'s AST/source-map analysis to attribute the
+    /// per-instruction gas costs recorded while running tests to the enclosing Solidity function.
+    /// Because of this, it forces a full, un-sparse compile with the optimizer disabled, so the
+    /// numbers will not match an optimized build and the run will be slower than a plain
+    /// `--gas-report`.
+    #[clap(long, env = "FORGE_GAS_REPORT_INTERNAL")]
+    gas_report_internal: bool,
 }
 
 impl TestArgs {
@@ -142,21 +193,71 @@ impl TestArgs {
             project = config.project()?;
         }
 
-        let compiler = ProjectCompiler::default();
-        let output = if config.sparse_mode {
-            compiler.compile_sparse(&project, filter.clone())
+        // `--skip tests` would leave nothing for `forge test` to build and run
+        let skip = self.opts.skip.clone().unwrap_or_default();
+        if skip.contains(&SkipBuildFilter::Tests) {
+            eyre::bail!("cannot skip test files since they are needed to run tests")
+        }
+
+        // `--gas-report-internal` attributes gas to internal functions using the same
+        // AST/source-map analysis as `forge coverage`, which needs the AST in the output
+        // selection and an unoptimized build for accurate source maps.
+        if self.gas_report_internal {
+            project.solc_config.settings.optimizer.disable();
+            project.solc_config.settings.optimizer.runs = None;
+            project.solc_config.settings.optimizer.details = None;
+            project.solc_config.settings.via_ir = None;
+            project.solc_config.settings =
+                std::mem::take(&mut project.solc_config.settings).with_ast();
+        }
+
+        let compiler = ProjectCompiler::with_filter(false, false, skip.clone())
+            .ignore_warnings_from(config.ignore_warnings_from.clone());
+        // Sparsely compile the dependency closure of the matched test files whenever a filter
+        // narrows the test run, since compiling the whole project just to run a handful of tests
+        // is wasteful. `sparse_mode` forces this on even without a filter; `--no-sparse` always
+        // disables it. `--gas-report-internal` needs the whole project's AST, so it always forces
+        // a full compile just like `forge coverage` does.
+        let use_sparse = !self.no_sparse &&
+            !self.gas_report_internal &&
+            (config.sparse_mode || !filter.is_empty());
+        let output = if use_sparse {
+            let filter = AndFilter(filter.clone(), SkipBuildFilters(skip));
+            if self.opts.silent {
+                compile::suppress_compile_sparse(&project, filter)
+            } else {
+                compiler.compile_sparse(&project, filter)
+            }
         } else if self.opts.silent {
-            compile::suppress_compile(&project)
+            compile::suppress_compile_with_filter(&project, skip)
         } else {
             compiler.compile(&project)
         }?;
 
         // Determine print verbosity and executor verbosity
         let verbosity = evm_opts.verbosity;
-        if self.gas_report && evm_opts.verbosity < 3 {
+        let gas_report = self.gas_report.is_some();
+        if (gas_report || self.gas_report_internal) && evm_opts.verbosity < 3 {
             evm_opts.verbosity = 3;
         }
 
+        // Reuse `forge coverage`'s AST analysis to have something to attribute internal gas
+        // costs to later, once tests have actually run and produced hit maps.
+        let internal_gas_report_data = if self.gas_report_internal {
+            let (report, source_maps, ic_pc_maps, _contracts) =
+                prepare_coverage_report(&config, &config.coverage, output.clone())?;
+            Some((report, source_maps, ic_pc_maps))
+        } else {
+            None
+        };
+
+        // A non-empty `--gas-report` contract list overrides the config's `gas_reports`
+        if let Some(ref contracts) = self.gas_report {
+            if !contracts.is_empty() {
+                config.gas_reports = contracts.clone();
+            }
+        }
+
         let env = evm_opts.evm_env_blocking()?;
 
         // Prepare the test builder
@@ -169,6 +270,10 @@ impl TestArgs {
             .with_fork(evm_opts.get_fork(&config, env.clone()))
             .with_cheats_config(CheatsConfig::new(&config, &evm_opts))
             .with_test_options(test_options)
+            .with_libraries(
+                config.parsed_libraries()?.with_applied_remappings(&config.project_paths()),
+            )
+            .set_coverage(self.gas_report_internal)
             .build(project.paths.root, output, env, evm_opts)?;
 
         if self.debug.is_some() {
@@ -231,7 +336,10 @@ impl TestArgs {
                 self.json,
                 self.allow_failure,
                 test_options,
-                self.gas_report,
+                gas_report,
+                internal_gas_report_data,
+                self.max_trace_depth,
+                self.flamegraph.clone(),
             )
         }
     }
@@ -471,6 +579,9 @@ fn test(
     allow_failure: bool,
     test_options: TestOptions,
     gas_reporting: bool,
+    internal_gas_report_data: Option<(CoverageReport, SourceMaps, HashMap<ContractId, (ICPCMap, ICPCMap)>)>,
+    max_trace_depth: Option<usize>,
+    flamegraph: Option<PathBuf>,
 ) -> eyre::Result<TestOutcome> {
     trace!(target: "forge::test", "running all tests");
     if runner.count_filtered_tests(&filter) == 0 {
@@ -500,6 +611,7 @@ fn test(
     } else {
         // Set up identifiers
         let mut local_identifier = LocalTraceIdentifier::new(&runner.known_contracts);
+        let known_contracts = runner.known_contracts.clone();
         let remote_chain_id = runner.evm_opts.get_remote_chain_id();
         // Do not re-query etherscan for contracts that you've already queried today.
         let mut etherscan_identifier = EtherscanIdentifier::new(&config, remote_chain_id)?;
@@ -512,6 +624,8 @@ fn test(
 
         let mut results: BTreeMap<String, SuiteResult> = BTreeMap::new();
         let mut gas_report = GasReport::new(config.gas_reports, config.gas_reports_ignore);
+        let mut internal_gas_report = InternalGasReport::default();
+        let mut folded_stacks: Vec<String> = Vec::new();
         let sig_identifier =
             SignaturesIdentifier::new(Config::foundry_cache_dir(), config.offline)?;
 
@@ -528,6 +642,56 @@ fn test(
             for (name, result) in &mut tests {
                 short_test_result(name, result);
 
+                if let Some((report, source_maps, ic_pc_maps)) = &internal_gas_report_data {
+                    if let Some(hit_maps) = result.coverage.take() {
+                        for hit_map in hit_maps.0.values() {
+                            let Some((artifact_id, _)) =
+                                known_contracts.find_by_code(hit_map.bytecode.as_ref())
+                            else {
+                                continue
+                            };
+                            let Some(&source_id) = report.get_source_id(
+                                artifact_id.version.clone(),
+                                artifact_id.source.to_string_lossy().to_string(),
+                            ) else {
+                                continue
+                            };
+                            let contract_id = ContractId {
+                                version: artifact_id.version.clone(),
+                                source_id,
+                                contract_name: artifact_id.name.clone(),
+                            };
+                            let (Some((_, source_map)), Some((_, ic_pc_map)), Some(items)) = (
+                                source_maps.get(&contract_id),
+                                ic_pc_maps.get(&contract_id),
+                                report.items.get(&contract_id.version),
+                            ) else {
+                                continue
+                            };
+                            let item_ids: Vec<usize> = items
+                                .iter()
+                                .enumerate()
+                                .filter_map(|(id, item)| {
+                                    (item.loc.contract_name == contract_id.contract_name &&
+                                        matches!(item.kind, CoverageItemKind::Function { .. }))
+                                    .then_some(id)
+                                })
+                                .collect();
+                            for (item_id, gas) in
+                                attribute_gas(hit_map, source_map, ic_pc_map, &item_ids, items)
+                            {
+                                if let CoverageItemKind::Function { name } = &items[item_id].kind {
+                                    internal_gas_report.add(
+                                        contract_id.contract_name.clone(),
+                                        name.clone(),
+                                        gas,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // We only display logs at level 2 and above
                 if verbosity >= 2 {
                     // We only decode logs from Hardhat and DS-style console events
@@ -542,9 +706,12 @@ fn test(
                 }
 
                 if !result.traces.is_empty() {
-                    // Identify addresses in each trace
+                    // Identify addresses in each trace. Labels configured in `foundry.toml` are
+                    // applied first, so `vm.label` calls at runtime can still override them.
+                    let mut labels = config.labels.clone();
+                    labels.extend(result.labeled_addresses.clone());
                     let mut decoder = CallTraceDecoderBuilder::new()
-                        .with_labels(result.labeled_addresses.clone())
+                        .with_labels(labels)
                         .with_events(local_identifier.events())
                         .with_verbosity(verbosity)
                         .build();
@@ -575,14 +742,18 @@ fn test(
                             _ => false,
                         };
 
-                        // We decode the trace if we either need to build a gas report or we need
-                        // to print it
-                        if should_include || gas_reporting {
+                        // We decode the trace if we either need to build a gas report, print it,
+                        // or fold it into the flamegraph
+                        if should_include || gas_reporting || flamegraph.is_some() {
                             rt.block_on(decoder.decode(trace));
                         }
 
                         if should_include {
-                            decoded_traces.push(trace.to_string());
+                            decoded_traces.push(trace.render(true, max_trace_depth));
+                        }
+
+                        if flamegraph.is_some() {
+                            folded_stacks.extend(trace.folded_stack_lines());
                         }
                     }
 
@@ -608,6 +779,15 @@ fn test(
             println!("{}", gas_report.finalize());
         }
 
+        if internal_gas_report_data.is_some() {
+            println!("{internal_gas_report}");
+        }
+
+        if let Some(path) = flamegraph {
+            std::fs::write(&path, folded_stacks.join("\n"))?;
+            println!("Flamegraph folded stacks written to {}", path.display());
+        }
+
         // reattach the thread
         let _ = handle.join();
 
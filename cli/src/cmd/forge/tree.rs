@@ -7,10 +7,22 @@ use ethers::solc::Graph;
 foundry_config::impl_figment_convert!(TreeArgs, opts);
 use crate::cmd::{forge::build::ProjectPathsArgs, LoadConfig};
 use ethers::solc::resolver::{Charset, TreeOptions};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+};
 
 /// CLI arguments for `forge tree`.
 #[derive(Debug, Clone, Parser)]
 pub struct TreeArgs {
+    #[clap(
+        help = "Only print the tree rooted at this contract/file, instead of the whole project.",
+        value_name = "CONTRACT"
+    )]
+    contract: Option<String>,
+
     #[clap(help = "Do not de-duplicate (repeats all shared dependencies)", long)]
     no_dedupe: bool,
     #[clap(
@@ -29,10 +41,180 @@ impl Cmd for TreeArgs {
 
     fn run(self) -> eyre::Result<Self::Output> {
         let config = self.try_load_config_emit_warnings()?;
-        let graph = Graph::resolve(&config.project_paths())?;
-        let opts = TreeOptions { charset: self.charset, no_dedupe: self.no_dedupe };
-        graph.print_with_options(opts);
+        let paths = config.project_paths();
+        let graph = Graph::resolve(&paths)?;
+
+        match &self.contract {
+            None => {
+                let opts = TreeOptions { charset: self.charset, no_dedupe: self.no_dedupe };
+                graph.print_with_options(opts);
+            }
+            Some(contract) => {
+                let sources = collect_sources(&graph);
+                let root = resolve_root(&sources, contract)?;
+                print_rooted_tree(root, &sources, &paths.root, self.charset, self.no_dedupe);
+            }
+        }
 
         Ok(())
     }
 }
+
+/// Matches every flavor of Solidity import statement, capturing only the quoted path, e.g.
+/// `import "./Foo.sol";`, `import {Foo} from "./Foo.sol";`, `import * as Foo from "./Foo.sol";`.
+static IMPORT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^\s*import\s+[^;]*["']([^"']+)["']\s*;"#).unwrap());
+
+/// Builds a `path -> source` map out of every file the graph resolved, so the rooted tree can be
+/// walked without depending on the graph's own (unverified/private) edge representation.
+fn collect_sources(graph: &Graph) -> HashMap<PathBuf, String> {
+    graph
+        .files()
+        .iter()
+        .map(|(_, index)| {
+            let (path, source) = graph.node(*index).unpack();
+            (path, source)
+        })
+        .collect()
+}
+
+/// Resolves `contract` to a single source file: either a path to an existing file in the project,
+/// or a bare contract/file name that's matched against every file's stem. Errors the same way
+/// `forge inspect` does when the name exists in more than one file.
+fn resolve_root<'a>(
+    sources: &'a HashMap<PathBuf, String>,
+    contract: &str,
+) -> eyre::Result<&'a Path> {
+    if let Ok(path) = dunce::canonicalize(contract) {
+        if let Some((key, _)) = sources.get_key_value(path.as_path()) {
+            return Ok(key.as_path());
+        }
+    }
+
+    let candidates: Vec<&PathBuf> = sources
+        .keys()
+        .filter(|path| path.file_stem().and_then(|s| s.to_str()) == Some(contract))
+        .collect();
+
+    match candidates.len() {
+        0 => eyre::bail!("No source file found for `{contract}`"),
+        1 => Ok(candidates[0].as_path()),
+        _ => eyre::bail!(
+            "`{contract}` exists in more than one file, please specify the path:\n    {}",
+            candidates.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join("\n    ")
+        ),
+    }
+}
+
+/// Prints the import tree rooted at `root`, in the same indented style as
+/// `Graph::print_with_options`, but starting from a single file instead of the whole project.
+///
+/// Note: unlike the whole-project tree (which reuses the compiler's own import graph), this walks
+/// the source text itself to find `import` statements and resolves them relative to either the
+/// importing file or the project root. It doesn't know about remappings, so an import that only
+/// resolves via a remapping (e.g. `@openzeppelin/...`) is rendered as unresolved rather than
+/// expanded.
+fn print_rooted_tree(
+    root: &Path,
+    sources: &HashMap<PathBuf, String>,
+    project_root: &Path,
+    charset: Charset,
+    no_dedupe: bool,
+) {
+    let ascii = format!("{charset:?}").eq_ignore_ascii_case("ascii");
+    println!("{}", root.display());
+
+    let mut stack = vec![root.to_path_buf()];
+    let mut printed = HashSet::new();
+    printed.insert(root.to_path_buf());
+    print_children(root, sources, project_root, ascii, no_dedupe, "", &mut stack, &mut printed);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn print_children(
+    node: &Path,
+    sources: &HashMap<PathBuf, String>,
+    project_root: &Path,
+    ascii: bool,
+    no_dedupe: bool,
+    prefix: &str,
+    stack: &mut Vec<PathBuf>,
+    printed: &mut HashSet<PathBuf>,
+) {
+    let (branch, last, vert, space) = if ascii {
+        ("|-- ", "`-- ", "|   ", "    ")
+    } else {
+        ("├── ", "└── ", "│   ", "    ")
+    };
+
+    let children = resolve_imports(node, sources, project_root);
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+        let connector = if is_last { last } else { branch };
+
+        match child {
+            Import::Resolved(path) => {
+                let in_cycle = stack.contains(path);
+                let already_printed = !no_dedupe && printed.contains(path);
+                let marker = if in_cycle {
+                    " (*) (import cycle)"
+                } else if already_printed {
+                    " (*)"
+                } else {
+                    ""
+                };
+                println!("{prefix}{connector}{}{marker}", path.display());
+
+                if !in_cycle && !already_printed {
+                    printed.insert(path.clone());
+                    stack.push(path.clone());
+                    let child_prefix = format!("{prefix}{}", if is_last { space } else { vert });
+                    print_children(
+                        path,
+                        sources,
+                        project_root,
+                        ascii,
+                        no_dedupe,
+                        &child_prefix,
+                        stack,
+                        printed,
+                    );
+                    stack.pop();
+                }
+            }
+            Import::Unresolved(raw) => {
+                println!("{prefix}{connector}{raw} (unresolved)");
+            }
+        }
+    }
+}
+
+enum Import {
+    Resolved(PathBuf),
+    Unresolved(String),
+}
+
+/// Extracts the files `file` imports, in source order, resolving each against either the
+/// importing file's own directory (for relative imports) or the project root (for the
+/// project-root-relative style used elsewhere in this repo, e.g. `import "src/Foo.sol";`).
+fn resolve_imports(
+    file: &Path,
+    sources: &HashMap<PathBuf, String>,
+    project_root: &Path,
+) -> Vec<Import> {
+    let Some(source) = sources.get(file) else { return Vec::new() };
+    let dir = file.parent().unwrap_or(project_root);
+
+    IMPORT_RE
+        .captures_iter(source)
+        .map(|cap| {
+            let raw = cap[1].to_string();
+            let candidate =
+                if raw.starts_with('.') { dir.join(&raw) } else { project_root.join(&raw) };
+            match dunce::canonicalize(&candidate) {
+                Ok(resolved) if sources.contains_key(&resolved) => Import::Resolved(resolved),
+                _ => Import::Unresolved(raw),
+            }
+        })
+        .collect()
+}
@@ -1,5 +1,11 @@
 //! Update command
-use crate::{cmd::Cmd, utils::CommandUtils};
+use crate::{
+    cmd::{
+        forge::lockfile::{resolve_commit, Lockfile},
+        Cmd,
+    },
+    utils::{self, CommandUtils},
+};
 use clap::{Parser, ValueHint};
 use std::{path::PathBuf, process::Command};
 
@@ -11,20 +17,52 @@ pub struct UpdateArgs {
         value_hint = ValueHint::DirPath
     )]
     lib: Option<PathBuf>,
+
+    #[clap(
+        help = "The project's root path.",
+        long_help = "The project's root path. By default, this is the root directory of the current Git repository, or the current working directory.",
+        long,
+        value_hint = ValueHint::DirPath,
+        value_name = "PATH"
+    )]
+    pub root: Option<PathBuf>,
 }
 
 impl Cmd for UpdateArgs {
     type Output = ();
 
     fn run(self) -> eyre::Result<Self::Output> {
+        let config = utils::load_config_with_root(self.root.clone());
+        let root = config.__root.0.clone();
+        let libs = root.join(config.install_lib_dir());
+
         let mut cmd = Command::new("git");
-        cmd.args(["submodule", "update", "--remote", "--init"]);
+        cmd.current_dir(&root).args(["submodule", "update", "--remote", "--init"]);
         // if a lib is specified, open it
-        if let Some(lib) = self.lib {
+        if let Some(lib) = &self.lib {
             cmd.args(["--", lib.display().to_string().as_str()]);
         }
         cmd.exec()?;
 
+        // re-pin the lockfile to whatever commits the update left checked out
+        let mut lockfile = Lockfile::read(&root)?;
+        let targets: Vec<String> = match &self.lib {
+            Some(lib) => {
+                let target = lib.strip_prefix(config.install_lib_dir()).unwrap_or(lib);
+                vec![target.display().to_string()]
+            }
+            None => lockfile.dependencies.keys().cloned().collect(),
+        };
+        for target_dir in targets {
+            let dir = libs.join(&target_dir);
+            let Some(entry) = lockfile.dependencies.get(&target_dir) else { continue };
+            if let Ok(rev) = resolve_commit(&dir) {
+                let url = entry.url.clone();
+                lockfile.set(target_dir, url, rev, None);
+            }
+        }
+        lockfile.write(&root)?;
+
         Ok(())
     }
 }
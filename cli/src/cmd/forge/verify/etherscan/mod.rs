@@ -14,10 +14,11 @@ use ethers::{
         verify::{CodeFormat, VerifyContract},
         Client,
     },
+    prelude::Middleware,
     solc::{artifacts::CompactContract, cache::CacheEntry, Project, Solc},
 };
 use eyre::{eyre, Context};
-use foundry_common::abi::encode_args;
+use foundry_common::{abi::encode_args, try_get_http_provider};
 use foundry_config::{Chain, Config, SolcReq};
 use foundry_utils::Retry;
 use futures::FutureExt;
@@ -114,6 +115,13 @@ impl VerificationProvider for EtherscanVerificationProvider {
                 etherscan.address_url(args.address)
             );
 
+            // Persist the GUID so a dropped `--watch` connection (or a later manual check) can
+            // still find it via `forge verify-check <guid>` without having to scroll back through
+            // the submission output.
+            if let Err(err) = self.save_guid(&args, &resp.result) {
+                warn!("Failed to persist verification GUID: {err}");
+            }
+
             if args.watch {
                 let check_args = VerifyCheckArgs {
                     id: resp.result,
@@ -189,6 +197,29 @@ impl VerificationProvider for EtherscanVerificationProvider {
 }
 
 impl EtherscanVerificationProvider {
+    /// Persists the verification GUID returned by Etherscan to the project's cache directory, so
+    /// it isn't lost if the connection drops before `--watch` can poll it to completion.
+    fn save_guid(&self, args: &VerifyArgs, guid: &str) -> eyre::Result<()> {
+        let config = args.try_load_config_emit_warnings()?;
+        let path = Self::guid_path(&config, args);
+        foundry_common::fs::create_dir_all(path.parent().unwrap())?;
+        foundry_common::fs::write_json_file(
+            &path,
+            &serde_json::json!({
+                "guid": guid,
+                "address": format!("{:?}", args.address),
+                "chain": args.chain.id(),
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// Returns the path the verification GUID for `args` is persisted to.
+    fn guid_path(config: &Config, args: &VerifyArgs) -> PathBuf {
+        let filename = format!("{}-{:?}.json", args.chain.id(), args.address);
+        config.cache_path.join("verify").join(filename)
+    }
+
     /// Create a source provider
     fn source_provider(&self, args: &VerifyArgs) -> Box<dyn EtherscanSourceProvider> {
         if args.flatten {
@@ -227,11 +258,71 @@ impl EtherscanVerificationProvider {
             args.etherscan_key.as_deref(),
             &config,
         )?;
+        self.check_onchain_bytecode(args, &config).await?;
         let verify_args = self.create_verify_request(args, Some(config)).await?;
 
         Ok((etherscan, verify_args))
     }
 
+    /// Fetches the contract's deployed bytecode from the configured RPC endpoint and compares it
+    /// against the locally compiled runtime bytecode, ignoring the trailing CBOR metadata hash
+    /// (which legitimately differs across otherwise-identical compilations). A mismatch almost
+    /// always means the contract was compiled with different settings than it was deployed with,
+    /// and Etherscan's own rejection message for that case is not very actionable, so we fail
+    /// fast here with a summary of the settings that are most likely to blame.
+    async fn check_onchain_bytecode(
+        &mut self,
+        args: &VerifyArgs,
+        config: &Config,
+    ) -> eyre::Result<()> {
+        let project = config.project()?;
+        let (_, _, contract) = match self.cache_entry(&project, &args.contract.name) {
+            Ok(entry) => entry,
+            // without cache we have no local bytecode to compare against, so don't block on it
+            Err(_) => return Ok(()),
+        };
+        let local_bytecode = match contract.bin_runtime.as_ref().and_then(|bin| bin.as_bytes()) {
+            Some(bytecode) => bytecode.clone(),
+            None => return Ok(()),
+        };
+
+        let provider = try_get_http_provider(config.get_rpc_url_or_localhost_http()?).wrap_err(
+            "Failed to establish an RPC connection to cross-check the deployed bytecode",
+        )?;
+        let onchain_bytecode = provider
+            .get_code(args.address, None)
+            .await
+            .wrap_err("Failed to fetch the deployed bytecode from the RPC endpoint")?;
+
+        if onchain_bytecode.0.is_empty() {
+            eyre::bail!(
+                "No bytecode found at {:?} on chain {}. Is the contract actually deployed there?",
+                args.address,
+                args.chain
+            )
+        }
+
+        if strip_bytecode_metadata(&onchain_bytecode) != strip_bytecode_metadata(&local_bytecode) {
+            eyre::bail!(
+                "The bytecode deployed at {:?} does not match the locally compiled bytecode for `{}` \
+                 (ignoring metadata). Double check these settings against how the contract was \
+                 actually deployed before verifying:\n\
+                 \tcompiler version: {}\n\
+                 \tlibraries: {:?}\n\
+                 \toptimizer runs: {}",
+                args.address,
+                args.contract.name,
+                args.compiler_version.as_deref().unwrap_or("<inferred from cache>"),
+                args.libraries,
+                args.num_of_optimizations
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| config.optimizer_runs.to_string()),
+            )
+        }
+
+        Ok(())
+    }
+
     /// Create an etherscan client
     pub(crate) fn client(
         &self,
@@ -282,7 +373,13 @@ impl EtherscanVerificationProvider {
             self.source_provider(args).source(args, &project, &contract_path, &compiler_version)?;
 
         let compiler_version = format!("v{}", ensure_solc_build_metadata(compiler_version).await?);
-        let constructor_args = self.constructor_args(args, &project)?;
+        let etherscan = self.client(
+            args.chain,
+            args.verifier.verifier_url.as_deref(),
+            args.etherscan_key.as_deref(),
+            &config,
+        )?;
+        let constructor_args = self.constructor_args(args, &project, &etherscan).await?;
         let mut verify_args =
             VerifyContract::new(args.address, contract_name, source, compiler_version)
                 .constructor_arguments(constructor_args)
@@ -372,13 +469,19 @@ impl EtherscanVerificationProvider {
     }
 
     /// Return the optional encoded constructor arguments. If the path to
-    /// constructor arguments was provided, read them and encode. Otherwise,
-    /// return whatever was set in the [VerifyArgs] args.
-    fn constructor_args(
+    /// constructor arguments was provided, read them and encode. If
+    /// `--guess-constructor-args` was passed, recover them from the on-chain deployment
+    /// transaction. Otherwise, return whatever was set in the [VerifyArgs] args.
+    async fn constructor_args(
         &mut self,
         args: &VerifyArgs,
         project: &Project,
+        etherscan: &Client,
     ) -> eyre::Result<Option<String>> {
+        if args.guess_constructor_args {
+            return Ok(Some(self.guess_constructor_args(args, project, etherscan).await?))
+        }
+
         if let Some(ref constructor_args_path) = args.constructor_args_path {
             let (_, _, contract) = self.cache_entry(project, &args.contract.name).wrap_err(
                 "Cache must be enabled in order to use the `--constructor-args-path` option",
@@ -405,6 +508,65 @@ impl EtherscanVerificationProvider {
 
         Ok(args.constructor_args.clone())
     }
+
+    /// Recovers the ABI-encoded constructor arguments by fetching the contract creation
+    /// transaction from Etherscan and stripping the known, locally compiled creation bytecode
+    /// off the front of its calldata. Whatever remains is the constructor arguments.
+    async fn guess_constructor_args(
+        &mut self,
+        args: &VerifyArgs,
+        project: &Project,
+        etherscan: &Client,
+    ) -> eyre::Result<String> {
+        let creation_tx = etherscan
+            .get_transactions(&args.address, None)
+            .await
+            .wrap_err("Failed to fetch the contract's transaction history from Etherscan")?
+            .into_iter()
+            .find(|tx| tx.to.is_none())
+            .ok_or_else(|| {
+                eyre!(
+                    "Could not find the contract creation transaction for {:?} on Etherscan",
+                    args.address
+                )
+            })?;
+
+        let (_, _, contract) = self.cache_entry(project, &args.contract.name).wrap_err(
+            "If cache is disabled, constructor arguments can't be guessed from on-chain data",
+        )?;
+        let creation_code =
+            contract.bin.as_ref().and_then(|bytecode| bytecode.as_bytes()).ok_or_else(|| {
+                eyre!("Could not find the compiled creation bytecode in the cached artifact.")
+            })?;
+
+        let calldata = creation_tx.input.as_ref();
+        if calldata.len() < creation_code.len()
+            || calldata[..creation_code.len()] != creation_code[..]
+        {
+            eyre::bail!(
+                "The locally compiled creation bytecode doesn't match the deployment transaction's \
+                 calldata, so the constructor arguments can't be recovered. The contract was likely \
+                 compiled with different settings than it was deployed with."
+            )
+        }
+
+        Ok(calldata[creation_code.len()..].to_hex::<String>())
+    }
+}
+
+/// Strips the trailing CBOR-encoded metadata hash solc appends to compiled bytecode, so bytecode
+/// that only differs by that metadata (e.g. due to unrelated comment changes or a different solc
+/// build) can still be compared for equality.
+fn strip_bytecode_metadata(bytecode: &[u8]) -> &[u8] {
+    if bytecode.len() < 2 {
+        return bytecode
+    }
+    let metadata_len =
+        u16::from_be_bytes([bytecode[bytecode.len() - 2], bytecode[bytecode.len() - 1]]) as usize;
+    if metadata_len + 2 > bytecode.len() {
+        return bytecode
+    }
+    &bytecode[..bytecode.len() - metadata_len - 2]
 }
 
 /// Given any solc [Version] return a [Version] with build metadata
@@ -72,6 +72,13 @@ pub struct VerifyArgs {
     )]
     pub constructor_args_path: Option<PathBuf>,
 
+    #[clap(
+        long,
+        help = "Try to guess the constructor arguments from the on-chain deployment transaction.",
+        conflicts_with_all = &["constructor_args", "constructor_args_path"]
+    )]
+    pub guess_constructor_args: bool,
+
     #[clap(
         long,
         help = "The compiler version used to build the smart contract.",
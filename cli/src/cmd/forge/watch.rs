@@ -6,7 +6,13 @@ use crate::{
 };
 use clap::{ArgAction, Parser};
 use foundry_config::Config;
-use std::{collections::HashSet, convert::Infallible, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashSet,
+    convert::Infallible,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 use tracing::trace;
 use watchexec::{
     action::{Action, Outcome, PreSpawn},
@@ -84,8 +90,19 @@ impl WatchArgs {
     }
 }
 
-/// Executes a [`Watchexec`] that listens for changes in the project's src dir and reruns `forge
-/// build`
+/// Tracks when the most recently spawned iteration started and which paths triggered it, so a
+/// compact summary can be printed once the iteration completes.
+#[derive(Debug, Default)]
+struct IterationState {
+    started_at: Option<Instant>,
+    changed_paths: Vec<String>,
+}
+
+/// Shared, cloneable handle to the currently running iteration's [`IterationState`].
+type WatchState = Arc<Mutex<IterationState>>;
+
+/// Executes a [`Watchexec`] that listens for changes in the project's src, test, script and lib
+/// dirs and reruns `forge build`
 pub async fn watch_build(args: BuildArgs) -> eyre::Result<()> {
     let (init, mut runtime) = args.watchexec_config()?;
     let cmd = cmd_args(args.watch.watch.as_ref().map(|paths| paths.len()).unwrap_or_default());
@@ -93,8 +110,10 @@ pub async fn watch_build(args: BuildArgs) -> eyre::Result<()> {
     trace!("watch build cmd={:?}", cmd);
     runtime.command(watch_command(cmd.clone()));
 
+    let timing = install_iteration_hooks(&mut runtime);
+
     let wx = Watchexec::new(init, runtime.clone())?;
-    on_action(args.watch, runtime, Arc::clone(&wx), cmd, (), |_| {});
+    on_action(args.watch, runtime, Arc::clone(&wx), cmd, timing, (), |_| {});
 
     // start executing the command immediately
     wx.send_event(Event::default(), Priority::default()).await?;
@@ -111,9 +130,10 @@ pub async fn watch_snapshot(args: SnapshotArgs) -> eyre::Result<()> {
 
     trace!("watch snapshot cmd={:?}", cmd);
     runtime.command(watch_command(cmd.clone()));
+    let timing = install_iteration_hooks(&mut runtime);
     let wx = Watchexec::new(init, runtime.clone())?;
 
-    on_action(args.test.watch.clone(), runtime, Arc::clone(&wx), cmd, (), |_| {});
+    on_action(args.test.watch.clone(), runtime, Arc::clone(&wx), cmd, timing, (), |_| {});
 
     // start executing the command immediately
     wx.send_event(Event::default(), Priority::default()).await?;
@@ -129,6 +149,7 @@ pub async fn watch_test(args: TestArgs) -> eyre::Result<()> {
     let cmd = cmd_args(args.watch.watch.as_ref().map(|paths| paths.len()).unwrap_or_default());
     trace!("watch test cmd={:?}", cmd);
     runtime.command(watch_command(cmd.clone()));
+    let timing = install_iteration_hooks(&mut runtime);
     let wx = Watchexec::new(init, runtime.clone())?;
 
     let config: Config = args.build_args().into();
@@ -147,7 +168,7 @@ pub async fn watch_test(args: TestArgs) -> eyre::Result<()> {
         no_reconfigure,
         last_test_files: Default::default(),
     };
-    on_action(args.watch.clone(), runtime, Arc::clone(&wx), cmd, state, on_test);
+    on_action(args.watch.clone(), runtime, Arc::clone(&wx), cmd, timing, state, on_test);
 
     // start executing the command immediately
     wx.send_event(Event::default(), Priority::default()).await?;
@@ -156,6 +177,48 @@ pub async fn watch_test(args: TestArgs) -> eyre::Result<()> {
     Ok(())
 }
 
+/// Installs a `pre_spawn` hook on `runtime` that, for every iteration: records the start time and
+/// changed paths (so [`on_action`] can print a compact summary with timing once the iteration
+/// completes), prints the change set so shells consuming stdout can act on it, and forwards the
+/// changed paths as `CARGO_WATCH_*_PATH` env vars to the spawned command.
+fn install_iteration_hooks(runtime: &mut RuntimeConfig) -> WatchState {
+    let state = WatchState::default();
+    let hook_state = Arc::clone(&state);
+
+    runtime.on_pre_spawn(move |prespawn: PreSpawn| {
+        let hook_state = Arc::clone(&hook_state);
+        async move {
+            let changed_paths: Vec<String> = prespawn
+                .events
+                .iter()
+                .flat_map(|e| e.paths())
+                .filter_map(|(path, _)| path.to_str())
+                .map(str::to_string)
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect();
+
+            if !changed_paths.is_empty() {
+                println!("Change detected: {}", changed_paths.join(", "));
+            }
+
+            *hook_state.lock().unwrap() =
+                IterationState { started_at: Some(Instant::now()), changed_paths };
+
+            let envs = summarise_events_to_env(prespawn.events.iter());
+            if let Some(mut command) = prespawn.command().await {
+                for (k, v) in envs {
+                    command.env(format!("CARGO_WATCH_{k}_PATH"), v);
+                }
+            }
+
+            Ok::<(), Infallible>(())
+        }
+    });
+
+    state
+}
+
 #[derive(Debug, Clone)]
 struct WatchTestState {
     /// the root directory of the project
@@ -170,7 +233,7 @@ struct WatchTestState {
 
 /// The `on_action` hook for `forge test --watch`
 fn on_test(action: OnActionState<WatchTestState>) {
-    let OnActionState { args, runtime, action, wx, cmd, other } = action;
+    let OnActionState { args, runtime, action, wx, cmd, timing, other } = action;
     let WatchTestState { project_root, no_reconfigure, last_test_files } = other;
 
     if no_reconfigure {
@@ -209,6 +272,7 @@ fn on_test(action: OnActionState<WatchTestState>) {
             config,
             wx,
             cmd,
+            timing,
             WatchTestState {
                 project_root,
                 no_reconfigure,
@@ -249,6 +313,7 @@ fn on_test(action: OnActionState<WatchTestState>) {
         config,
         wx,
         cmd,
+        timing,
         WatchTestState { project_root, no_reconfigure, last_test_files: changed_sol_test_files },
         on_test,
     );
@@ -295,6 +360,7 @@ struct OnActionState<'a, T: Clone> {
     action: &'a Action,
     cmd: &'a Vec<String>,
     wx: Arc<Watchexec>,
+    timing: WatchState,
     // additional context to inject
     other: T,
 }
@@ -309,6 +375,7 @@ fn on_action<F, T>(
     mut config: RuntimeConfig,
     wx: Arc<Watchexec>,
     cmd: Vec<String>,
+    timing: WatchState,
     other: T,
     f: F,
 ) where
@@ -341,24 +408,34 @@ fn on_action<F, T>(
 
             let completion = action.events.iter().flat_map(|e| e.completions()).next();
             if let Some(status) = completion {
-                match status {
-                    Some(ProcessEnd::ExitError(code)) => {
-                        tracing::trace!("Command exited with {code}")
-                    }
-                    Some(ProcessEnd::ExitSignal(sig)) => {
-                        tracing::trace!("Command killed by {:?}", sig)
-                    }
-                    Some(ProcessEnd::ExitStop(sig)) => {
-                        tracing::trace!("Command stopped by {:?}", sig)
-                    }
-                    Some(ProcessEnd::Continued) => tracing::trace!("Command continued"),
-                    Some(ProcessEnd::Exception(ex)) => {
-                        tracing::trace!("Command ended by exception {:#x}", ex)
-                    }
-                    Some(ProcessEnd::Success) => tracing::trace!("Command was successful"),
-                    None => tracing::trace!("Command completed"),
+                // errors are intentionally not propagated here: a failed iteration should not
+                // stop the watcher, we just report it and keep watching for the next change.
+                let (status_display, status_level) = match status {
+                    Some(ProcessEnd::ExitError(code)) => (format!("exit code {code}"), false),
+                    Some(ProcessEnd::ExitSignal(sig)) => (format!("killed by {sig:?}"), false),
+                    Some(ProcessEnd::ExitStop(sig)) => (format!("stopped by {sig:?}"), false),
+                    Some(ProcessEnd::Continued) => ("continued".to_string(), true),
+                    Some(ProcessEnd::Exception(ex)) => (format!("exception {ex:#x}"), false),
+                    Some(ProcessEnd::Success) => ("success".to_string(), true),
+                    None => ("completed".to_string(), true),
+                };
+
+                let iteration = std::mem::take(&mut *timing.lock().unwrap());
+                let elapsed = iteration.started_at.map(|started| started.elapsed());
+                let changed = if iteration.changed_paths.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", iteration.changed_paths.join(", "))
                 };
 
+                match elapsed {
+                    Some(elapsed) => {
+                        println!("Finished in {elapsed:.2?}: {status_display}{changed}")
+                    }
+                    None => println!("Finished: {status_display}{changed}"),
+                }
+                tracing::trace!(success = status_level, "watch iteration finished");
+
                 action.outcome(Outcome::DoNothing);
                 return fut
             }
@@ -370,6 +447,7 @@ fn on_action<F, T>(
             action: &action,
             wx: w.clone(),
             cmd: &cmd,
+            timing: timing.clone(),
             other: other.clone(),
         });
 
@@ -405,16 +483,5 @@ pub fn runtime(args: &WatchArgs) -> eyre::Result<RuntimeConfig> {
         config.action_throttle(utils::parse_delay(delay)?);
     }
 
-    config.on_pre_spawn(move |prespawn: PreSpawn| async move {
-        let envs = summarise_events_to_env(prespawn.events.iter());
-        if let Some(mut command) = prespawn.command().await {
-            for (k, v) in envs {
-                command.env(format!("CARGO_WATCH_{k}_PATH"), v);
-            }
-        }
-
-        Ok::<(), Infallible>(())
-    });
-
     Ok(config)
 }
@@ -12,7 +12,11 @@ use ethers::{
 };
 use eyre::WrapErr;
 use forge::executor::opts::EvmOpts;
-use foundry_common::{cli_warn, fs, TestFunctionExt};
+use foundry_common::{
+    cli_warn,
+    errors::{ExitCode, ExitCodeError},
+    fs, TestFunctionExt,
+};
 use foundry_config::{error::ExtractConfigError, figment::Figment, Chain as ConfigChain, Config};
 use std::{fmt::Write, path::PathBuf};
 use tracing::trace;
@@ -238,8 +242,12 @@ where
 
     fn load_config_and_evm_opts(self) -> eyre::Result<(Config, EvmOpts)> {
         let figment: Figment = self.into();
-        let mut evm_opts = figment.extract::<EvmOpts>()?;
-        let config = Config::try_from(figment)?.sanitized();
+        let mut evm_opts = figment
+            .extract::<EvmOpts>()
+            .map_err(|err| ExitCodeError::new(ExitCode::ConfigError, err.into()))?;
+        let config = Config::try_from(figment)
+            .map_err(|err| ExitCodeError::new(ExitCode::ConfigError, err.into()))?
+            .sanitized();
 
         // update the fork url if it was an alias
         if let Some(fork_url) = config.get_rpc_url() {
@@ -9,8 +9,16 @@ use foundry_cli::{
     opts::forge::{Opts, Subcommands},
     utils,
 };
+use foundry_common::errors::ExitCodeError;
 
-fn main() -> eyre::Result<()> {
+fn main() {
+    if let Err(report) = run() {
+        eprintln!("{report:?}");
+        std::process::exit(ExitCodeError::code_of(&report).into());
+    }
+}
+
+fn run() -> eyre::Result<()> {
     utils::load_dotenv();
     handler::install()?;
     utils::subscriber();
@@ -84,9 +92,8 @@ fn main() -> eyre::Result<()> {
             "forge",
             &mut std::io::stdout(),
         ),
-        Subcommands::Clean { root } => {
-            let config = utils::load_config_with_root(root);
-            config.project()?.cleanup()?;
+        Subcommands::Clean(cmd) => {
+            cmd.run()?;
         }
         Subcommands::Snapshot(cmd) => {
             if cmd.is_watch() {
@@ -110,6 +117,9 @@ fn main() -> eyre::Result<()> {
         Subcommands::UploadSelectors(args) => {
             utils::block_on(args.run())?;
         }
+        Subcommands::Selectors(cmd) => {
+            utils::block_on(cmd.run())?;
+        }
         Subcommands::Tree(cmd) => {
             cmd.run()?;
         }
@@ -1,9 +1,10 @@
 use super::{ClapChain, EthereumOpts};
 use crate::{
     cmd::cast::{
-        call::CallArgs, create2::Create2Args, estimate::EstimateArgs, find_block::FindBlockArgs,
-        interface::InterfaceArgs, rpc::RpcArgs, run::RunArgs, send::SendTxArgs,
-        storage::StorageArgs, wallet::WalletSubcommands,
+        call::CallArgs, create2::Create2Args, estimate::EstimateArgs, fee_history::FeeHistoryArgs,
+        find_block::FindBlockArgs, interface::InterfaceArgs, logs::LogsArgs,
+        multicall::MulticallArgs, pretty_calldata::PrettyCalldataArgs, rpc::RpcArgs,
+        run::RunArgs, send::SendTxArgs, storage::StorageArgs, wallet::WalletSubcommands,
     },
     utils::parse_u256,
 };
@@ -270,6 +271,12 @@ Examples:
         full: bool,
         #[clap(long = "json", short = 'j', help_heading = "Display options")]
         to_json: bool,
+        #[clap(
+            long = "hex",
+            help = "Print a selected numeric field in hex instead of decimal.",
+            help_heading = "Display options"
+        )]
+        hex: bool,
         #[clap(long, env = "ETH_RPC_URL", value_name = "URL")]
         rpc_url: Option<String>,
     },
@@ -345,6 +352,12 @@ Examples:
         field: Option<String>,
         #[clap(long = "json", short = 'j', help_heading = "Display options")]
         to_json: bool,
+        #[clap(
+            long = "hex",
+            help = "Print a selected numeric field in hex instead of decimal.",
+            help_heading = "Display options"
+        )]
+        hex: bool,
         #[clap(long, env = "ETH_RPC_URL", value_name = "URL")]
         rpc_url: Option<String>,
     },
@@ -364,6 +377,13 @@ Examples:
             value_name = "CONFIRMATIONS"
         )]
         confirmations: usize,
+        #[clap(
+            long,
+            help = "Timeout for the receipt wait, in seconds.",
+            default_value = "120",
+            value_name = "TIMEOUT"
+        )]
+        timeout: u64,
         #[clap(
             long = "async",
             env = "CAST_ASYNC",
@@ -455,6 +475,19 @@ Defaults to decoding output data. To decode input data pass --input or use cast
         #[clap(help = "The storage slot of the mapping.", value_name = "SLOT_NUMBER")]
         slot_number: String,
     },
+    #[clap(name = "index-array")]
+    #[clap(about = "Compute the storage slot for an element in a dynamic array.")]
+    IndexArray {
+        #[clap(help = "The storage slot of the array.", value_name = "SLOT")]
+        slot_number: String,
+        #[clap(help = "The index of the element.", value_name = "INDEX")]
+        index: String,
+        #[clap(
+            help = "The number of storage slots occupied by each element. Defaults to 1.",
+            value_name = "ELEMENT_SIZE"
+        )]
+        element_size: Option<String>,
+    },
     #[clap(name = "4byte")]
     #[clap(visible_aliases = &["4", "4b"])]
     #[clap(
@@ -463,6 +496,11 @@ Defaults to decoding output data. To decode input data pass --input or use cast
     FourByte {
         #[clap(help = "The function selector.", value_name = "SELECTOR")]
         selector: String,
+        #[clap(
+            long,
+            help = "Only look up the selector in the local cache, without querying https://sig.eth.samczsun.com."
+        )]
+        offline: bool,
     },
     #[clap(name = "4byte-decode")]
     #[clap(visible_aliases = &["4d", "4bd"])]
@@ -470,6 +508,17 @@ Defaults to decoding output data. To decode input data pass --input or use cast
     FourByteDecode {
         #[clap(help = "The ABI-encoded calldata.", value_name = "CALLDATA")]
         calldata: Option<String>,
+        #[clap(
+            long,
+            help = "The index of the matching signature to use, in case of ambiguity. Bypasses the interactive prompt.",
+            value_name = "INDEX"
+        )]
+        index: Option<usize>,
+        #[clap(
+            long,
+            help = "Only look up the selector in the local cache, without querying https://sig.eth.samczsun.com."
+        )]
+        offline: bool,
     },
     #[clap(name = "4byte-event")]
     #[clap(visible_aliases = &["4e", "4be"])]
@@ -479,6 +528,11 @@ Defaults to decoding output data. To decode input data pass --input or use cast
     FourByteEvent {
         #[clap(help = "Topic 0", value_name = "TOPIC_0")]
         topic: String,
+        #[clap(
+            long,
+            help = "Only look up the topic in the local cache, without querying https://sig.eth.samczsun.com."
+        )]
+        offline: bool,
     },
     #[clap(name = "upload-signature")]
     #[clap(visible_aliases = &["ups"])]
@@ -504,14 +558,11 @@ Examples:
         about = "Pretty print calldata.",
         long_about = r#"Pretty print calldata.
 
-Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline is passed."#
+Tries to decode the calldata against every signature in the local cache/signature directory that
+actually matches it unless --offline is passed. Pass --recurse to also decode nested calldata
+carried in `bytes`/`bytes[]` arguments, e.g. multicall batches or a Safe's execTransaction."#
     )]
-    PrettyCalldata {
-        #[clap(help = "The calldata.", value_name = "CALLDATA")]
-        calldata: String,
-        #[clap(long, short, help = "Skip the https://sig.eth.samczsun.com lookup.")]
-        offline: bool,
-    },
+    PrettyCalldata(PrettyCalldataArgs),
     #[clap(name = "age")]
     #[clap(visible_alias = "a")]
     #[clap(about = "Get the timestamp of a block.")]
@@ -561,6 +612,14 @@ Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline
         block: Option<BlockId>,
         #[clap(short, long, env = "ETH_RPC_URL", value_name = "URL")]
         rpc_url: Option<String>,
+        #[clap(
+            long,
+            help = "Print the basefee in gwei instead of wei.",
+            help_heading = "Display options"
+        )]
+        gwei: bool,
+        #[clap(long = "json", short = 'j', help_heading = "Display options")]
+        to_json: bool,
     },
     #[clap(name = "code")]
     #[clap(visible_alias = "co")]
@@ -579,6 +638,15 @@ Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline
         who: NameOrAddress,
         #[clap(short, long, env = "ETH_RPC_URL", value_name = "URL")]
         rpc_url: Option<String>,
+        #[clap(long, help = "Disassemble the bytecode into opcodes.")]
+        disassemble: bool,
+    },
+    #[clap(name = "disassemble")]
+    #[clap(visible_alias = "da")]
+    #[clap(about = "Disassemble hex encoded bytecode into opcodes.")]
+    Disassemble {
+        #[clap(help = "The hex encoded bytecode.", value_name = "BYTECODE")]
+        bytecode: String,
     },
     #[clap(name = "gas-price")]
     #[clap(visible_alias = "g")]
@@ -586,7 +654,19 @@ Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline
     GasPrice {
         #[clap(short, long, env = "ETH_RPC_URL", value_name = "URL")]
         rpc_url: Option<String>,
+        #[clap(
+            long,
+            help = "Print the gas price in gwei instead of wei.",
+            help_heading = "Display options"
+        )]
+        gwei: bool,
+        #[clap(long = "json", short = 'j', help_heading = "Display options")]
+        to_json: bool,
     },
+    #[clap(name = "fee-history")]
+    #[clap(visible_alias = "fh")]
+    #[clap(about = "Get the base fee and priority fee percentiles for a range of blocks.")]
+    FeeHistory(FeeHistoryArgs),
     #[clap(name = "sig-event")]
     #[clap(visible_alias = "se")]
     #[clap(about = "Generate event signatures from event string.")]
@@ -633,6 +713,14 @@ Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline
         about = "Get the raw value of a contract's storage slot."
     )]
     Storage(StorageArgs),
+    #[clap(name = "logs", visible_alias = "l2", about = "Get logs by signature or topic.")]
+    Logs(LogsArgs),
+    #[clap(
+        name = "multicall",
+        visible_alias = "mc",
+        about = "Batch read calls through the Multicall3 contract."
+    )]
+    Multicall(MulticallArgs),
     #[clap(
         name = "proof",
         visible_alias = "pr",
@@ -654,6 +742,11 @@ Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline
             value_name = "BLOCK"
         )]
         block: Option<BlockId>,
+        #[clap(
+            long,
+            help = "Verify the account and storage proofs against the block's state root."
+        )]
+        verify: bool,
     },
     #[clap(name = "nonce")]
     #[clap(visible_alias = "n")]
@@ -715,7 +808,7 @@ Tries to decode the calldata using https://sig.eth.samczsun.com unless --offline
     #[clap(
         name = "find-block",
         visible_alias = "f",
-        about = "Get the block number closest to the provided timestamp."
+        about = "Get the latest block at or before the provided timestamp (or, with --after, the earliest block at or after it)."
     )]
     FindBlock(FindBlockArgs),
     #[clap(visible_alias = "com", about = "Generate shell completions script")]
@@ -776,6 +869,17 @@ pub fn parse_block_id(s: &str) -> eyre::Result<BlockId> {
     })
 }
 
+/// Parses a block tag or number into a [`BlockNumber`], for use where a block hash would not make
+/// sense (e.g. the bounds of a log filter range).
+pub fn parse_block_number(s: &str) -> eyre::Result<BlockNumber> {
+    Ok(match s {
+        "earliest" => BlockNumber::Earliest,
+        "latest" => BlockNumber::Latest,
+        "pending" => BlockNumber::Pending,
+        s => BlockNumber::Number(s.parse::<u64>()?.into()),
+    })
+}
+
 pub fn parse_slot(s: &str) -> eyre::Result<H256> {
     Numeric::from_str(s)
         .map_err(|e| eyre::eyre!("Could not parse slot number: {e}"))
@@ -43,6 +43,13 @@ pub struct EthereumOpts {
     #[clap(flatten)]
     #[serde(skip)]
     pub wallet: Wallet,
+
+    #[clap(
+        long,
+        help = "Do not automatically resolve ENS names; treat `to`/`from` values that look like a name as an error instead."
+    )]
+    #[serde(skip)]
+    pub no_ens: bool,
 }
 
 impl EthereumOpts {
@@ -2,6 +2,7 @@ use crate::cmd::forge::{
     bind::BindArgs,
     build::BuildArgs,
     cache::CacheArgs,
+    clean::CleanArgs,
     config, coverage,
     create::CreateArgs,
     debug::DebugArgs,
@@ -16,12 +17,12 @@ use crate::cmd::forge::{
     remappings::RemappingArgs,
     remove::RemoveArgs,
     script::ScriptArgs,
+    selectors::SelectorsArgs,
     snapshot, test, tree, update,
     verify::{VerifyArgs, VerifyCheckArgs},
 };
-use clap::{Parser, Subcommand, ValueHint};
+use clap::{Parser, Subcommand};
 use ethers::solc::{artifacts::output_selection::ContractOutputSelection, EvmVersion};
-use std::path::PathBuf;
 
 use serde::Serialize;
 
@@ -103,15 +104,7 @@ pub enum Subcommands {
     #[clap(visible_alias = "fig", about = "Generate Fig autocompletion spec.")]
     GenerateFigSpec,
     #[clap(visible_alias = "cl", about = "Remove the build artifacts and cache directories.")]
-    Clean {
-        #[clap(
-            help = "The project's root path. Defaults to the current working directory.",
-            long,
-            value_hint = ValueHint::DirPath,
-            value_name = "PATH"
-        )]
-        root: Option<PathBuf>,
-    },
+    Clean(CleanArgs),
 
     #[clap(about = "Manage the Foundry cache.")]
     Cache(CacheArgs),
@@ -140,6 +133,9 @@ pub enum Subcommands {
     )]
     UploadSelectors(UploadSelectorsArgs),
 
+    #[clap(about = "Manage function, event and error selectors.")]
+    Selectors(SelectorsArgs),
+
     #[clap(
         visible_alias = "tr",
         about = "Display a tree visualization of the project's dependency graph."
@@ -32,9 +32,10 @@ The wallet options can either be:
 2. Trezor
 3. Mnemonic (via file path)
 4. Keystore (via file path)
-5. Private Key (cleartext in CLI)
-6. Private Key (interactively via secure prompt)
-7. AWS KMS
+5. Keystore (via account name in `~/.foundry/keystores`, see `cast wallet import`)
+6. Private Key (cleartext in CLI)
+7. Private Key (interactively via secure prompt)
+8. AWS KMS
 "#
 )]
 #[clap(next_help_heading = "Wallet options")]
@@ -101,11 +102,19 @@ pub struct Wallet {
     )]
     pub keystore_path: Option<String>,
 
+    #[clap(
+        long = "account",
+        help_heading = "Wallet options - keystore",
+        help = "Use the keystore in `~/.foundry/keystores/<ACCOUNT_NAME>.json`.",
+        conflicts_with = "keystore_path",
+        value_name = "ACCOUNT_NAME"
+    )]
+    pub keystore_account_name: Option<String>,
+
     #[clap(
         long = "password",
         help_heading = "Wallet options - keystore",
-        help = "The keystore password. Used with --keystore.",
-        requires = "keystore_path",
+        help = "The keystore password. Used with --keystore or --account.",
         value_name = "PASSWORD"
     )]
     pub keystore_password: Option<String>,
@@ -114,8 +123,7 @@ pub struct Wallet {
         env = "ETH_PASSWORD",
         long = "password-file",
         help_heading = "Wallet options - keystore",
-        help = "The keystore password file path. Used with --keystore.",
-        requires = "keystore_path",
+        help = "The keystore password file path. Used with --keystore or --account.",
         value_name = "PASSWORD_FILE"
     )]
     pub keystore_password_file: Option<String>,
@@ -168,13 +176,23 @@ impl Wallet {
     }
 
     pub fn keystore(&self) -> Result<Option<LocalWallet>> {
+        let keystore_path = self.resolved_keystore_path()?;
         self.get_from_keystore(
-            self.keystore_path.as_ref(),
+            keystore_path.as_ref(),
             self.keystore_password.as_ref(),
             self.keystore_password_file.as_ref(),
         )
     }
 
+    /// Returns the keystore path to use, resolving `--account <name>` to
+    /// `~/.foundry/keystores/<name>.json` if `--keystore` wasn't given directly.
+    fn resolved_keystore_path(&self) -> Result<Option<String>> {
+        if self.keystore_path.is_some() {
+            return Ok(self.keystore_path.clone())
+        }
+        self.keystore_account_name.as_deref().map(account_keystore_path).transpose()
+    }
+
     pub fn mnemonic(&self) -> Result<Option<LocalWallet>> {
         Ok(if let Some(ref mnemonic) = self.mnemonic {
             Some(self.get_from_mnemonic(
@@ -386,6 +404,14 @@ pub struct KeystoreFile {
     pub address: Address,
 }
 
+/// Resolves the path to the keystore file for the account with the given name, i.e.
+/// `~/.foundry/keystores/<name>.json`, as created by `cast wallet import`.
+pub(crate) fn account_keystore_path(name: &str) -> Result<String> {
+    let path = foundry_config::Config::foundry_keystore_file(name)
+        .ok_or_else(|| eyre!("Could not find the global foundry keystores directory"))?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,6 +440,7 @@ mod tests {
             interactive: false,
             private_key: Some("123".to_string()),
             keystore_path: None,
+            keystore_account_name: None,
             keystore_password: None,
             keystore_password_file: None,
             mnemonic: None,
@@ -86,8 +86,9 @@ The wallet options can either be:
 2. Trezor
 3. Mnemonics (via file path)
 4. Keystores (via file path)
-5. Private Keys (cleartext in CLI)
-6. Private Keys (interactively via secure prompt)
+5. Keystores (via account names in `~/.foundry/keystores`, see `cast wallet import`)
+6. Private Keys (cleartext in CLI)
+7. Private Keys (interactively via secure prompt)
 "#
 )]
 pub struct MultiWallet {
@@ -172,11 +173,20 @@ pub struct MultiWallet {
     )]
     pub keystore_paths: Option<Vec<String>>,
 
+    #[clap(
+        long = "accounts",
+        help_heading = "Wallet options - keystore",
+        help = "Use the keystores in `~/.foundry/keystores/<ACCOUNT_NAME>.json`.",
+        conflicts_with = "keystore_paths",
+        value_name = "ACCOUNT_NAMES",
+        action = ArgAction::Append,
+    )]
+    pub keystore_account_names: Option<Vec<String>>,
+
     #[clap(
         long = "password",
         help_heading = "Wallet options - keystore",
-        help = "The keystore password. Used with --keystore.",
-        requires = "keystore_paths",
+        help = "The keystore password. Used with --keystore or --accounts.",
         value_name = "PASSWORDS",
         action = ArgAction::Append,
     )]
@@ -186,8 +196,7 @@ pub struct MultiWallet {
         env = "ETH_PASSWORD",
         long = "password-file",
         help_heading = "Wallet options - keystore",
-        help = "The keystore password file path. Used with --keystore.",
-        requires = "keystore_paths",
+        help = "The keystore password file path. Used with --keystore or --accounts.",
         value_name = "PASSWORD_FILE"
     )]
     pub keystore_password_file: Option<Vec<String>>,
@@ -314,11 +323,11 @@ impl MultiWallet {
         Ok(None)
     }
 
-    /// Returns all wallets read from the provided keystores arguments
+    /// Returns all wallets read from the provided keystores or accounts arguments
     ///
-    /// Returns `Ok(None)` if no keystore provided.
+    /// Returns `Ok(None)` if neither was provided.
     pub fn keystores(&self) -> Result<Option<Vec<LocalWallet>>> {
-        if let Some(keystore_paths) = &self.keystore_paths {
+        if let Some(keystore_paths) = self.resolved_keystore_paths()? {
             let mut wallets = Vec::with_capacity(keystore_paths.len());
 
             let mut passwords_iter =
@@ -327,7 +336,7 @@ impl MultiWallet {
             let mut password_files_iter =
                 self.keystore_password_file.clone().unwrap_or_default().into_iter();
 
-            for path in keystore_paths {
+            for path in &keystore_paths {
                 wallets.push(self.get_from_keystore(Some(path), passwords_iter.next().as_ref(), password_files_iter.next().as_ref())?.wrap_err("Keystore paths do not have the same length as provided passwords or password files.")?);
             }
             return Ok(Some(wallets))
@@ -335,6 +344,23 @@ impl MultiWallet {
         Ok(None)
     }
 
+    /// Resolves `--keystore`/`--accounts` into a single list of keystore file paths, translating
+    /// each `--accounts` entry into its `~/.foundry/keystores/<name>.json` path.
+    fn resolved_keystore_paths(&self) -> Result<Option<Vec<String>>> {
+        if let Some(keystore_paths) = &self.keystore_paths {
+            return Ok(Some(keystore_paths.clone()))
+        }
+        if let Some(account_names) = &self.keystore_account_names {
+            return Ok(Some(
+                account_names
+                    .iter()
+                    .map(|name| super::account_keystore_path(name))
+                    .collect::<Result<Vec<_>>>()?,
+            ))
+        }
+        Ok(None)
+    }
+
     pub fn mnemonics(&self) -> Result<Option<Vec<LocalWallet>>> {
         if let Some(ref mnemonics) = self.mnemonics {
             let mut wallets = vec![];
@@ -13,7 +13,7 @@ use foundry_cli::opts::forge::Opts;
 use foundry_cli_test_utils::{
     ethers_solc::PathStyle,
     forgetest, forgetest_init,
-    util::{pretty_err, read_string, OutputExt, TestCommand, TestProject},
+    util::{pretty_err, read_string, setup_forge, OutputExt, TestCommand, TestProject},
 };
 use foundry_config::{parse_with_profile, BasicConfig, Chain, Config, SolidityErrorCode};
 use semver::Version;
@@ -394,6 +394,35 @@ forgetest_init!(can_emit_extra_output, |prj: TestProject, mut cmd: TestCommand|
     let _artifact: Metadata = ethers::solc::utils::read_json_file(metadata_path).unwrap();
 });
 
+// checks that `--hardhat-artifacts` writes a Hardhat-shaped artifact alongside the native one,
+// guarding the known-good shape Hardhat tooling expects (top-level abi/bytecode/deployedBytecode)
+forgetest_init!(can_emit_hardhat_artifacts, |prj: TestProject, mut cmd: TestCommand| {
+    cmd.args(["build", "--hardhat-artifacts"]);
+    cmd.assert_non_empty_stdout();
+
+    // the native artifact must still be there, untouched, so `forge test` keeps working
+    let native_path = prj.paths().artifacts.join(TEMPLATE_CONTRACT_ARTIFACT_JSON);
+    assert!(native_path.exists());
+
+    let hh_path = prj.paths().artifacts.join("hardhat").join(TEMPLATE_CONTRACT_ARTIFACT_JSON);
+    let hh_artifact: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(hh_path).unwrap()).unwrap();
+    assert_eq!(hh_artifact["contractName"], "Counter");
+    assert_eq!(hh_artifact["sourceName"], "Counter.sol");
+    assert!(hh_artifact["abi"].is_array());
+    assert!(hh_artifact["bytecode"].as_str().unwrap().starts_with("0x"));
+    assert!(hh_artifact["deployedBytecode"].as_str().unwrap().starts_with("0x"));
+    assert!(hh_artifact["linkReferences"].is_object());
+    assert!(hh_artifact["deployedLinkReferences"].is_object());
+
+    let dbg_path = prj.paths().artifacts.join("hardhat").join("Counter.sol/Counter.dbg.json");
+    let dbg: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dbg_path).unwrap()).unwrap();
+    let build_info_rel = dbg["buildInfo"].as_str().unwrap().to_string();
+    let build_info_path = prj.paths().artifacts.join("hardhat/Counter.sol").join(build_info_rel);
+    assert!(build_info_path.exists());
+});
+
 // checks that extra output works
 forgetest_init!(can_emit_multiple_extra_output, |prj: TestProject, mut cmd: TestCommand| {
     cmd.args(["build", "--extra-output", "metadata", "ir-optimized", "--extra-output", "ir"]);
@@ -430,6 +459,35 @@ forgetest_init!(can_emit_multiple_extra_output, |prj: TestProject, mut cmd: Test
     std::fs::read_to_string(sourcemap).unwrap();
 });
 
+// checks that `forge coverage` actually produces coverage data for the project's sources,
+// rather than a silent 0/0 summary; `CoverageArgs::build` force-adds the AST output selection to
+// guard against a project's own settings dropping it, and this is what exercises that path
+forgetest_init!(can_generate_coverage, |prj: TestProject, mut cmd: TestCommand| {
+    cmd.arg("coverage");
+    let stdout = cmd.stdout_lossy();
+    assert!(stdout.contains("Counter.sol"), "coverage summary is missing source data: {stdout}");
+});
+
+// checks that `forge build --timings` prints a compile-time breakdown, both as a human-readable
+// report and, with `--json`, as a machine-readable one
+forgetest_init!(can_print_build_timings, |prj: TestProject, mut cmd: TestCommand| {
+    cmd.args(["build", "--timings"]);
+    let stdout = cmd.stdout_lossy();
+    assert!(stdout.contains("Compiled in"), "missing timings breakdown: {stdout}");
+    assert!(stdout.contains("Slowest source files"), "missing slowest files section: {stdout}");
+
+    cmd.forge_fuse().args(["build", "--timings", "--json", "--force"]).root_arg();
+    let stdout = cmd.stdout_lossy();
+    // the JSON report follows the regular compiler output
+    let report = stdout.split("Compiler run successful").nth(1).unwrap().trim();
+    let json: serde_json::Value = serde_json::from_str(report).unwrap_or_else(|err| {
+        panic!("`--timings --json` did not print valid JSON: {err}\n{stdout}")
+    });
+    assert!(json.get("totalDurationMs").is_some());
+    assert!(json.get("versions").is_some());
+    assert!(json.get("slowestFiles").is_some());
+});
+
 forgetest!(can_print_warnings, |prj: TestProject, mut cmd: TestCommand| {
     prj.inner()
         .add_source(
@@ -527,6 +585,144 @@ Compiler run successful
     ));
 });
 
+// tests that `forge tree <contract>` roots the tree at that file instead of the whole project
+forgetest!(can_print_rooted_tree, |prj: TestProject, mut cmd: TestCommand| {
+    prj.inner()
+        .add_source(
+            "Dep",
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+contract Dep {}
+   "#,
+        )
+        .unwrap();
+
+    prj.inner()
+        .add_source(
+            "Root",
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import {Dep} from "src/Dep.sol";
+contract Root is Dep {}
+   "#,
+        )
+        .unwrap();
+
+    cmd.args(["tree", "Root"]);
+    let out = cmd.stdout_lossy();
+    assert!(out.contains("Root.sol"));
+    assert!(out.contains("Dep.sol"));
+});
+
+// tests that `forge flatten` dedupes diamond imports and still produces something that compiles
+forgetest!(can_flatten_diamond_imports, |prj: TestProject, mut cmd: TestCommand| {
+    prj.inner()
+        .add_source(
+            "D",
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+contract D {
+    function d() external pure returns (uint256) {
+        return 1;
+    }
+}
+   "#,
+        )
+        .unwrap();
+
+    prj.inner()
+        .add_source(
+            "B",
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import {D} from "src/D.sol";
+contract B is D {}
+   "#,
+        )
+        .unwrap();
+
+    prj.inner()
+        .add_source(
+            "C",
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import {D} from "src/D.sol";
+contract C is D {}
+   "#,
+        )
+        .unwrap();
+
+    let a_path = prj
+        .inner()
+        .add_source(
+            "A",
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import {B} from "src/B.sol";
+import {C} from "src/C.sol";
+contract A is B, C {}
+   "#,
+        )
+        .unwrap();
+
+    cmd.arg("flatten").arg(a_path);
+
+    let out = cmd.stdout_lossy();
+    assert_eq!(out.matches("contract D").count(), 1);
+    assert_eq!(out.matches("SPDX-License-Identifier").count(), 1);
+});
+
+// checks that two projects at different absolute paths produce byte-for-byte identical bytecode
+// when the metadata hash is disabled, since the metadata hash would otherwise embed the
+// (machine-dependent) absolute source paths
+forgetest!(
+    can_disable_bytecode_hash_for_deterministic_builds,
+    |prj: TestProject, mut cmd: TestCommand| {
+        let src = r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 0.8.10;
+contract Foo {
+    function run() external pure returns (uint256) {
+        return 1;
+    }
+}
+    "#;
+
+        let config = Config { bytecode_hash: BytecodeHash::None, ..Default::default() };
+
+        prj.write_config(config.clone());
+        prj.inner().add_source("Foo", src).unwrap();
+        cmd.arg("build");
+        cmd.assert_non_empty_stdout();
+        let artifact: ConfigurableContractArtifact =
+            ethers::solc::utils::read_json_file(prj.paths().artifacts.join("Foo.sol/Foo.json"))
+                .unwrap();
+        let bytecode = artifact.bytecode.unwrap().object.as_bytes().unwrap().clone();
+
+        // a second, independent project at a different absolute path with the exact same source
+        let (prj2, mut cmd2) = setup_forge(
+            "can_disable_bytecode_hash_for_deterministic_builds2",
+            PathStyle::Dapptools,
+        );
+        prj2.write_config(config);
+        prj2.inner().add_source("Foo", src).unwrap();
+        cmd2.arg("build");
+        cmd2.assert_non_empty_stdout();
+        let artifact2: ConfigurableContractArtifact =
+            ethers::solc::utils::read_json_file(prj2.paths().artifacts.join("Foo.sol/Foo.json"))
+                .unwrap();
+        let bytecode2 = artifact2.bytecode.unwrap().object.as_bytes().unwrap().clone();
+
+        assert_eq!(bytecode, bytecode2);
+    }
+);
+
 // tests that the `inspect` command works correctly
 forgetest!(can_execute_inspect_command, |prj: TestProject, mut cmd: TestCommand| {
     // explicitly set to include the ipfs bytecode hash
@@ -569,6 +765,37 @@ contract Foo {
     check_output(cmd.stdout_lossy());
 });
 
+// tests that `inspect` errors with the candidate paths when a bare contract name is ambiguous
+forgetest!(can_detect_ambiguous_inspect_contract, |prj: TestProject, mut cmd: TestCommand| {
+    prj.inner()
+        .add_source(
+            "Dup1.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 0.8.10;
+contract Dup {}
+   "#,
+        )
+        .unwrap();
+    prj.inner()
+        .add_source(
+            "Dup2.sol",
+            r#"
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity 0.8.10;
+contract Dup {}
+   "#,
+        )
+        .unwrap();
+
+    cmd.args(["inspect", "Dup", "abi"]);
+    cmd.assert_err();
+    let stderr = cmd.stderr_lossy();
+    assert!(stderr.contains("more than one file"), "{stderr}");
+    assert!(stderr.contains("Dup1.sol"), "{stderr}");
+    assert!(stderr.contains("Dup2.sol"), "{stderr}");
+});
+
 // test that `forge snapshot` commands work
 forgetest!(
     #[serial_test::serial]
@@ -683,6 +910,39 @@ contract A {
     assert!(!out.trim().contains("Compiler run successful (with warnings)"));
 });
 
+// test that `ignore_warnings_from` exempts warnings coming from the given path prefix from
+// `deny_warnings`, while warnings elsewhere still fail the build
+forgetest!(can_ignore_warnings_from_path, |prj: TestProject, mut cmd: TestCommand| {
+    prj.inner()
+        .add_source(
+            "vendor/Vendored",
+            r#"
+pragma solidity 0.8.10;
+contract Vendored {
+    function testExample() public {}
+}
+   "#,
+        )
+        .unwrap();
+
+    let config = Config {
+        ignored_error_codes: vec![],
+        deny_warnings: true,
+        ignore_warnings_from: vec!["src/vendor".to_string()],
+        ..Default::default()
+    };
+    prj.write_config(config);
+
+    cmd.args(["build", "--force"]);
+    let out = cmd.stdout();
+    assert!(out.trim().contains("Compiler run successful"));
+
+    // without the exemption the same warning fails the build
+    let config = Config { ignored_error_codes: vec![], deny_warnings: true, ..Default::default() };
+    prj.write_config(config);
+    cmd.assert_err();
+});
+
 // test against a local checkout, useful to debug with local ethers-rs patch
 forgetest!(
     #[ignore]
@@ -1471,6 +1731,16 @@ forgetest_init!(can_bind, |_prj: TestProject, mut cmd: TestCommand| {
     cmd.assert_non_empty_stdout();
 });
 
+forgetest_init!(can_check_bind, |_prj: TestProject, mut cmd: TestCommand| {
+    // bindings don't exist yet, so --check must fail rather than silently generate them
+    cmd.args(["bind", "--check"]);
+    cmd.assert_err();
+
+    cmd.forge_fuse().arg("bind").assert_non_empty_stdout();
+
+    cmd.forge_fuse().args(["bind", "--check"]).assert_non_empty_stdout();
+});
+
 // checks missing dependencies are auto installed
 forgetest_init!(can_install_missing_deps_test, |prj: TestProject, mut cmd: TestCommand| {
     // wipe forge-std
@@ -1532,6 +1802,25 @@ forgetest_init!(can_build_sizes_repeatedly, |_prj: TestProject, mut cmd: TestCom
     assert!(unchanged.contains(table), "{}", table);
 });
 
+// checks that build --sizes --json emits the size report as structured json
+forgetest_init!(can_build_sizes_json, |_prj: TestProject, mut cmd: TestCommand| {
+    cmd.args(["build", "--sizes", "--json"]);
+    let out = cmd.stdout();
+    let json = out.split("Compiler run successful").nth(1).unwrap().trim();
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    let contract = &value[TEMPLATE_CONTRACT];
+    assert!(contract["size"].is_u64());
+    assert!(contract["init_code_size"].is_u64());
+    assert!(contract["is_dev_contract"].is_boolean());
+});
+
+// checks that build --sizes --init-code-size adds an extra column to the table
+forgetest_init!(can_build_sizes_with_init_code_size, |_prj: TestProject, mut cmd: TestCommand| {
+    cmd.args(["build", "--sizes", "--init-code-size"]);
+    let out = cmd.stdout();
+    assert!(out.contains("Init Code Size"));
+});
+
 // checks that build --names includes all contracts even if unchanged
 forgetest_init!(can_build_names_repeatedly, |_prj: TestProject, mut cmd: TestCommand| {
     cmd.args(["build", "--names"]);
@@ -40,6 +40,8 @@ forgetest!(can_extract_config_values, |prj: TestProject, mut cmd: TestCommand| {
         auto_detect_solc: false,
         auto_detect_remappings: true,
         offline: true,
+        hardhat_artifacts: false,
+        timings: false,
         optimizer: false,
         optimizer_runs: 1000,
         optimizer_details: Some(OptimizerDetails {
@@ -47,6 +49,7 @@ forgetest!(can_extract_config_values, |prj: TestProject, mut cmd: TestCommand| {
             yul_details: Some(YulDetails { stack_allocation: Some(true), ..Default::default() }),
             ..Default::default()
         }),
+        compilation_restrictions: vec![],
         model_checker: None,
         extra_output: Default::default(),
         extra_output_files: Default::default(),
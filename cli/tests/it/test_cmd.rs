@@ -34,6 +34,22 @@ forgetest!(can_set_filter_values, |prj: TestProject, mut cmd: TestCommand| {
     assert_eq!(config.path_pattern_inverse, None);
 });
 
+// checks that a full build + test cycle succeeds with `--offline` set and no network reachable,
+// i.e. relying solely on the solc version already installed for the default project template
+forgetest_init!(can_build_and_test_offline, |prj: TestProject, mut cmd: TestCommand| {
+    // point rpc/etherscan env vars at a non-routable address (RFC 5737 TEST-NET-1): if offline
+    // mode isn't actually honored end-to-end, a stray request would fail loudly instead of the
+    // test silently passing because it happened to have real network access
+    cmd.set_env("ETH_RPC_URL", "http://192.0.2.1:1");
+    cmd.set_env("ETHERSCAN_API_KEY", "");
+
+    cmd.args(["build", "--offline"]);
+    cmd.assert_success();
+
+    cmd.forge_fuse().args(["test", "--offline"]);
+    cmd.assert_success();
+});
+
 // tests that warning is displayed when there are no tests in project
 forgetest!(warn_no_tests, |prj: TestProject, mut cmd: TestCommand| {
     prj.inner()
@@ -56,46 +56,153 @@ pub fn parse_tokens<'a, I: IntoIterator<Item = (&'a ParamType, &'a str)>>(
 ) -> Result<Vec<Token>> {
     let mut tokens = Vec::new();
 
-    for (param, value) in params.into_iter() {
-        let mut token = if lenient {
-            LenientTokenizer::tokenize(param, value)
-        } else {
-            StrictTokenizer::tokenize(param, value)
-        };
-        if token.is_err() && value.starts_with("0x") {
-            match param {
-                ParamType::FixedBytes(32) => {
-                    if value.len() < 66 {
-                        let padded_value = [value, &"0".repeat(66 - value.len())].concat();
-                        token = if lenient {
-                            LenientTokenizer::tokenize(param, &padded_value)
-                        } else {
-                            StrictTokenizer::tokenize(param, &padded_value)
-                        };
-                    }
+    for (idx, (param, value)) in params.into_iter().enumerate() {
+        let token = parse_token(param, value, lenient).wrap_err_with(|| {
+            format!("Failed to parse argument {idx} (`{value}`), expected value of type: {param}")
+        })?;
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Parses a single string input as a Token against the expected ParamType.
+///
+/// Falls back to [`parse_tuple_literal`] for tuples (and arrays of tuples), since ethers'
+/// tokenizer doesn't understand tuple literals like `(0xabc,1)`.
+fn parse_token(param: &ParamType, value: &str, lenient: bool) -> Result<Token> {
+    if contains_tuple(param) {
+        return parse_tuple_literal(param, value.trim())
+    }
+
+    let mut token = if lenient {
+        LenientTokenizer::tokenize(param, value)
+    } else {
+        StrictTokenizer::tokenize(param, value)
+    };
+    if token.is_err() && value.starts_with("0x") {
+        match param {
+            ParamType::FixedBytes(32) => {
+                if value.len() < 66 {
+                    let padded_value = [value, &"0".repeat(66 - value.len())].concat();
+                    token = if lenient {
+                        LenientTokenizer::tokenize(param, &padded_value)
+                    } else {
+                        StrictTokenizer::tokenize(param, &padded_value)
+                    };
                 }
-                ParamType::Uint(_) => {
-                    // try again if value is hex
-                    if let Ok(value) = U256::from_str(value).map(|v| v.to_string()) {
-                        token = if lenient {
-                            LenientTokenizer::tokenize(param, &value)
-                        } else {
-                            StrictTokenizer::tokenize(param, &value)
-                        };
-                    }
+            }
+            ParamType::Uint(_) => {
+                // try again if value is hex
+                if let Ok(value) = U256::from_str(value).map(|v| v.to_string()) {
+                    token = if lenient {
+                        LenientTokenizer::tokenize(param, &value)
+                    } else {
+                        StrictTokenizer::tokenize(param, &value)
+                    };
                 }
-                // TODO: Not sure what to do here. Put the no effect in for now, but that is not
-                // ideal. We could attempt massage for every value type?
-                _ => {}
             }
+            // TODO: Not sure what to do here. Put the no effect in for now, but that is not
+            // ideal. We could attempt massage for every value type?
+            _ => {}
         }
+    }
 
-        let token = token.map(sanitize_token).wrap_err_with(|| {
-            format!("Failed to parse `{value}`, expected value of type: {param}")
-        })?;
-        tokens.push(token);
+    Ok(sanitize_token(token?))
+}
+
+/// Whether `param` is a tuple, or an array (fixed or dynamic) of a type that contains a tuple.
+fn contains_tuple(param: &ParamType) -> bool {
+    match param {
+        ParamType::Tuple(_) => true,
+        ParamType::Array(inner) | ParamType::FixedArray(inner, _) => contains_tuple(inner),
+        _ => false,
     }
-    Ok(tokens)
+}
+
+/// Parses a tuple literal like `(0xabc,1)`, or an array of such literals like `[(0xabc,1),(0xdef,2)]`,
+/// against a `ParamType` that contains a `Tuple` (possibly nested inside `Array`/`FixedArray`).
+///
+/// Splits the literal by hand, only at the commas and brackets that sit at the outermost nesting
+/// level, then recurses into each field/element with its own `ParamType`.
+fn parse_tuple_literal(param: &ParamType, value: &str) -> Result<Token> {
+    match param {
+        ParamType::Tuple(types) => {
+            let inner = value
+                .strip_prefix('(')
+                .and_then(|v| v.strip_suffix(')'))
+                .ok_or_else(|| eyre::eyre!("expected a tuple literal like `(..)`"))?;
+            let fields = split_top_level(inner)?;
+            if fields.len() != types.len() {
+                eyre::bail!(
+                    "tuple has {} field(s) but {} value(s) were given",
+                    types.len(),
+                    fields.len()
+                )
+            }
+            let tokens = types
+                .iter()
+                .zip(fields)
+                .map(|(ty, field)| parse_token(ty, field.trim(), true))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Token::Tuple(tokens))
+        }
+        ParamType::Array(inner) => {
+            let elements = parse_array_literal(inner, value)?;
+            Ok(Token::Array(elements))
+        }
+        ParamType::FixedArray(inner, size) => {
+            let elements = parse_array_literal(inner, value)?;
+            if elements.len() != *size {
+                eyre::bail!(
+                    "expected a fixed-size array of {size} element(s), got {}",
+                    elements.len()
+                )
+            }
+            Ok(Token::FixedArray(elements))
+        }
+        _ => unreachable!("contains_tuple guarantees this is a Tuple, Array, or FixedArray"),
+    }
+}
+
+fn parse_array_literal(inner: &ParamType, value: &str) -> Result<Vec<Token>> {
+    let elements = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .ok_or_else(|| eyre::eyre!("expected an array literal like `[..]`"))?;
+    split_top_level(elements)?
+        .into_iter()
+        .map(|part| parse_token(inner, part.trim(), true))
+        .collect()
+}
+
+/// Splits a literal at the commas that are not nested inside a `(..)` or `[..]`.
+fn split_top_level(s: &str) -> Result<Vec<&str>> {
+    if s.trim().is_empty() {
+        return Ok(Vec::new())
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            eyre::bail!("unbalanced brackets in `{s}`")
+        }
+    }
+    if depth != 0 {
+        eyre::bail!("unbalanced brackets in `{s}`")
+    }
+    parts.push(&s[start..]);
+    Ok(parts)
 }
 
 /// Cleans up potential shortcomings of the ethabi Tokenizer.
@@ -429,4 +536,101 @@ mod tests {
             eip1191.to_string()
         );
     }
+
+    /// Round-trips a function signature/args pair through `encode_args` and `abi_decode(input:
+    /// true)`, checking that the decoded tokens match what was encoded.
+    fn roundtrip(sig: &str, args: &[&str]) {
+        let func = get_func(sig).unwrap();
+        let encoded = encode_args(&func, args).unwrap();
+
+        let mut calldata = func.short_signature().to_vec();
+        calldata.extend(encoded);
+        let calldata = format!("0x{}", hex::encode(calldata));
+
+        let decoded = abi_decode(sig, &calldata, true).unwrap();
+        assert_eq!(decoded, func.decode_input(&hex::decode(&calldata[10..]).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn can_parse_tuple_literal() {
+        let param = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+        let addr = "0x0000000000000000000000000000000000000001";
+        let tokens =
+            parse_tokens(std::iter::once((&param, &*format!("({addr},1)"))), true).unwrap();
+        assert_eq!(
+            tokens,
+            vec![Token::Tuple(vec![
+                Token::Address(Address::from_str(addr).unwrap()),
+                Token::Uint(1u64.into())
+            ])]
+        );
+    }
+
+    #[test]
+    fn can_parse_array_of_tuples() {
+        let param = ParamType::Array(Box::new(ParamType::Tuple(vec![
+            ParamType::Address,
+            ParamType::Uint(256),
+        ])));
+        let addr1 = "0x0000000000000000000000000000000000000001";
+        let addr2 = "0x0000000000000000000000000000000000000002";
+        let tokens =
+            parse_tokens(std::iter::once((&param, &*format!("[({addr1},1),({addr2},2)]"))), true)
+                .unwrap();
+        assert_eq!(
+            tokens,
+            vec![Token::Array(vec![
+                Token::Tuple(vec![
+                    Token::Address(Address::from_str(addr1).unwrap()),
+                    Token::Uint(1u64.into())
+                ]),
+                Token::Tuple(vec![
+                    Token::Address(Address::from_str(addr2).unwrap()),
+                    Token::Uint(2u64.into())
+                ]),
+            ])]
+        );
+    }
+
+    #[test]
+    fn can_parse_nested_tuple() {
+        let inner = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+        let param = ParamType::Tuple(vec![inner, ParamType::Bool]);
+        let addr = "0x0000000000000000000000000000000000000001";
+        let tokens =
+            parse_tokens(std::iter::once((&param, &*format!("(({addr},1),true)"))), true).unwrap();
+        assert_eq!(
+            tokens,
+            vec![Token::Tuple(vec![
+                Token::Tuple(vec![
+                    Token::Address(Address::from_str(addr).unwrap()),
+                    Token::Uint(1u64.into())
+                ]),
+                Token::Bool(true)
+            ])]
+        );
+    }
+
+    #[test]
+    fn tuple_literal_error_points_at_argument_index() {
+        let param = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+        let err = parse_tokens(std::iter::once((&param, "(0xnotanaddress,1)")), true).unwrap_err();
+        assert!(err.to_string().contains("argument 0"), "{err}");
+    }
+
+    #[test]
+    fn can_round_trip_nested_tuples_and_arrays() {
+        roundtrip(
+            "f((address,uint256)[],bytes32)",
+            &[
+                "[(0x0000000000000000000000000000000000000001,1),(0x0000000000000000000000000000000000000002,2)]",
+                "0x0000000000000000000000000000000000000000000000000000000000000001",
+            ],
+        );
+        roundtrip(
+            "f(((bool,uint8),address)[2])",
+            &["[((true,1),0x0000000000000000000000000000000000000001),((false,2),0x0000000000000000000000000000000000000002)]"],
+        );
+        roundtrip("f(uint256,string)", &["1", "hello world"]);
+    }
 }
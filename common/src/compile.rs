@@ -1,5 +1,8 @@
 //! Support for compiling [ethers::solc::Project]
-use crate::{term, TestFunctionExt};
+use crate::{
+    errors::{ExitCode, ExitCodeError},
+    term, TestFunctionExt,
+};
 use comfy_table::{presets::ASCII_MARKDOWN, *};
 use ethers_etherscan::contract::Metadata;
 use ethers_solc::{
@@ -10,13 +13,17 @@ use ethers_solc::{
     Solc, SolcConfig,
 };
 use eyre::Result;
+use serde::Serialize;
 use std::{
     collections::BTreeMap,
     convert::Infallible,
     fmt::Display,
+    fs,
+    hash::{Hash, Hasher},
     path::{Path, PathBuf},
     result,
     str::FromStr,
+    time::Duration,
 };
 
 /// Helper type to configure how to compile a project
@@ -31,6 +38,16 @@ pub struct ProjectCompiler {
     print_sizes: bool,
     /// files to exclude
     filters: Vec<SkipBuildFilter>,
+    /// paths whose warnings are exempt from `deny_warnings`
+    ignore_warnings_from: Vec<String>,
+    /// whether to print the size report as JSON instead of a table
+    print_sizes_json: bool,
+    /// whether to include the init code size as an extra column in the size report
+    print_init_code_size: bool,
+    /// whether to additionally write artifacts in Hardhat's format alongside the native ones
+    hardhat: bool,
+    /// whether to print a compile-time breakdown after a successful build
+    timings: bool,
 }
 
 impl ProjectCompiler {
@@ -45,7 +62,51 @@ impl ProjectCompiler {
         print_sizes: bool,
         filters: Vec<SkipBuildFilter>,
     ) -> Self {
-        Self { print_names, print_sizes, filters }
+        Self {
+            print_names,
+            print_sizes,
+            filters,
+            ignore_warnings_from: Vec::new(),
+            print_sizes_json: false,
+            print_init_code_size: false,
+            hardhat: false,
+            timings: false,
+        }
+    }
+
+    /// Exempts warnings originating from files under any of the given paths from `deny_warnings`,
+    /// e.g. so vendored code under `lib/` doesn't block a build that denies warnings everywhere
+    /// else.
+    pub fn ignore_warnings_from(mut self, paths: Vec<String>) -> Self {
+        self.ignore_warnings_from = paths;
+        self
+    }
+
+    /// Prints the size report (if enabled via `print_sizes`) as JSON instead of a table, e.g. so
+    /// contract sizes can be tracked over time.
+    pub fn print_sizes_json(mut self, json: bool) -> Self {
+        self.print_sizes_json = json;
+        self
+    }
+
+    /// Includes the init code size as an extra column in the size report.
+    pub fn print_init_code_size(mut self, print_init_code_size: bool) -> Self {
+        self.print_init_code_size = print_init_code_size;
+        self
+    }
+
+    /// Additionally writes artifacts in the shape Hardhat expects, alongside the native ones, so
+    /// tooling that only understands Hardhat's artifact format keeps working.
+    pub fn hardhat(mut self, hardhat: bool) -> Self {
+        self.hardhat = hardhat;
+        self
+    }
+
+    /// Prints a compile-time breakdown (per-solc-version file counts/durations, plus the slowest
+    /// source files) after a successful build.
+    pub fn timings(mut self, timings: bool) -> Self {
+        self.timings = timings;
+        self
     }
 
     /// Compiles the project with [`Project::compile()`]
@@ -95,32 +156,93 @@ impl ProjectCompiler {
             std::process::exit(0);
         }
 
+        // print the resolved compiler before kicking off compilation, so it's clear up front
+        // which solc binary/version is going to be used, especially when it was pinned via
+        // `solc`/`--use` or resolved from a local path
+        if let Ok(version) = project.solc.version() {
+            println!("Using solc {version}");
+        }
+
         let now = std::time::Instant::now();
         tracing::trace!("start compiling project");
 
-        let output = term::with_spinner_reporter(|| f(project))?;
+        let (output, timings) = if self.timings {
+            let (output, timings) = term::with_timings_reporter(|| f(project));
+            (output?, timings)
+        } else {
+            (term::with_spinner_reporter(|| f(project))?, Vec::new())
+        };
 
         let elapsed = now.elapsed();
         tracing::trace!(?elapsed, "finished compiling");
 
         if output.has_compiler_errors() {
-            tracing::warn!("compiled with errors");
-            eyre::bail!(output.to_string())
+            // `deny_warnings` makes solc treat warnings as errors, which loses the distinction
+            // between the two; re-derive it here so a build that's only failing because of a
+            // denied warning can be told apart from one with a genuine compile error, and so
+            // `ignore_warnings_from` can still exempt warnings that solc already escalated.
+            let diagnostics = &output.output().errors;
+            if diagnostics.iter().any(|diag| diag.severity.is_error()) {
+                tracing::warn!("compiled with errors");
+                return Err(ExitCodeError::new(ExitCode::CompileError, eyre::eyre!(output.to_string())))
+            }
+
+            let denied: Vec<_> = diagnostics
+                .iter()
+                .filter(|diag| diag.severity.is_warning())
+                .filter(|diag| {
+                    !diag.source_location.as_ref().map_or(false, |loc| {
+                        self.ignore_warnings_from
+                            .iter()
+                            .any(|ignored| Path::new(&loc.file).starts_with(ignored))
+                    })
+                })
+                .collect();
+
+            if !denied.is_empty() {
+                tracing::warn!("compiled with denied warnings");
+                return Err(ExitCodeError::new(
+                    ExitCode::CompileError,
+                    eyre::eyre!(
+                        "Compiler run failed because warnings were denied:\n\n{}",
+                        denied
+                            .iter()
+                            .map(|diag| diag
+                                .formatted_message
+                                .clone()
+                                .unwrap_or_else(|| diag.message.clone()))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    ),
+                ))
+            }
+
+            println!("{output}");
+            self.handle_output(project, &output)?;
         } else if output.is_unchanged() {
             println!("No files changed, compilation skipped");
-            self.handle_output(&output);
+            self.handle_output(project, &output)?;
         } else {
             // print the compiler output / warnings
             println!("{output}");
 
-            self.handle_output(&output);
+            self.handle_output(project, &output)?;
+        }
+
+        if self.timings {
+            self.print_timings(&timings, elapsed)?;
         }
 
         Ok(output)
     }
 
-    /// If configured, this will print sizes or names
-    fn handle_output(&self, output: &ProjectCompileOutput) {
+    /// If configured, this will print sizes or names, and/or write out Hardhat-shaped artifacts
+    fn handle_output(&self, project: &Project, output: &ProjectCompileOutput) -> Result<()> {
+        // standalone Yul sources aren't part of `ProjectCompileOutput` at all, since `solc`'s
+        // regular Solidity pipeline doesn't understand them; compile and write their artifacts
+        // out-of-band so `vm.getCode`/`vm.getDeployedCode` and `--sizes` still see them
+        let yul_artifacts = crate::yul::compile_yul_sources(project)?;
+
         // print any sizes or names
         if self.print_names {
             let mut artifacts: BTreeMap<_, Vec<_>> = BTreeMap::new();
@@ -142,10 +264,14 @@ impl ProjectCompiler {
             if self.print_names {
                 println!();
             }
-            let mut size_report = SizeReport { contracts: BTreeMap::new() };
+            let mut size_report = SizeReport {
+                contracts: BTreeMap::new(),
+                print_init_code_size: self.print_init_code_size,
+            };
             let artifacts: BTreeMap<_, _> = output.artifacts().collect();
             for (name, artifact) in artifacts {
                 let size = deployed_contract_size(artifact).unwrap_or_default();
+                let init_code_size = contract_size(artifact).unwrap_or_default();
 
                 let dev_functions = artifact
                     .abi
@@ -158,17 +284,194 @@ impl ProjectCompiler {
                     });
 
                 let is_dev_contract = dev_functions.count() > 0;
-                size_report.contracts.insert(name, ContractInfo { size, is_dev_contract });
+                size_report
+                    .contracts
+                    .insert(name, ContractInfo { size, init_code_size, is_dev_contract });
             }
 
-            println!("{size_report}");
+            for artifact in &yul_artifacts {
+                size_report.contracts.insert(
+                    artifact.name.clone(),
+                    ContractInfo {
+                        size: artifact.deployed_bytecode.len(),
+                        init_code_size: artifact.bytecode.len(),
+                        is_dev_contract: false,
+                    },
+                );
+            }
+
+            if self.print_sizes_json {
+                println!("{}", serde_json::to_string_pretty(&size_report.contracts)?);
+            } else {
+                println!("{size_report}");
+            }
 
             // exit with error if any contract exceeds the size limit, excluding test contracts.
             if size_report.exceeds_size_limit() {
                 std::process::exit(1);
             }
         }
+
+        if self.hardhat {
+            write_hardhat_artifacts(project, output)?;
+        }
+
+        Ok(())
     }
+
+    /// Prints a compile-time breakdown: total duration, a per-solc-version file count/duration
+    /// summary, and the slowest source files.
+    ///
+    /// Note: `solc` only reports timing per invocation (a "compilation unit"), not per file, so
+    /// the "slowest source files" are ranked by the duration of the compilation unit each file was
+    /// part of, not by isolated per-file timing.
+    fn print_timings(&self, timings: &[term::CompilationTiming], elapsed: Duration) -> Result<()> {
+        let slowest = slowest_files(timings, 5);
+
+        if self.print_sizes_json {
+            let json = serde_json::json!({
+                "totalDurationMs": elapsed.as_millis(),
+                "versions": timings.iter().map(|t| serde_json::json!({
+                    "version": t.version.to_string(),
+                    "files": t.files.len(),
+                    "durationMs": t.duration.as_millis(),
+                })).collect::<Vec<_>>(),
+                "slowestFiles": slowest.iter().map(|(file, duration)| serde_json::json!({
+                    "file": file.display().to_string(),
+                    "durationMs": duration.as_millis(),
+                })).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+            return Ok(())
+        }
+
+        println!("\nCompiled in {elapsed:.2?}");
+        for timing in timings {
+            println!(
+                "  solc {}.{}.{}: {} files in {:.2?}",
+                timing.version.major,
+                timing.version.minor,
+                timing.version.patch,
+                timing.files.len(),
+                timing.duration
+            );
+        }
+
+        if !slowest.is_empty() {
+            println!("\nSlowest source files (by compilation unit):");
+            for (file, duration) in slowest {
+                println!("  {:.2?} {}", duration, file.display());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the `n` slowest `(file, duration)` pairs across all compilation units, attributing
+/// each file the total duration of the compilation unit it was part of.
+fn slowest_files(timings: &[term::CompilationTiming], n: usize) -> Vec<(PathBuf, Duration)> {
+    let mut files: Vec<_> = timings
+        .iter()
+        .flat_map(|timing| timing.files.iter().map(|file| (file.clone(), timing.duration)))
+        .collect();
+    files.sort_by(|a, b| b.1.cmp(&a.1));
+    files.truncate(n);
+    files
+}
+
+/// Writes artifacts in the shape Hardhat expects (`<source>/<Contract>.json` with
+/// `abi`/`bytecode`/`deployedBytecode`/`linkReferences` at the top level, plus a `.dbg.json`
+/// pointing at a shared `build-info/<id>.json`) into a `hardhat/` subdirectory of the configured
+/// artifacts dir, in addition to Foundry's native artifacts, so tooling that only understands the
+/// Hardhat format (e.g. existing frontend/deployment scripts) keeps working without migrating off
+/// it. This is purely additive and written to its own subdirectory rather than alongside the
+/// native files: Foundry's own native layout is also `<out>/<source>/<Contract>.json`, so writing
+/// Hardhat's shape directly into `<out>/<source>/<Contract>.json` would overwrite the file
+/// Foundry's artifact cache and `forge test` rely on.
+///
+/// Note: the `build-info` files this writes only carry the compiler output we already have on
+/// hand, not the original standard-json `input` (sources/settings) sent to solc, since
+/// `ProjectCompileOutput` doesn't retain it. Hardhat plugins that only consume the `output` half
+/// of a build-info file (the common case) are unaffected; ones that need the original `input` are
+/// not supported yet.
+fn write_hardhat_artifacts(project: &Project, output: &ProjectCompileOutput) -> Result<()> {
+    let artifacts_root = project.paths.artifacts.join("hardhat");
+
+    let mut versions = BTreeMap::new();
+    for (_, (_, version)) in output.versioned_artifacts() {
+        versions.entry(version).or_insert_with(|| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            version.hash(&mut hasher);
+            format!("{:016x}", hasher.finish())
+        });
+    }
+
+    for (id, artifact) in output.clone().into_artifacts() {
+        let source_name = id
+            .source
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let contract_dir = artifacts_root.join(&source_name);
+        fs::create_dir_all(&contract_dir)?;
+
+        let build_info_id = versions.get(&id.version).cloned().unwrap_or_default();
+
+        let link_references =
+            artifact.bytecode.as_ref().map(|b| b.link_references.clone()).unwrap_or_default();
+        let deployed_link_references = artifact
+            .deployed_bytecode
+            .as_ref()
+            .and_then(|b| b.bytecode.as_ref())
+            .map(|b| b.link_references.clone())
+            .unwrap_or_default();
+
+        let hh_artifact = serde_json::json!({
+            "contractName": id.name,
+            "sourceName": source_name,
+            "abi": artifact.abi,
+            "bytecode": artifact.bytecode.as_ref().map(|b| &b.object),
+            "deployedBytecode": artifact
+                .deployed_bytecode
+                .as_ref()
+                .and_then(|b| b.bytecode.as_ref())
+                .map(|b| &b.object),
+            "linkReferences": link_references,
+            "deployedLinkReferences": deployed_link_references,
+        });
+        fs::write(
+            contract_dir.join(format!("{}.json", id.name)),
+            serde_json::to_vec_pretty(&hh_artifact)?,
+        )?;
+
+        let dbg = serde_json::json!({
+            "_format": "hh-sol-dbg-1",
+            "buildInfo": format!("../build-info/{build_info_id}.json"),
+        });
+        fs::write(
+            contract_dir.join(format!("{}.dbg.json", id.name)),
+            serde_json::to_vec_pretty(&dbg)?,
+        )?;
+    }
+
+    let build_info_dir = artifacts_root.join("build-info");
+    fs::create_dir_all(&build_info_dir)?;
+    for (version, build_info_id) in &versions {
+        let build_info = serde_json::json!({
+            "_format": "hh-sol-build-info-1",
+            "id": build_info_id,
+            "solcVersion": version.to_string(),
+            "solcLongVersion": version.to_string(),
+            "output": output.output(),
+        });
+        fs::write(
+            build_info_dir.join(format!("{build_info_id}.json")),
+            serde_json::to_vec_pretty(&build_info)?,
+        )?;
+    }
+
+    Ok(())
 }
 
 // https://eips.ethereum.org/EIPS/eip-170
@@ -178,6 +481,8 @@ const CONTRACT_SIZE_LIMIT: usize = 24576;
 pub struct SizeReport {
     /// `<contract name>:info>`
     pub contracts: BTreeMap<String, ContractInfo>,
+    /// whether to show the init code size as an extra column when printed as a table
+    pub print_init_code_size: bool,
 }
 
 impl SizeReport {
@@ -202,11 +507,17 @@ impl Display for SizeReport {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         let mut table = Table::new();
         table.load_preset(ASCII_MARKDOWN);
-        table.set_header(vec![
+        let mut header = vec![
             Cell::new("Contract").add_attribute(Attribute::Bold).fg(Color::Blue),
             Cell::new("Size (kB)").add_attribute(Attribute::Bold).fg(Color::Blue),
             Cell::new("Margin (kB)").add_attribute(Attribute::Bold).fg(Color::Blue),
-        ]);
+        ];
+        if self.print_init_code_size {
+            header.push(
+                Cell::new("Init Code Size (kB)").add_attribute(Attribute::Bold).fg(Color::Blue),
+            );
+        }
+        table.set_header(header);
 
         let contracts = self.contracts.iter().filter(|(_, c)| !c.is_dev_contract && c.size > 0);
         for (name, contract) in contracts {
@@ -217,11 +528,15 @@ impl Display for SizeReport {
                 _ => Color::Red,
             };
 
-            table.add_row(vec![
+            let mut row = vec![
                 Cell::new(name).fg(color),
                 Cell::new(contract.size as f64 / 1000.0).fg(color),
                 Cell::new(margin as f64 / 1000.0).fg(color),
-            ]);
+            ];
+            if self.print_init_code_size {
+                row.push(Cell::new(contract.init_code_size as f64 / 1000.0).fg(color));
+            }
+            table.add_row(row);
         }
 
         writeln!(f, "{table}")?;
@@ -232,7 +547,17 @@ impl Display for SizeReport {
 /// Returns the size of the deployed contract
 pub fn deployed_contract_size<T: Artifact>(artifact: &T) -> Option<usize> {
     let bytecode = artifact.get_deployed_bytecode_object()?;
-    let size = match bytecode.as_ref() {
+    Some(bytecode_object_size(bytecode.as_ref()))
+}
+
+/// Returns the size of the contract's init code (creation bytecode)
+pub fn contract_size<T: Artifact>(artifact: &T) -> Option<usize> {
+    let bytecode = artifact.get_bytecode_object()?;
+    Some(bytecode_object_size(bytecode.as_ref()))
+}
+
+fn bytecode_object_size(bytecode: &BytecodeObject) -> usize {
+    match bytecode {
         BytecodeObject::Bytecode(bytes) => bytes.len(),
         BytecodeObject::Unlinked(unlinked) => {
             // we don't need to account for placeholders here, because library placeholders take up
@@ -244,15 +569,16 @@ pub fn deployed_contract_size<T: Artifact>(artifact: &T) -> Option<usize> {
             // hex -> bytes
             size / 2
         }
-    };
-    Some(size)
+    }
 }
 
 /// How big the contract is and whether it is a dev contract where size limits can be neglected
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct ContractInfo {
-    /// size of the contract in bytes
+    /// size of the deployed contract in bytes
     pub size: usize,
+    /// size of the contract's init code (creation bytecode) in bytes
+    pub init_code_size: usize,
     /// A development contract is either a Script or a Test contract.
     pub is_dev_contract: bool,
 }
@@ -290,7 +616,7 @@ pub fn suppress_compile(project: &Project) -> Result<ProjectCompileOutput> {
     )?;
 
     if output.has_compiler_errors() {
-        eyre::bail!(output.to_string())
+        return Err(ExitCodeError::new(ExitCode::CompileError, eyre::eyre!(output.to_string())))
     }
 
     Ok(output)
@@ -324,7 +650,7 @@ pub fn suppress_compile_sparse<F: FileFilter + 'static>(
     )?;
 
     if output.has_compiler_errors() {
-        eyre::bail!(output.to_string())
+        return Err(ExitCodeError::new(ExitCode::CompileError, eyre::eyre!(output.to_string())))
     }
 
     Ok(output)
@@ -348,7 +674,7 @@ pub fn compile_files(
     }?;
 
     if output.has_compiler_errors() {
-        eyre::bail!(output.to_string())
+        return Err(ExitCodeError::new(ExitCode::CompileError, eyre::eyre!(output.to_string())))
     }
     if !silent {
         println!("{output}");
@@ -409,7 +735,7 @@ pub async fn compile_from_source(
     let project_output = project.compile()?;
 
     if project_output.has_compiler_errors() {
-        eyre::bail!(project_output.to_string())
+        return Err(ExitCodeError::new(ExitCode::CompileError, eyre::eyre!(project_output.to_string())))
     }
 
     let (artifact_id, contract) = project_output
@@ -471,6 +797,18 @@ pub fn etherscan_project(metadata: &Metadata, target_path: impl AsRef<Path>) ->
         .build()?)
 }
 
+/// Combines two [`FileFilter`]s into one that only matches a file if _both_ of the inner filters
+/// match it, e.g. combining a test filter with [`SkipBuildFilters`] so that `--skip` is honored on
+/// top of an already-narrowed sparse compile.
+#[derive(Debug, Clone)]
+pub struct AndFilter<A, B>(pub A, pub B);
+
+impl<A: FileFilter, B: FileFilter> FileFilter for AndFilter<A, B> {
+    fn is_match(&self, file: &Path) -> bool {
+        self.0.is_match(file) && self.1.is_match(file)
+    }
+}
+
 /// Bundles multiple `SkipBuildFilter` into a single `FileFilter`
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct SkipBuildFilters(pub Vec<SkipBuildFilter>);
@@ -93,10 +93,31 @@ impl DerefMut for ContractsByArtifact {
 /// Wrapper type that maps an address to a contract identifier and contract ABI.
 pub type ContractsByAddress = BTreeMap<Address, (String, Abi)>;
 
+/// Strips solc's CBOR metadata trailer from the end of a bytecode, if present, so that two
+/// otherwise-identical contracts compiled with different metadata hashes (e.g. `bytecode_hash`
+/// settings, or the same project built from different absolute paths) still compare as equal.
+///
+/// The trailer is a CBOR-encoded blob followed by its own length as a 2-byte big-endian integer;
+/// see <https://docs.soliditylang.org/en/latest/metadata.html#encoding-of-the-metadata-hash-in-the-bytecode>.
+/// This doesn't validate the CBOR itself, it just trusts the length prefix, which is good enough
+/// for comparison purposes.
+fn strip_metadata(code: &[u8]) -> &[u8] {
+    if code.len() < 2 {
+        return code
+    }
+    let metadata_len = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+    if metadata_len == 0 || metadata_len + 2 > code.len() {
+        return code
+    }
+    &code[..code.len() - metadata_len - 2]
+}
+
 /// Very simple fuzzy matching of contract bytecode.
 ///
 /// Will fail for small contracts that are essentially all immutable variables.
 pub fn diff_score(a: &[u8], b: &[u8]) -> f64 {
+    let a = strip_metadata(a);
+    let b = strip_metadata(b);
     let cutoff_len = usize::min(a.len(), b.len());
     if cutoff_len == 0 {
         return 1.0
@@ -173,6 +194,10 @@ pub fn get_file_name(id: &str) -> &str {
 }
 
 /// Returns the path to the json artifact depending on the input
+///
+/// Accepts `<file>`, `<file>:<contract>`, or `<file>:<contract>:<version>`, the latter
+/// disambiguating between artifacts of the same name compiled by different solc versions, which
+/// are written to disk as `<contract>.<version>.json` instead of plain `<contract>.json`.
 pub fn get_artifact_path(paths: &ProjectPathsConfig, path: &str) -> PathBuf {
     if path.ends_with(".json") {
         PathBuf::from(path)
@@ -181,10 +206,35 @@ pub fn get_artifact_path(paths: &ProjectPathsConfig, path: &str) -> PathBuf {
         let file = parts[0];
         let contract_name =
             if parts.len() == 1 { parts[0].replace(".sol", "") } else { parts[1].to_string() };
-        paths.artifacts.join(format!("{file}/{contract_name}.json"))
+        let file_name = match parts.get(2) {
+            Some(version) => format!("{contract_name}.{version}.json"),
+            None => format!("{contract_name}.json"),
+        };
+        paths.artifacts.join(file).join(file_name)
     }
 }
 
+/// Given a missing artifact `path` that couldn't be read, returns up to a handful of artifact file
+/// names under `paths.artifacts` whose name is similar to the one the caller asked for, to surface
+/// as "did you mean" suggestions.
+pub fn find_close_artifacts(paths: &ProjectPathsConfig, path: &str) -> Vec<String> {
+    let parts: Vec<&str> = path.split(':').collect();
+    let wanted = if parts.len() == 1 { parts[0].replace(".sol", "") } else { parts[1].to_string() };
+
+    let mut candidates: Vec<(f64, String)> = walkdir::WalkDir::new(&paths.artifacts)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().map_or(false, |ext| ext == "json"))
+        .filter_map(|entry| entry.path().file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .map(|name| (strsim::jaro_winkler(&wanted, &name), name))
+        .filter(|(score, _)| *score > 0.8)
+        .collect();
+
+    candidates.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    candidates.dedup_by(|a, b| a.1 == b.1);
+    candidates.into_iter().take(5).map(|(_, name)| name).collect()
+}
+
 /// Given the transaction data tries to identify the constructor arguments
 /// The constructor data is encoded as: Constructor Code + Contract Code +  Constructor arguments
 /// decoding the arguments here with only the transaction data is not trivial here, we try to find
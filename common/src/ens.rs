@@ -0,0 +1,45 @@
+//! Shared ENS name resolution, used by the `cast` commands that build transactions (`call`,
+//! `send`, `estimate`) and by `storage`, `logs` and `multicall`, so their behavior (and error
+//! messages) stay consistent.
+
+use ethers_core::types::{Address, NameOrAddress};
+use ethers_providers::Middleware;
+use eyre::{Result, WrapErr};
+
+/// Resolves `addr` to a concrete [Address] against `provider`'s connected RPC, performing an ENS
+/// forward lookup if it's a name.
+///
+/// Distinguishes a name that simply isn't registered (the resolver returns the zero address)
+/// from an RPC failure while looking it up, so the two don't both surface as the same opaque
+/// error. Pass `no_ens: true` to reject names outright instead of resolving them, which is
+/// appropriate when `addr` comes from untrusted input and silent ENS resolution would be
+/// surprising.
+pub async fn resolve_ens<M: Middleware>(
+    provider: &M,
+    addr: impl Into<NameOrAddress>,
+    no_ens: bool,
+) -> Result<Address>
+where
+    M::Error: 'static,
+{
+    match addr.into() {
+        NameOrAddress::Address(addr) => Ok(addr),
+        NameOrAddress::Name(name) => {
+            if no_ens {
+                eyre::bail!(
+                    "`{name}` looks like an ENS name, but --no-ens disables automatic \
+                     resolution; pass a hex address instead"
+                )
+            }
+
+            let addr = provider
+                .resolve_name(&name)
+                .await
+                .wrap_err_with(|| format!("failed to resolve ENS name `{name}`"))?;
+            if addr.is_zero() {
+                eyre::bail!("ENS name `{name}` is not registered")
+            }
+            Ok(addr)
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// Process exit codes shared by `forge`'s subcommands, so CI scripts can tell *why* a run failed
+/// instead of just that it did.
+///
+/// An error that isn't explicitly tagged with one of these (e.g. a plain `eyre::bail!`) still
+/// exits with [`ExitCode::Failure`], so existing scripts that only check for a non-zero exit code
+/// keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ExitCode {
+    /// Test/assertion failures, and the generic fallback for untagged errors.
+    Failure = 1,
+    /// The project failed to compile.
+    CompileError = 2,
+    /// Coverage is below the configured `--minimum-coverage` threshold.
+    CoverageThreshold = 3,
+    /// Invalid configuration or CLI arguments.
+    ConfigError = 4,
+}
+
+impl From<ExitCode> for i32 {
+    fn from(code: ExitCode) -> Self {
+        code as i32
+    }
+}
+
+/// Wraps an [`eyre::Report`] with an explicit [`ExitCode`].
+///
+/// Attach this at the point where an error's failure category is known (e.g. right after a
+/// compiler-error `bail!`), then let it propagate via `?` as usual. The binary's `main` downcasts
+/// the final error chain with [`ExitCodeError::code_of`] to decide how to exit.
+#[derive(Debug, thiserror::Error)]
+#[error("{source}")]
+pub struct ExitCodeError {
+    pub code: ExitCode,
+    #[source]
+    pub source: eyre::Report,
+}
+
+impl ExitCodeError {
+    /// Wraps `source` so it carries `code` up the `?` chain to `main`.
+    pub fn new(code: ExitCode, source: eyre::Report) -> eyre::Report {
+        eyre::Report::new(Self { code, source })
+    }
+
+    /// Returns the [`ExitCode`] tagged onto `report`, or [`ExitCode::Failure`] if it was never
+    /// wrapped with [`ExitCodeError::new`].
+    pub fn code_of(report: &eyre::Report) -> ExitCode {
+        report.downcast_ref::<Self>().map(|err| err.code).unwrap_or(ExitCode::Failure)
+    }
+}
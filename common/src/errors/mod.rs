@@ -5,3 +5,6 @@ pub use fs::FsPathError;
 
 mod artifacts;
 pub use artifacts::*;
+
+mod exit;
+pub use exit::{ExitCode, ExitCodeError};
@@ -12,6 +12,7 @@ use foundry_config::{
     Chain, Config,
 };
 use serde::Serialize;
+use std::path::PathBuf;
 
 /// `EvmArgs` and `EnvArgs` take the highest precedence in the Config/Figment hierarchy.
 /// All vars are opt-in, their default values are expected to be set by the
@@ -58,6 +59,20 @@ pub struct EvmArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fork_retry_backoff: Option<u64>,
 
+    /// Number of retries for spurious requests before giving up.
+    ///
+    /// See --fork-url.
+    #[clap(long, requires = "fork_url", value_name = "RETRIES")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fork_retries: Option<u32>,
+
+    /// Sets the number of assumed available compute units per second for this provider.
+    ///
+    /// See also --fork-url and <https://docs.alchemy.com/reference/compute-units#what-are-cups-compute-units-per-second>
+    #[clap(long, requires = "fork_url", value_name = "CUPS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_units_per_second: Option<u64>,
+
     /// Explicitly disables the use of RPC caching.
     ///
     /// All storage slots are read entirely from the endpoint.
@@ -69,6 +84,27 @@ pub struct EvmArgs {
     #[serde(skip)]
     pub no_storage_caching: bool,
 
+    /// Records every account, storage slot, and block hash fetched from the fork into
+    /// `<PATH>/storage.json`, in the same format the regular RPC cache uses.
+    ///
+    /// The recording can later be replayed without a live endpoint via `--fork-replay`, which
+    /// makes forked tests hermetic and fast in CI.
+    ///
+    /// See --fork-url.
+    #[clap(long, requires = "fork_url", value_name = "PATH", conflicts_with = "fork_replay")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fork_record: Option<PathBuf>,
+
+    /// Serves all forked RPC data from `<PATH>/storage.json`, as written by `--fork-record`,
+    /// instead of the live endpoint.
+    ///
+    /// Fails loudly, naming the missing key, if the run requests data that wasn't recorded.
+    ///
+    /// See --fork-url.
+    #[clap(long, requires = "fork_url", value_name = "PATH", conflicts_with = "fork_record")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fork_replay: Option<PathBuf>,
+
     /// The initial balance of deployed test contracts.
     #[clap(long, value_name = "BALANCE")]
     #[serde(skip_serializing_if = "Option::is_none")]
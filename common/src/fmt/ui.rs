@@ -410,6 +410,24 @@ pub fn get_pretty_block_attr<TX>(block: &Block<TX>, attr: &str) -> Option<String
     }
 }
 
+/// The field names accepted by [`get_pretty_tx_receipt_attr`], in `camelCase` form.
+pub const RECEIPT_FIELDS: &[&str] = &[
+    "blockHash",
+    "blockNumber",
+    "contractAddress",
+    "cumulativeGasUsed",
+    "effectiveGasPrice",
+    "gasUsed",
+    "logs",
+    "logsBloom",
+    "root",
+    "status",
+    "transactionHash",
+    "transactionIndex",
+    "type",
+    "revertReason",
+];
+
 /// Returns the ``UiFmt::pretty()` formatted attribute of the transaction receipt
 pub fn get_pretty_tx_receipt_attr(
     receipt: &TransactionReceiptWithRevertReason,
@@ -7,6 +7,7 @@ pub mod clap_helpers;
 pub mod compile;
 pub mod constants;
 pub mod contracts;
+pub mod ens;
 pub mod errors;
 pub mod evm;
 pub mod fmt;
@@ -22,3 +23,4 @@ pub use contracts::*;
 pub use traits::*;
 pub mod transactions;
 pub use transactions::*;
+pub mod yul;
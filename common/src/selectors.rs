@@ -1,12 +1,14 @@
 #![allow(missing_docs)]
 //! Support for handling/identifying selectors
 use crate::abi::abi_decode;
+use crate::fs;
 use ethers_solc::artifacts::LosslessAbi;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt,
+    path::{Path, PathBuf},
     sync::{
         atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
@@ -141,6 +143,25 @@ impl SignEthClient {
         selector: &str,
         selector_type: SelectorType,
     ) -> eyre::Result<Vec<String>> {
+        self.decode_selectors(&[selector.to_string()], selector_type)
+            .await?
+            .remove(selector)
+            .ok_or_else(|| eyre::eyre!("No signature found"))
+    }
+
+    /// Decodes the given function or event selectors using sig.eth.samczsun.com, in a single
+    /// batched request.
+    ///
+    /// Selectors for which no signature could be found are omitted from the returned map.
+    pub async fn decode_selectors(
+        &self,
+        selectors: &[String],
+        selector_type: SelectorType,
+    ) -> eyre::Result<HashMap<String, Vec<String>>> {
+        if selectors.is_empty() {
+            return Ok(HashMap::new())
+        }
+
         // exit early if spurious connection
         self.ensure_not_spurious()?;
 
@@ -164,9 +185,10 @@ impl SignEthClient {
 
         // using samczsun signature database over 4byte
         // see https://github.com/foundry-rs/foundry/issues/1672
+        let joined = selectors.join(",");
         let url = match selector_type {
-            SelectorType::Function => format!("{SELECTOR_DATABASE_URL}?function={selector}"),
-            SelectorType::Event => format!("{SELECTOR_DATABASE_URL}?event={selector}"),
+            SelectorType::Function => format!("{SELECTOR_DATABASE_URL}?function={joined}"),
+            SelectorType::Event => format!("{SELECTOR_DATABASE_URL}?event={joined}"),
         };
 
         let res = self.get_text(&url).await?;
@@ -187,21 +209,21 @@ impl SignEthClient {
         };
 
         Ok(decoded
-            .get(selector)
-            .ok_or(eyre::eyre!("No signature found"))?
-            .iter()
-            .filter_map(|d| (!d.filtered).then(|| d.name.clone()))
-            .collect::<Vec<String>>())
+            .into_iter()
+            .filter_map(|(selector, candidates)| {
+                let names: Vec<String> = candidates
+                    .into_iter()
+                    .filter_map(|d| (!d.filtered).then(|| d.name))
+                    .collect();
+                (!names.is_empty()).then_some((selector, names))
+            })
+            .collect())
     }
 
     /// Fetches a function signature given the selector using sig.eth.samczsun.com
     pub async fn decode_function_selector(&self, selector: &str) -> eyre::Result<Vec<String>> {
-        let prefixed_selector = format!("0x{}", selector.strip_prefix("0x").unwrap_or(selector));
-        if prefixed_selector.len() != 10 {
-            eyre::bail!("Invalid selector: expected 8 characters (excluding 0x prefix), got {} characters (including 0x prefix).", prefixed_selector.len())
-        }
-
-        self.decode_selector(&prefixed_selector[..10], SelectorType::Function).await
+        let selector = normalize_selector(selector)?;
+        self.decode_selector(&selector, SelectorType::Function).await
     }
 
     /// Fetches all possible signatures and attempts to abi decode the calldata
@@ -226,11 +248,109 @@ impl SignEthClient {
 
     /// Fetches an event signature given the 32 byte topic using sig.eth.samczsun.com
     pub async fn decode_event_topic(&self, topic: &str) -> eyre::Result<Vec<String>> {
-        let prefixed_topic = format!("0x{}", topic.strip_prefix("0x").unwrap_or(topic));
-        if prefixed_topic.len() != 66 {
-            eyre::bail!("Invalid topic: expected 64 characters (excluding 0x prefix), got {} characters (including 0x prefix).", prefixed_topic.len())
+        let topic = normalize_topic(topic)?;
+        self.decode_selector(&topic, SelectorType::Event).await
+    }
+
+    /// Like [`decode_selector`](Self::decode_selector), but consults the on-disk cache at
+    /// `cache_path` (shared with trace decoding's `SignaturesIdentifier`, albeit under its own
+    /// cache file since every matching signature is kept here instead of only the first one
+    /// resolved) before reaching the network, and updates it with any newly resolved signatures.
+    /// When `offline` is true, a cache miss resolves to an empty list instead of making a
+    /// request.
+    async fn decode_selector_with_cache(
+        &self,
+        selector: &str,
+        selector_type: SelectorType,
+        cache_path: Option<&Path>,
+        offline: bool,
+    ) -> eyre::Result<Vec<String>> {
+        let cache_file = cache_path.map(selector_cache_file);
+        let mut cache = cache_file
+            .as_deref()
+            .filter(|path| path.is_file())
+            .and_then(|path| fs::read_json_file::<SelectorCache>(path).ok())
+            .unwrap_or_default();
+
+        let map = match selector_type {
+            SelectorType::Function => &mut cache.functions,
+            SelectorType::Event => &mut cache.events,
+        };
+
+        if let Some(sigs) = map.get(selector) {
+            return Ok(sigs.clone())
+        }
+
+        if offline {
+            return Ok(Vec::new())
+        }
+
+        let sigs = self.decode_selector(selector, selector_type).await?;
+        map.insert(selector.to_string(), sigs.clone());
+
+        if let Some(cache_file) = &cache_file {
+            if let Some(parent) = cache_file.parent() {
+                if let Err(err) = std::fs::create_dir_all(parent) {
+                    warn!(?parent, ?err, "failed to create selector cache dir");
+                }
+            }
+            if let Err(err) = fs::write_json_file(cache_file, &cache) {
+                warn!(?cache_file, ?err, "failed to flush selector cache");
+            }
+        }
+
+        Ok(sigs)
+    }
+
+    /// Like [`decode_function_selector`](Self::decode_function_selector), but cached; see
+    /// [`decode_selector_with_cache`](Self::decode_selector_with_cache).
+    pub async fn decode_function_selector_with_cache(
+        &self,
+        selector: &str,
+        cache_path: Option<&Path>,
+        offline: bool,
+    ) -> eyre::Result<Vec<String>> {
+        let selector = normalize_selector(selector)?;
+        self.decode_selector_with_cache(&selector, SelectorType::Function, cache_path, offline)
+            .await
+    }
+
+    /// Like [`decode_calldata`](Self::decode_calldata), but cached; see
+    /// [`decode_selector_with_cache`](Self::decode_selector_with_cache).
+    pub async fn decode_calldata_with_cache(
+        &self,
+        calldata: &str,
+        cache_path: Option<&Path>,
+        offline: bool,
+    ) -> eyre::Result<Vec<String>> {
+        let calldata = calldata.strip_prefix("0x").unwrap_or(calldata);
+        if calldata.len() < 8 {
+            eyre::bail!(
+                "Calldata too short: expected at least 8 characters (excluding 0x prefix), got {}.",
+                calldata.len()
+            )
         }
-        self.decode_selector(&prefixed_topic[..66], SelectorType::Event).await
+
+        let sigs =
+            self.decode_function_selector_with_cache(&calldata[..8], cache_path, offline).await?;
+
+        Ok(sigs
+            .iter()
+            .cloned()
+            .filter(|sig| abi_decode(sig, calldata, true).is_ok())
+            .collect::<Vec<String>>())
+    }
+
+    /// Like [`decode_event_topic`](Self::decode_event_topic), but cached; see
+    /// [`decode_selector_with_cache`](Self::decode_selector_with_cache).
+    pub async fn decode_event_topic_with_cache(
+        &self,
+        topic: &str,
+        cache_path: Option<&Path>,
+        offline: bool,
+    ) -> eyre::Result<Vec<String>> {
+        let topic = normalize_topic(topic)?;
+        self.decode_selector_with_cache(&topic, SelectorType::Event, cache_path, offline).await
     }
 
     /// Pretty print calldata and if available, fetch possible function signatures
@@ -345,6 +465,35 @@ pub enum SelectorType {
     Event,
 }
 
+fn normalize_selector(selector: &str) -> eyre::Result<String> {
+    let selector = format!("0x{}", selector.strip_prefix("0x").unwrap_or(selector));
+    if selector.len() != 10 {
+        eyre::bail!("Invalid selector: expected 8 characters (excluding 0x prefix), got {} characters (including 0x prefix).", selector.len())
+    }
+    Ok(selector)
+}
+
+fn normalize_topic(topic: &str) -> eyre::Result<String> {
+    let topic = format!("0x{}", topic.strip_prefix("0x").unwrap_or(topic));
+    if topic.len() != 66 {
+        eyre::bail!("Invalid topic: expected 64 characters (excluding 0x prefix), got {} characters (including 0x prefix).", topic.len())
+    }
+    Ok(topic)
+}
+
+fn selector_cache_file(cache_path: &Path) -> PathBuf {
+    cache_path.join("selectors")
+}
+
+/// On-disk cache of every signature known to match a given selector or event topic, keyed by the
+/// selector/topic itself. Lives alongside trace decoding's signature cache under
+/// [`foundry_config::Config::foundry_cache_dir`], in its own file.
+#[derive(Debug, Deserialize, Serialize, Default)]
+struct SelectorCache {
+    functions: BTreeMap<String, Vec<String>>,
+    events: BTreeMap<String, Vec<String>>,
+}
+
 /// Decodes the given function or event selector using sig.eth.samczsun.com
 pub async fn decode_selector(
     selector: &str,
@@ -368,6 +517,36 @@ pub async fn decode_event_topic(topic: &str) -> eyre::Result<Vec<String>> {
     SignEthClient::new()?.decode_event_topic(topic).await
 }
 
+/// Like [`decode_function_selector`], but consults the local signature cache at `cache_path`
+/// first, and only consults the cache (rather than the network) when `offline` is true.
+pub async fn decode_function_selector_with_cache(
+    selector: &str,
+    cache_path: Option<&Path>,
+    offline: bool,
+) -> eyre::Result<Vec<String>> {
+    SignEthClient::new()?.decode_function_selector_with_cache(selector, cache_path, offline).await
+}
+
+/// Like [`decode_calldata`], but consults the local signature cache at `cache_path` first, and
+/// only consults the cache (rather than the network) when `offline` is true.
+pub async fn decode_calldata_with_cache(
+    calldata: &str,
+    cache_path: Option<&Path>,
+    offline: bool,
+) -> eyre::Result<Vec<String>> {
+    SignEthClient::new()?.decode_calldata_with_cache(calldata, cache_path, offline).await
+}
+
+/// Like [`decode_event_topic`], but consults the local signature cache at `cache_path` first, and
+/// only consults the cache (rather than the network) when `offline` is true.
+pub async fn decode_event_topic_with_cache(
+    topic: &str,
+    cache_path: Option<&Path>,
+    offline: bool,
+) -> eyre::Result<Vec<String>> {
+    SignEthClient::new()?.decode_event_topic_with_cache(topic, cache_path, offline).await
+}
+
 /// Pretty print calldata and if available, fetch possible function signatures
 ///
 /// ```no_run
@@ -233,6 +233,77 @@ pub fn with_spinner_reporter<T>(f: impl FnOnce() -> T) -> T {
     report::with_scoped(&reporter, f)
 }
 
+/// The wall time a single `solc` invocation (a "compilation unit": one version, compiled with one
+/// set of settings) took, and which files it compiled.
+#[derive(Debug, Clone)]
+pub struct CompilationTiming {
+    /// The `solc` version used for this invocation.
+    pub version: Version,
+    /// The files that were part of this compilation unit.
+    pub files: Vec<PathBuf>,
+    /// How long the `solc` invocation took.
+    pub duration: Duration,
+}
+
+/// A [`Reporter`] that records, instead of printing, the wall time of every `solc` invocation via
+/// [`Reporter::on_solc_spawn`]/[`Reporter::on_solc_success`], for `--timings` output.
+///
+/// `solc` doesn't report per-file timings, only per-invocation ones, so the files of a given
+/// invocation all share that invocation's duration.
+#[derive(Debug, Clone, Default)]
+struct TimingsReporter {
+    /// Invocations that have been spawned but haven't finished yet, keyed by version.
+    pending: Arc<Mutex<Vec<(Version, Vec<PathBuf>)>>>,
+    /// Invocations that have finished, in completion order.
+    completed: Arc<Mutex<Vec<CompilationTiming>>>,
+}
+
+impl TimingsReporter {
+    fn timings(&self) -> Vec<CompilationTiming> {
+        self.completed.lock().unwrap().clone()
+    }
+}
+
+impl Reporter for TimingsReporter {
+    fn on_solc_spawn(
+        &self,
+        _solc: &Solc,
+        version: &Version,
+        _input: &CompilerInput,
+        dirty_files: &[PathBuf],
+    ) {
+        self.pending.lock().unwrap().push((version.clone(), dirty_files.to_vec()));
+    }
+
+    fn on_solc_success(
+        &self,
+        _solc: &Solc,
+        version: &Version,
+        _output: &CompilerOutput,
+        duration: &Duration,
+    ) {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(idx) = pending.iter().position(|(v, _)| v == version) {
+            let (version, files) = pending.remove(idx);
+            self.completed.lock().unwrap().push(CompilationTiming {
+                version,
+                files,
+                duration: *duration,
+            });
+        }
+    }
+}
+
+/// Runs `f` while recording the wall time of every `solc` invocation, for `--timings` output.
+///
+/// Unlike [`with_spinner_reporter`], this doesn't show any progress output of its own; callers
+/// that want both should print their own progress from the returned timings instead.
+pub fn with_timings_reporter<T>(f: impl FnOnce() -> T) -> (T, Vec<CompilationTiming>) {
+    let reporter = TimingsReporter::default();
+    let result = report::with_scoped(&report::Report::new(reporter.clone()), f);
+    (result, reporter.timings())
+}
+
 #[macro_export]
 /// Displays warnings on the cli
 macro_rules! cli_warn {
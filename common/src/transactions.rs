@@ -1,5 +1,5 @@
 //! wrappers for transactions
-use ethers_core::types::{BlockId, TransactionReceipt};
+use ethers_core::types::{BlockId, BlockNumber, TransactionReceipt};
 use ethers_providers::Middleware;
 use eyre::Result;
 use serde::{Deserialize, Serialize};
@@ -29,6 +29,8 @@ impl TransactionReceiptWithRevertReason {
         Ok(())
     }
 
+    /// Re-executes the transaction via `eth_call` at its parent block to recover the revert
+    /// reason, if the receipt indicates the transaction failed.
     async fn fetch_revert_reason<M: Middleware>(&self, provider: &M) -> Result<Option<String>> {
         if let Some(false) | None = self.is_failure() {
             return Ok(None)
@@ -39,13 +41,16 @@ impl TransactionReceiptWithRevertReason {
             .await
             .map_err(|_| eyre::eyre!("unable to fetch transaction"))?
         {
-            if let Some(block_hash) = self.receipt.block_hash {
-                match provider.call(&transaction.into(), Some(BlockId::Hash(block_hash))).await {
-                    Err(e) => return Ok(extract_revert_reason(e.to_string())),
-                    Ok(_) => eyre::bail!("no revert reason as transaction succeeded"),
-                }
+            let block_number = self
+                .receipt
+                .block_number
+                .ok_or_else(|| eyre::eyre!("unable to fetch block number"))?;
+            let parent_block =
+                BlockId::Number(BlockNumber::Number(block_number.saturating_sub(1u64.into())));
+            match provider.call(&transaction.into(), Some(parent_block)).await {
+                Err(e) => return Ok(extract_revert_reason(e.to_string())),
+                Ok(_) => eyre::bail!("no revert reason as transaction succeeded"),
             }
-            eyre::bail!("unable to fetch block_hash")
         }
         Err(eyre::eyre!("transaction does not exist"))
     }
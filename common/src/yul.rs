@@ -0,0 +1,121 @@
+//! Support for compiling standalone Yul sources (`.yul` files).
+//!
+//! `ethers_solc`'s project pipeline only understands Solidity, so Yul files sitting in the
+//! project's source directory are otherwise silently ignored by `forge build`. This module finds
+//! them, shells out to the resolved `solc` binary directly (Yul's `--strict-assembly` output is a
+//! stable, documented text format, unlike the internals of a pinned `ethers_solc` dependency), and
+//! writes the result as an artifact in the same on-disk shape Foundry's Solidity artifacts use, so
+//! it can be picked up by `vm.getCode`/`vm.getDeployedCode` without any further change.
+
+use crate::fs;
+use ethers_core::utils::hex;
+use ethers_solc::Project;
+use eyre::{Context, Result};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// A standalone Yul contract compiled outside of the regular Solidity pipeline.
+#[derive(Debug, Clone)]
+pub struct YulArtifact {
+    /// Path to the `.yul` source file, relative to the project root.
+    pub source: PathBuf,
+    /// The contract name, taken from the file stem.
+    pub name: String,
+    /// Creation bytecode, as raw bytes.
+    pub bytecode: Vec<u8>,
+    /// Runtime (deployed) bytecode, as raw bytes.
+    pub deployed_bytecode: Vec<u8>,
+}
+
+/// Finds and compiles every `.yul` file under `project`'s source directory, writing each result's
+/// artifact into the project's artifacts directory.
+///
+/// Returns an empty vector without invoking `solc` if there are no Yul sources.
+pub fn compile_yul_sources(project: &Project) -> Result<Vec<YulArtifact>> {
+    let yul_files = find_yul_sources(&project.paths.sources);
+    if yul_files.is_empty() {
+        return Ok(Vec::new())
+    }
+
+    let mut artifacts = Vec::new();
+    for file in yul_files {
+        let artifact = compile_yul_file(&project.solc.solc, &file)
+            .wrap_err_with(|| format!("failed to compile Yul file `{}`", file.display()))?;
+        write_yul_artifact(&project.paths.artifacts, &project.paths.root, &file, &artifact)?;
+        artifacts.push(artifact);
+    }
+    Ok(artifacts)
+}
+
+/// Recursively collects every `.yul` file under `sources_dir`.
+fn find_yul_sources(sources_dir: &Path) -> Vec<PathBuf> {
+    walkdir::WalkDir::new(sources_dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "yul"))
+        .collect()
+}
+
+/// Invokes `solc --strict-assembly` on a single Yul file and parses its creation and runtime
+/// bytecode out of the textual output.
+fn compile_yul_file(solc: &Path, file: &Path) -> Result<YulArtifact> {
+    let output = Command::new(solc)
+        .arg("--strict-assembly")
+        .arg("--bin")
+        .arg("--bin-runtime")
+        .arg(file)
+        .output()
+        .wrap_err("failed to spawn solc")?;
+
+    if !output.status.success() {
+        eyre::bail!("solc exited with an error:\n{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let name = file
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| eyre::eyre!("invalid Yul file name `{}`", file.display()))?
+        .to_string();
+
+    let bytecode = extract_bytecode(&stdout, "Binary representation:")
+        .ok_or_else(|| eyre::eyre!("solc produced no creation bytecode"))?;
+    let deployed_bytecode =
+        extract_bytecode(&stdout, "Binary representation (\"runtime\" subobject):")
+            .unwrap_or_else(|| bytecode.clone());
+
+    Ok(YulArtifact { source: file.to_path_buf(), name, bytecode, deployed_bytecode })
+}
+
+/// Extracts the hex bytecode following `marker` in solc's `--strict-assembly` output.
+fn extract_bytecode(stdout: &str, marker: &str) -> Option<Vec<u8>> {
+    let after_marker = &stdout[stdout.find(marker)? + marker.len()..];
+    let hex_line = after_marker.lines().find(|line| !line.trim().is_empty())?;
+    hex::decode(hex_line.trim()).ok()
+}
+
+/// Writes a [`YulArtifact`] to `artifacts_root`, mirroring the `<out>/<source>/<name>.json` layout
+/// used by the regular Solidity artifacts, in the same `CompactContractBytecode`-compatible shape
+/// `vm.getCode`/`vm.getDeployedCode` already know how to read.
+fn write_yul_artifact(
+    artifacts_root: &Path,
+    project_root: &Path,
+    file: &Path,
+    artifact: &YulArtifact,
+) -> Result<()> {
+    let relative_source = file.strip_prefix(project_root).unwrap_or(file);
+    let contract_dir = artifacts_root.join(relative_source);
+    fs::create_dir_all(&contract_dir)?;
+
+    let artifact_json = serde_json::json!({
+        "abi": [],
+        "bytecode": { "object": format!("0x{}", hex::encode(&artifact.bytecode)) },
+        "deployedBytecode": { "object": format!("0x{}", hex::encode(&artifact.deployed_bytecode)) },
+    });
+    fs::write_json_file(&contract_dir.join(format!("{}.json", artifact.name)), &artifact_json)?;
+    Ok(())
+}
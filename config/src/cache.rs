@@ -202,26 +202,52 @@ impl fmt::Display for Cache {
             for block in &chain.blocks {
                 match NumberPrefix::decimal(block.1 as f32) {
                     NumberPrefix::Standalone(size) => {
-                        writeln!(f, "\t-️ Block {} ({size:.1} B)", block.0)?;
+                        write!(f, "\t-️ Block {} ({size:.1} B)", block.0)?;
                     }
                     NumberPrefix::Prefixed(prefix, size) => {
-                        writeln!(f, "\t-️ Block {} ({size:.1} {prefix}B)", block.0)?;
+                        write!(f, "\t-️ Block {} ({size:.1} {prefix}B)", block.0)?;
                     }
                 }
+                writeln!(f, ", last used {}", fmt_age(block.2))?;
             }
         }
         Ok(())
     }
 }
 
+/// Formats a unix timestamp (seconds) as a rough "age" relative to now, e.g. `3d ago`.
+///
+/// Falls back to `never` for a timestamp of `0`, which is what's recorded for cache entries
+/// whose last-modified time could not be determined.
+fn fmt_age(last_used: u64) -> String {
+    if last_used == 0 {
+        return "never".to_string()
+    }
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(last_used);
+    let age = now.saturating_sub(last_used);
+    if age < 60 {
+        "just now".to_string()
+    } else if age < 60 * 60 {
+        format!("{}m ago", age / 60)
+    } else if age < 60 * 60 * 24 {
+        format!("{}h ago", age / (60 * 60))
+    } else {
+        format!("{}d ago", age / (60 * 60 * 24))
+    }
+}
+
 /// A representation of data for a given chain in the foundry cache
 #[derive(Debug)]
 pub struct ChainCache {
     /// The name of the chain
     pub name: String,
 
-    /// A tuple containing block number and the block directory size in bytes
-    pub blocks: Vec<(String, u64)>,
+    /// A tuple containing the block number, the block directory size in bytes, and the unix
+    /// timestamp (seconds) the block's cache file was last written to, or `0` if unknown.
+    pub blocks: Vec<(String, u64, u64)>,
 
     /// The size of the block explorer directory in bytes
     pub block_explorer: u64,
@@ -270,22 +296,22 @@ mod tests {
             chains: vec![
                 ChainCache {
                     name: "mainnet".to_string(),
-                    blocks: vec![("1".to_string(), 1), ("2".to_string(), 2)],
+                    blocks: vec![("1".to_string(), 1, 0), ("2".to_string(), 2, 0)],
                     block_explorer: 500,
                 },
                 ChainCache {
                     name: "ropsten".to_string(),
-                    blocks: vec![("1".to_string(), 1), ("2".to_string(), 2)],
+                    blocks: vec![("1".to_string(), 1, 0), ("2".to_string(), 2, 0)],
                     block_explorer: 4567,
                 },
                 ChainCache {
                     name: "rinkeby".to_string(),
-                    blocks: vec![("1".to_string(), 1032), ("2".to_string(), 2000000)],
+                    blocks: vec![("1".to_string(), 1032, 0), ("2".to_string(), 2000000, 0)],
                     block_explorer: 4230000,
                 },
                 ChainCache {
                     name: "mumbai".to_string(),
-                    blocks: vec![("1".to_string(), 1), ("2".to_string(), 2)],
+                    blocks: vec![("1".to_string(), 1, 0), ("2".to_string(), 2, 0)],
                     block_explorer: 0,
                 },
             ],
@@ -294,20 +320,20 @@ mod tests {
         let expected = "\
             -️ mainnet (503.0 B)\n\t\
                 -️ Block Explorer (500.0 B)\n\n\t\
-                -️ Block 1 (1.0 B)\n\t\
-                -️ Block 2 (2.0 B)\n\
+                -️ Block 1 (1.0 B), last used never\n\t\
+                -️ Block 2 (2.0 B), last used never\n\
             -️ ropsten (4.6 kB)\n\t\
                 -️ Block Explorer (4.6 kB)\n\n\t\
-                -️ Block 1 (1.0 B)\n\t\
-                -️ Block 2 (2.0 B)\n\
+                -️ Block 1 (1.0 B), last used never\n\t\
+                -️ Block 2 (2.0 B), last used never\n\
             -️ rinkeby (6.2 MB)\n\t\
                 -️ Block Explorer (4.2 MB)\n\n\t\
-                -️ Block 1 (1.0 kB)\n\t\
-                -️ Block 2 (2.0 MB)\n\
+                -️ Block 1 (1.0 kB), last used never\n\t\
+                -️ Block 2 (2.0 MB), last used never\n\
             -️ mumbai (3.0 B)\n\t\
                 -️ Block Explorer (0.0 B)\n\n\t\
-                -️ Block 1 (1.0 B)\n\t\
-                -️ Block 2 (2.0 B)\n";
+                -️ Block 1 (1.0 B), last used never\n\t\
+                -️ Block 2 (2.0 B), last used never\n";
         assert_str_eq!(format!("{cache}"), expected);
     }
 }
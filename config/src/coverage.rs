@@ -0,0 +1,47 @@
+//! Configuration for `forge coverage`
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Contains the config for `forge coverage`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageConfig {
+    /// The report types to generate by default, e.g. `["summary", "lcov"]`. Overridden by the
+    /// `--report` CLI flag if given.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub report: Vec<CoverageReportKind>,
+    /// Glob patterns matching source files that should be excluded from the coverage report
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Path to the lcov report, relative to the project root
+    pub lcov_file: PathBuf,
+    /// The minimum line coverage percentage required, between `0` and `100`. If set and the
+    /// actual coverage falls below this, `forge coverage` exits with an error.
+    pub minimum_coverage: Option<f64>,
+    /// Whether to include the project's test files in the coverage report
+    pub include_tests: bool,
+}
+
+impl Default for CoverageConfig {
+    fn default() -> Self {
+        CoverageConfig {
+            report: vec![],
+            exclude: vec![],
+            lcov_file: "lcov.info".into(),
+            minimum_coverage: None,
+            include_tests: false,
+        }
+    }
+}
+
+/// Kind of coverage report to generate
+///
+/// Mirrors the `forge coverage --report` CLI flag, but lives in the `config` crate too so it can
+/// be set directly from a `[coverage]` section in `foundry.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoverageReportKind {
+    Summary,
+    Lcov,
+    Debug,
+}
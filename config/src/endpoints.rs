@@ -31,7 +31,15 @@ impl RpcEndpoints {
     /// Returns all (alias -> url) pairs
     pub fn resolved(self) -> ResolvedRpcEndpoints {
         ResolvedRpcEndpoints {
-            endpoints: self.endpoints.into_iter().map(|(name, e)| (name, e.resolve())).collect(),
+            endpoints: self
+                .endpoints
+                .into_iter()
+                .map(|(name, e)| {
+                    let resolved =
+                        e.resolve().map_err(|err| err.with_key(format!("rpc_endpoints.{name}")));
+                    (name, resolved)
+                })
+                .collect(),
         }
     }
 }
@@ -25,6 +25,8 @@ pub enum EtherscanConfigError {
     UnknownChain(String, Chain),
     #[error("Missing `url` or `chain` for etherscan config `{0}`")]
     MissingUrlOrChain(String),
+    #[error("No `etherscan` entry configured for chain `{0}`; configured aliases: {1}")]
+    ChainNotConfigured(Chain, String),
 }
 
 /// Container type for API endpoints, like various RPC endpoints
@@ -52,6 +54,18 @@ impl EtherscanConfigs {
         self.configs.values().find(|config| config.chain == Some(chain))
     }
 
+    /// Returns a copy of this type with all `key` values replaced by a placeholder, so it can be
+    /// safely printed (e.g. by `forge config`) without leaking the configured secrets.
+    pub fn redacted(&self) -> Self {
+        Self {
+            configs: self
+                .configs
+                .iter()
+                .map(|(name, config)| (name.clone(), config.redacted()))
+                .collect(),
+        }
+    }
+
     /// Returns all (alias -> url) pairs
     pub fn resolved(self) -> ResolvedEtherscanConfigs {
         ResolvedEtherscanConfigs {
@@ -152,7 +166,18 @@ impl EtherscanConfig {
             // also serves as the chain id
             self.chain = Chain::from_str(alias).ok();
         }
-        self.resolve()
+        self.resolve().map_err(|err| match err {
+            EtherscanConfigError::Unresolved(err) => {
+                EtherscanConfigError::Unresolved(err.with_key(format!("etherscan.{alias}.key")))
+            }
+            other => other,
+        })
+    }
+
+    /// Returns a copy of this config with the `key` replaced by a placeholder, so it can be
+    /// safely printed without leaking the underlying secret.
+    pub fn redacted(&self) -> Self {
+        Self { key: EtherscanApiKey::Key("<your api key>".to_string()), ..self.clone() }
     }
 
     /// Returns the etherscan config required to create a client
@@ -28,7 +28,7 @@ use semver::Version;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
     str::FromStr,
@@ -47,6 +47,7 @@ pub use endpoints::{ResolvedRpcEndpoints, RpcEndpoint, RpcEndpoints};
 
 mod etherscan;
 mod resolve;
+use resolve::interpolate;
 pub use resolve::UnresolvedEnvVarError;
 
 pub mod cache;
@@ -87,6 +88,9 @@ use providers::*;
 mod fuzz;
 pub use fuzz::FuzzConfig;
 
+mod coverage;
+pub use coverage::{CoverageConfig, CoverageReportKind};
+
 mod invariant;
 use crate::fs_permissions::PathPermission;
 pub use invariant::InvariantConfig;
@@ -185,6 +189,14 @@ pub struct Config {
     ///      be auto detected but if the solc version is not installed, it will _not_ try to
     ///      install it
     pub offline: bool,
+    /// Whether to additionally write compiled artifacts in the shape Hardhat expects
+    /// (`artifacts/<source>/<Contract>.json` with `abi`/`bytecode`/`deployedBytecode` at the top
+    /// level, plus a `build-info` directory), alongside the native artifacts, so tooling that
+    /// only understands the Hardhat format keeps working.
+    pub hardhat_artifacts: bool,
+    /// Whether to print a compile-time breakdown (per-solc-version file counts/durations, plus
+    /// the slowest source files) after a successful build.
+    pub timings: bool,
     /// Whether to activate optimizer
     pub optimizer: bool,
     /// Sets the optimizer runs
@@ -193,6 +205,12 @@ pub struct Config {
     /// The "enabled" switch above provides two defaults which can be
     /// tweaked here. If "details" is given, "enabled" can be omitted.
     pub optimizer_details: Option<OptimizerDetails>,
+    /// Path-glob based overrides of the solc version/optimizer runs/via_ir/evm_version settings,
+    /// see [`SettingsOverride`]. [`Config::compilation_restriction_for`] picks the override for a
+    /// source: if it matches more than one, the override with the most specific (longest) glob
+    /// wins; ties are resolved by config order and a warning is emitted. The build itself does
+    /// not read these overrides.
+    pub compilation_restrictions: Vec<SettingsOverride>,
     /// Model checker settings.
     pub model_checker: Option<ModelCheckerSettings>,
     /// verbosity to use
@@ -206,6 +224,9 @@ pub struct Config {
     pub etherscan: EtherscanConfigs,
     /// list of solidity error codes to always silence in the compiler output
     pub ignored_error_codes: Vec<SolidityErrorCode>,
+    /// Paths (relative to the project root) whose warnings are always silenced in the compiler
+    /// output, e.g. `["lib"]` to ignore warnings coming from vendored dependencies
+    pub ignore_warnings_from: Vec<String>,
     /// When true, compiler warnings are treated as errors
     pub deny_warnings: bool,
     /// Only run test functions matching the specified regex pattern.
@@ -230,6 +251,8 @@ pub struct Config {
     pub fuzz: FuzzConfig,
     /// Configuration for invariant testing
     pub invariant: InvariantConfig,
+    /// Configuration for `forge coverage`
+    pub coverage: CoverageConfig,
     /// Whether to allow ffi cheatcodes in test
     pub ffi: bool,
     /// The address which will be executing all tests
@@ -308,6 +331,12 @@ pub struct Config {
     /// Multiple rpc endpoints and their aliases
     #[serde(default, skip_serializing_if = "RpcEndpoints::is_empty")]
     pub rpc_endpoints: RpcEndpoints,
+    /// Persistent address labels applied to every run, e.g. `"0xC02a...": "WETH"`.
+    ///
+    /// These seed the trace decoder's labels; labels set at runtime via `vm.label` take
+    /// precedence over labels configured here for the same address.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<Address, String>,
     /// Whether to include the metadata hash.
     ///
     /// The metadata hash is machine dependent. By default, this is set to [BytecodeHash::None] to allow for deterministic code, See: <https://docs.soliditylang.org/en/latest/metadata.html>
@@ -374,7 +403,12 @@ pub static STANDALONE_FALLBACK_SECTIONS: Lazy<HashMap<&'static str, &'static str
 
 /// Deprecated keys.
 pub static DEPRECATIONS: Lazy<HashMap<String, String>> = Lazy::new(|| {
-    HashMap::from([("fuzz.max_global_rejects".into(), "fuzz.max_test_rejects".into())])
+    HashMap::from([
+        ("fuzz.max_global_rejects".into(), "fuzz.max_test_rejects".into()),
+        // flat keys predating the `[fuzz]` section, still accepted for backwards compatibility
+        ("fuzz_runs".into(), "fuzz.runs".into()),
+        ("fuzz_max_local_rejects".into(), "fuzz.max_test_rejects".into()),
+    ])
 });
 
 impl Config {
@@ -389,7 +423,7 @@ impl Config {
 
     /// Standalone sections in the config which get integrated into the selected profile
     pub const STANDALONE_SECTIONS: &'static [&'static str] =
-        &["rpc_endpoints", "etherscan", "fmt", "doc", "fuzz", "invariant"];
+        &["rpc_endpoints", "etherscan", "fmt", "doc", "fuzz", "invariant", "coverage"];
 
     /// File name of config toml file
     pub const FILE_NAME: &'static str = "foundry.toml";
@@ -651,8 +685,11 @@ impl Config {
                     let mut solc = Solc::find_svm_installed_version(&v)?;
                     if solc.is_none() {
                         if self.offline {
+                            let searched_dir = svm_home_dir()
+                                .map(|dir| dir.display().to_string())
+                                .unwrap_or_else(|| "<unknown svm home>".to_string());
                             return Err(SolcError::msg(format!(
-                                "can't install missing solc {version} in offline mode"
+                                "can't install missing solc {version} in offline mode, not found in {searched_dir}"
                             )))
                         }
                         Solc::blocking_install(version)?;
@@ -767,7 +804,12 @@ impl Config {
         if let Some(alias) = endpoints.remove(maybe_alias) {
             Some(alias.map(Cow::Owned))
         } else {
-            Some(Ok(Cow::Borrowed(self.eth_rpc_url.as_deref()?)))
+            let eth_rpc_url = self.eth_rpc_url.as_deref()?;
+            Some(
+                interpolate(eth_rpc_url)
+                    .map_err(|err| err.with_key("eth_rpc_url"))
+                    .map(Cow::Owned),
+            )
         }
     }
 
@@ -874,6 +916,14 @@ impl Config {
             return Ok(ResolvedEtherscanConfig::create(key, chain))
         }
 
+        // the `etherscan` table is configured but none of its entries cover the requested
+        // chain and no literal `etherscan_api_key` was provided, so report the chain as
+        // unsupported instead of silently resolving to `None`
+        if let Some(chain) = chain.filter(|_| !self.etherscan.is_empty()) {
+            let aliases = self.etherscan.keys().cloned().collect::<Vec<_>>().join(", ");
+            return Err(EtherscanConfigError::ChainNotConfigured(chain, aliases))
+        }
+
         Ok(None)
     }
 
@@ -882,6 +932,33 @@ impl Config {
         self.get_etherscan_config_with_chain(chain).ok().flatten().map(|c| c.key)
     }
 
+    /// Returns a copy of this config with all etherscan API key values masked, so it can be
+    /// safely printed (e.g. by `forge config`) without leaking the configured secrets.
+    pub fn redacted(&self) -> Self {
+        Self { etherscan: self.etherscan.redacted(), ..self.clone() }
+    }
+
+    /// Returns the set of top-level field names `Config` serializes to, as they'd appear in a
+    /// `[profile.*]` table.
+    ///
+    /// Used to flag unknown keys (most likely typos) in a `foundry.toml`, see
+    /// [`crate::providers::WarningsProvider`].
+    pub fn field_names() -> HashSet<String> {
+        let mut known: HashSet<String> = Serialized::defaults(Config::default())
+            .data()
+            .unwrap_or_default()
+            .remove(&Config::DEFAULT_PROFILE)
+            .map(|dict| dict.into_iter().map(|(key, _)| key).collect())
+            .unwrap_or_default();
+        // `__root` is `skip_serializing` (see its doc comment) but is still a valid key that can
+        // be set and deserialized from a `foundry.toml`
+        known.insert("root".to_string());
+        // `inherits` isn't a `Config` field, it's consumed while building the profile's figment,
+        // see `Config::resolve_inherits_chain`
+        known.insert("inherits".to_string());
+        known
+    }
+
     /// Returns the remapping for the project's _src_ directory
     ///
     /// **Note:** this will add an additional `<src>/=<src path>` remapping here so imports that
@@ -941,6 +1018,39 @@ impl Config {
         Libraries::parse(&self.libraries)
     }
 
+    /// Returns the [`SettingsOverride`] from `compilation_restrictions` that applies to `path`,
+    /// if any.
+    ///
+    /// If more than one override's glob matches, the one with the most specific (longest) glob
+    /// wins; if multiple matches are equally specific, a warning is emitted and the first one
+    /// (in config order) is used.
+    pub fn compilation_restriction_for(&self, path: &Path) -> Option<&SettingsOverride> {
+        let mut matches: Vec<&SettingsOverride> = self
+            .compilation_restrictions
+            .iter()
+            .filter(|over| {
+                globset::Glob::new(&over.path)
+                    .map(|glob| glob.compile_matcher().is_match(path))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        if matches.len() > 1 {
+            matches.sort_by_key(|over| std::cmp::Reverse(over.path.len()));
+            if matches[0].path.len() == matches[1].path.len() {
+                warn!(
+                    "{} matches multiple equally specific `compilation_restrictions` globs ({:?} and {:?}); using {:?}",
+                    path.display(),
+                    matches[0].path,
+                    matches[1].path,
+                    matches[0].path
+                );
+            }
+        }
+
+        matches.into_iter().next()
+    }
+
     /// Returns the configured `solc` `Settings` that includes:
     ///   - all libraries
     ///   - the optimizer (including details, if configured)
@@ -1240,6 +1350,17 @@ impl Config {
         Some(Self::foundry_block_cache_dir(chain_id, block)?.join("storage.json"))
     }
 
+    /// Returns the path to foundry's keystores dir `~/.foundry/keystores`
+    pub fn foundry_keystores_dir() -> Option<PathBuf> {
+        Some(Self::foundry_dir()?.join("keystores"))
+    }
+
+    /// Returns the path to the keystore file for the account with the given name
+    /// `~/.foundry/keystores/<name>.json`
+    pub fn foundry_keystore_file(name: impl AsRef<str>) -> Option<PathBuf> {
+        Some(Self::foundry_keystores_dir()?.join(format!("{}.json", name.as_ref())))
+    }
+
     #[doc = r#"Returns the path to `foundry`'s data directory inside the user's data directory
     |Platform | Value                                 | Example                          |
     | ------- | ------------------------------------- | -------------------------------- |
@@ -1387,16 +1508,26 @@ impl Config {
     }
 
     //The path provided to this function should point to a cached chain folder
-    fn get_cached_blocks(chain_path: &Path) -> eyre::Result<Vec<(String, u64)>> {
+    fn get_cached_blocks(chain_path: &Path) -> eyre::Result<Vec<(String, u64, u64)>> {
         let mut blocks = vec![];
         if !chain_path.exists() {
             return Ok(blocks)
         }
         for block in chain_path.read_dir()?.flatten().filter(|x| x.file_type().unwrap().is_dir()) {
             let filepath = block.path().join("storage.json");
+            let metadata = fs::metadata(&filepath)?;
+            // the cache file is rewritten every time the fork is flushed, so its mtime is a
+            // reasonable proxy for when the entry was last used
+            let last_used = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
             blocks.push((
                 block.file_name().to_string_lossy().into_owned(),
-                fs::metadata(filepath)?.len(),
+                metadata.len(),
+                last_used,
             ));
         }
         Ok(blocks)
@@ -1435,8 +1566,18 @@ impl Config {
             figment.merge(warnings)
         };
 
+        // resolve the `inherits` chain, with the default profile as the implicit, ultimate base;
+        // a cyclic chain is surfaced as a regular figment error at extraction time
+        let ancestors = match Self::resolve_inherits_chain(&toml_provider, &profile) {
+            Ok(ancestors) => ancestors,
+            Err(err) => {
+                figment = figment.merge(ErrorProvider(err));
+                vec![Config::DEFAULT_PROFILE]
+            }
+        };
+
         // use [profile.<profile>] as [<profile>]
-        let mut profiles = vec![Config::DEFAULT_PROFILE];
+        let mut profiles = ancestors.clone();
         if profile != Config::DEFAULT_PROFILE {
             profiles.push(profile.clone());
         }
@@ -1445,9 +1586,12 @@ impl Config {
         // apply any key fixes
         let provider = BackwardsCompatTomlProvider(ForcedSnakeCaseData(provider));
 
-        // merge the default profile as a base
+        // merge each ancestor in as a base, from the most distant to the nearest, so the profile
+        // itself (merged last, below) always has the final say
         if profile != Config::DEFAULT_PROFILE {
-            figment = figment.merge(provider.rename(Config::DEFAULT_PROFILE, profile.clone()));
+            for ancestor in &ancestors {
+                figment = figment.merge(provider.rename(ancestor.clone(), profile.clone()));
+            }
         }
         // merge special keys into config
         for standalone_key in Config::STANDALONE_SECTIONS {
@@ -1465,6 +1609,56 @@ impl Config {
         figment = figment.merge(provider);
         figment
     }
+
+    /// Resolves the `inherits` chain for `profile` against the `[profile.*]` tables of the raw
+    /// `toml_provider` data, returning the ancestor profiles ordered from the most distant to the nearest, so they
+    /// can be merged in as increasingly specific bases (see [`Self::merge_toml_provider`]).
+    ///
+    /// [`Config::DEFAULT_PROFILE`] is always the first, ultimate ancestor, regardless of whether
+    /// it's named explicitly via `inherits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `inherits` chain starting at `profile` cycles back on itself.
+    fn resolve_inherits_chain(
+        toml_provider: &impl Provider,
+        profile: &Profile,
+    ) -> Result<Vec<Profile>, Error> {
+        let data = toml_provider.data().unwrap_or_default();
+        // the profile tables live under the top-level `profile` key, e.g. `[profile.ci]`
+        let profiles = data.get(&Profile::new(Config::PROFILE_SECTION));
+
+        let mut seen = vec![profile.clone()];
+        let mut nearest_first = Vec::new();
+        let mut current = profile.clone();
+        while let Some(parent) = profiles
+            .and_then(|profiles| profiles.get(&current.to_string()))
+            .and_then(|value| value.as_dict())
+            .and_then(|dict| dict.get("inherits"))
+            .and_then(|value| match value {
+                Value::String(_, s) => Some(Profile::new(s)),
+                _ => None,
+            })
+        {
+            // reaching the default profile always terminates the chain, it's never a cycle
+            if parent == Config::DEFAULT_PROFILE {
+                break
+            }
+            if seen.contains(&parent) {
+                return Err(Error::from(format!(
+                    "cyclic `inherits` chain detected: `{current}` inherits from `{parent}`, \
+                     which is already part of the chain starting at `{profile}`"
+                )))
+            }
+            seen.push(parent.clone());
+            nearest_first.push(parent.clone());
+            current = parent;
+        }
+
+        let mut ancestors = vec![Config::DEFAULT_PROFILE];
+        ancestors.extend(nearest_first.into_iter().rev());
+        Ok(ancestors)
+    }
 }
 
 impl From<Config> for Figment {
@@ -1689,9 +1883,12 @@ impl Default for Config {
             solc: None,
             auto_detect_solc: true,
             offline: false,
+            hardhat_artifacts: false,
+            timings: false,
             optimizer: true,
             optimizer_runs: 200,
             optimizer_details: None,
+            compilation_restrictions: vec![],
             model_checker: None,
             extra_output: Default::default(),
             extra_output_files: Default::default(),
@@ -1705,6 +1902,7 @@ impl Default for Config {
             path_pattern_inverse: None,
             fuzz: Default::default(),
             invariant: Default::default(),
+            coverage: Default::default(),
             ffi: false,
             sender: Config::DEFAULT_SENDER,
             tx_origin: Config::DEFAULT_SENDER,
@@ -1732,10 +1930,12 @@ impl Default for Config {
                 SolidityErrorCode::SpdxLicenseNotProvided,
                 SolidityErrorCode::ContractExceeds24576Bytes,
             ],
+            ignore_warnings_from: vec![],
             deny_warnings: false,
             via_ir: false,
             rpc_storage_caching: Default::default(),
             rpc_endpoints: Default::default(),
+            labels: Default::default(),
             etherscan: Default::default(),
             no_storage_caching: false,
             bytecode_hash: BytecodeHash::Ipfs,
@@ -1847,6 +2047,28 @@ impl<T: AsRef<str>> From<T> for SolcReq {
     }
 }
 
+/// A path-glob based override of select solc settings, set via
+/// `[[profile.default.compilation_restrictions]]`, so a vendored subtree can be compiled with a
+/// different solc version or optimizer settings than the rest of the project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SettingsOverride {
+    /// Glob pattern (relative to the project root) selecting which sources this override applies
+    /// to.
+    pub path: String,
+    /// Overrides the solc version used to compile the matched sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc: Option<SolcReq>,
+    /// Overrides the optimizer run count for the matched sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optimizer_runs: Option<usize>,
+    /// Overrides whether the matched sources are compiled via IR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via_ir: Option<bool>,
+    /// Overrides the EVM version targeted for the matched sources.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "from_opt_str_lowercase")]
+    pub evm_version: Option<EvmVersion>,
+}
+
 /// A convenience provider to retrieve a toml file.
 /// This will return an error if the env var is set but the file does not exist
 struct TomlFileProvider {
@@ -1962,6 +2184,26 @@ impl<P: Provider> Provider for BackwardsCompatTomlProvider<P> {
             if let Some(v) = solc_env.clone().or_else(|| dict.remove("solc_version")) {
                 dict.insert("solc".to_string(), v);
             }
+
+            // migrate flat keys that predate the `[fuzz]` section into the nested table,
+            // without overwriting an explicit `fuzz.*` value if one is already present
+            let flat_fuzz_keys =
+                [("fuzz_runs", "runs"), ("fuzz_max_local_rejects", "max_test_rejects")];
+            if flat_fuzz_keys.iter().any(|(old, _)| dict.contains_key(*old)) {
+                let mut fuzz_dict = match dict.remove("fuzz") {
+                    Some(Value::Dict(_, d)) => d,
+                    _ => Dict::new(),
+                };
+                for (old_key, new_key) in flat_fuzz_keys {
+                    if let Some(v) = dict.remove(old_key) {
+                        if !fuzz_dict.contains_key(new_key) {
+                            fuzz_dict.insert(new_key.to_string(), v);
+                        }
+                    }
+                }
+                dict.insert("fuzz".to_string(), fuzz_dict.into());
+            }
+
             map.insert(profile, dict);
         }
         Ok(map)
@@ -2306,6 +2548,19 @@ impl<P: Provider> Provider for OptionalStrictProfileProvider<P> {
     }
 }
 
+/// A provider that always fails to produce data, used to surface a pre-computed error (like a
+/// cyclic `inherits` chain) through the regular figment error-propagation path, at extraction time
+struct ErrorProvider(Error);
+
+impl Provider for ErrorProvider {
+    fn metadata(&self) -> Metadata {
+        Metadata::named("inherits")
+    }
+    fn data(&self) -> Result<Map<Profile, Dict>, Error> {
+        Err(self.0.clone())
+    }
+}
+
 trait ProviderExt: Provider {
     fn rename(
         &self,
@@ -2402,11 +2657,49 @@ pub(crate) mod from_str_lowercase {
     }
 }
 
+/// Like [`from_str_lowercase`], but for an `Option<T>` field.
+pub(crate) mod from_opt_str_lowercase {
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: std::fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.collect_str(&value.to_string().to_lowercase()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        let s: Option<String> = Option::deserialize(deserializer)?;
+        s.map(|s| s.to_lowercase().parse().map_err(serde::de::Error::custom)).transpose()
+    }
+}
+
 fn canonic(path: impl Into<PathBuf>) -> PathBuf {
     let path = path.into();
     ethers_solc::utils::canonicalize(&path).unwrap_or(path)
 }
 
+/// Returns the directory `svm` installs solc binaries into, honoring the `SVM_HOME` override it
+/// recognizes, so error messages about a missing solc version can point the user at the right
+/// place to look.
+fn svm_home_dir() -> Option<PathBuf> {
+    if let Some(home) = std::env::var_os("SVM_HOME") {
+        return Some(PathBuf::from(home))
+    }
+    dirs_next::home_dir().map(|home| home.join(".svm"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2603,6 +2896,99 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_profile_inherits() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [profile.default]
+                src = "src"
+                verbosity = 1
+
+                [profile.default.fuzz]
+                runs = 256
+
+                [profile.ci]
+                inherits = "default"
+                verbosity = 4
+
+                [profile.ci.fuzz]
+                runs = 64
+            "#,
+            )?;
+
+            jail.set_env("FOUNDRY_PROFILE", "ci");
+            let config = Config::load();
+            // inherited from `default`, not re-specified by `ci`
+            assert_eq!(config.src, PathBuf::from("src"));
+            // overridden by `ci`, including within the nested `fuzz` table
+            assert_eq!(config.verbosity, 4);
+            assert_eq!(config.fuzz.runs, 64);
+
+            // precedence: env > profile > inherited > defaults
+            jail.set_env("FOUNDRY_VERBOSITY", "5");
+            let config = Config::load();
+            assert_eq!(config.verbosity, 5);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_profile_inherits_chain() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [profile.default]
+                src = "src"
+                out = "out"
+
+                [profile.ci]
+                inherits = "default"
+                out = "ci-out"
+
+                [profile.staging]
+                inherits = "ci"
+                verbosity = 2
+            "#,
+            )?;
+
+            jail.set_env("FOUNDRY_PROFILE", "staging");
+            let config = Config::load();
+            assert_eq!(config.src, PathBuf::from("src"));
+            assert_eq!(config.out, PathBuf::from("ci-out"));
+            assert_eq!(config.verbosity, 2);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_profile_inherits_cycle_is_rejected() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [profile.default]
+
+                [profile.a]
+                inherits = "b"
+
+                [profile.b]
+                inherits = "a"
+            "#,
+            )?;
+
+            jail.set_env("FOUNDRY_PROFILE", "a");
+            let err = Config::figment().extract::<Config>().unwrap_err();
+            assert!(err.to_string().contains("cyclic"));
+
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_custom_test_path() {
         figment::Jail::expect_with(|jail| {
@@ -3203,6 +3589,35 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_conflicting_auto_detected_remappings() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [profile.default]
+                libs = ['lib', 'node_modules']
+            "#,
+            )?;
+
+            fs::create_dir_all(jail.directory().join("lib/foo/src")).unwrap();
+            fs::write(jail.directory().join("lib/foo/src/Foo.sol"), "contract Foo {}").unwrap();
+
+            fs::create_dir_all(jail.directory().join("node_modules/foo/src")).unwrap();
+            fs::write(jail.directory().join("node_modules/foo/src/Foo.sol"), "contract Foo {}")
+                .unwrap();
+
+            // `foo` is auto-detected from two unrelated (non-nested) library directories, which
+            // can't be silently resolved, so loading the config should surface that as an error
+            // rather than arbitrarily picking one.
+            let figment: Figment = Config::figment_with_root(jail.directory());
+            let remappings: Result<Vec<Remapping>, _> = figment.extract_inner("remappings");
+            assert!(remappings.is_err());
+
+            Ok(())
+        });
+    }
+
     #[test]
     fn test_load_full_toml() {
         figment::Jail::expect_with(|jail| {
@@ -3342,6 +3757,67 @@ mod tests {
         });
     }
 
+    #[test]
+    fn can_parse_compilation_restrictions() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file(
+                "foundry.toml",
+                r#"
+                [[profile.default.compilation_restrictions]]
+                path = "src/vendor/**"
+                solc = "0.7.6"
+                optimizer_runs = 1337
+                via_ir = false
+                evm_version = "byzantium"
+            "#,
+            )?;
+
+            let config = Config::load();
+            assert_eq!(
+                config.compilation_restrictions,
+                vec![SettingsOverride {
+                    path: "src/vendor/**".to_string(),
+                    solc: Some(SolcReq::Version("0.7.6".parse().unwrap())),
+                    optimizer_runs: Some(1337),
+                    via_ir: Some(false),
+                    evm_version: Some(EvmVersion::Byzantium),
+                }]
+            );
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn compilation_restriction_for_uses_most_specific_glob() {
+        let config = Config {
+            compilation_restrictions: vec![
+                SettingsOverride {
+                    path: "src/**".to_string(),
+                    solc: Some(SolcReq::Version("0.8.10".parse().unwrap())),
+                    optimizer_runs: None,
+                    via_ir: None,
+                    evm_version: None,
+                },
+                SettingsOverride {
+                    path: "src/vendor/**".to_string(),
+                    solc: Some(SolcReq::Version("0.7.6".parse().unwrap())),
+                    optimizer_runs: None,
+                    via_ir: None,
+                    evm_version: None,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let over = config.compilation_restriction_for(Path::new("src/vendor/Vendored.sol"));
+        assert_eq!(over.unwrap().solc, Some(SolcReq::Version("0.7.6".parse().unwrap())));
+
+        let over = config.compilation_restriction_for(Path::new("src/Counter.sol"));
+        assert_eq!(over.unwrap().solc, Some(SolcReq::Version("0.8.10".parse().unwrap())));
+
+        assert!(config.compilation_restriction_for(Path::new("test/Counter.t.sol")).is_none());
+    }
+
     #[test]
     fn test_toml_casing_file() {
         figment::Jail::expect_with(|jail| {
@@ -3,6 +3,7 @@ use figment::{
     value::{Dict, Map, Value},
     Error, Figment, Metadata, Profile, Provider,
 };
+use std::cmp::Ordering;
 
 pub mod remappings;
 
@@ -61,15 +62,72 @@ impl<P: Provider> WarningsProvider<P> {
                 .data()
                 .unwrap_or_default()
                 .iter()
-                .flat_map(|(profile, dict)| dict.keys().map(move |key| format!("{profile}.{key}")))
-                .filter(|k| DEPRECATIONS.contains_key(k))
-                .map(|deprecated_key| Warning::DeprecatedKey {
-                    old: deprecated_key.clone(),
-                    new: DEPRECATIONS.get(&deprecated_key).unwrap().to_string(),
-                }),
+                .flat_map(|(profile, dict)| {
+                    dict.keys().map(move |key| (format!("{profile}.{key}"), key.clone()))
+                })
+                .filter_map(|(qualified_key, bare_key)| {
+                    DEPRECATIONS
+                        .get(&qualified_key)
+                        .map(|new| (qualified_key, new))
+                        .or_else(|| DEPRECATIONS.get(&bare_key).map(|new| (bare_key, new)))
+                })
+                .map(|(old, new)| Warning::DeprecatedKey { old, new: new.to_string() }),
         );
+        // add warnings for unknown keys inside known `[profile.x]` tables
+        out.extend(self.unknown_key_warnings());
         Ok(out)
     }
+
+    /// Checks the keys of every `[profile.*]` table against `Config`'s known field names and
+    /// proposes a fuzzy-matched replacement for likely typos.
+    ///
+    /// Standalone sections like `[fuzz]`/`[etherscan]`/`[rpc_endpoints]` are intentionally not
+    /// checked here: some have their own typed shape that's already validated on deserialization,
+    /// others (like `[rpc_endpoints]`) are free-form by design, so flagging their keys would just
+    /// produce false positives.
+    fn unknown_key_warnings(&self) -> Vec<Warning> {
+        let Some(profiles) =
+            self.provider.data().unwrap_or_default().remove(&Config::PROFILE_SECTION.into())
+        else {
+            return vec![]
+        };
+        let known = Config::field_names();
+        let source = self.provider.metadata().source.map(|s| s.to_string());
+
+        profiles
+            .into_iter()
+            .filter_map(|(profile, value)| match value {
+                Value::Dict(_, dict) => Some((profile, dict)),
+                _ => None,
+            })
+            .flat_map(|(profile, dict)| {
+                let section = format!("profile.{profile}");
+                let source = source.clone();
+                dict.into_iter()
+                    .map(|(key, _)| key)
+                    .filter(|key| {
+                        !known.contains(key)
+                            && !Config::STANDALONE_SECTIONS.iter().any(|s| s == key)
+                    })
+                    .map(move |key| Warning::UnknownKey {
+                        section: section.clone(),
+                        suggestion: closest_match(&key, &known),
+                        key,
+                        source: source.clone(),
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Suggests the most similar known key for a likely-typo'd one, if any are close enough.
+fn closest_match(key: &str, known: &std::collections::HashSet<String>) -> Option<String> {
+    known
+        .iter()
+        .map(|candidate| (strsim::jaro_winkler(key, candidate), candidate))
+        .filter(|(score, _)| *score > 0.8)
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal))
+        .map(|(_, candidate)| candidate.clone())
 }
 
 impl<P: Provider> Provider for WarningsProvider<P> {
@@ -45,14 +45,34 @@ impl<'a> RemappingsProvider<'a> {
     /// - CLI parameters
     fn get_remappings(&self, remappings: Vec<Remapping>) -> Result<Vec<Remapping>, Error> {
         trace!("get all remappings from {:?}", self.root);
-        /// prioritizes remappings that are closer: shorter `path`
-        ///   - ("a", "1/2") over ("a", "1/2/3")
-        fn insert_closest(mappings: &mut HashMap<String, PathBuf>, key: String, path: PathBuf) {
-            match mappings.entry(key) {
+        /// Prioritizes remappings that are closer: shorter `path`, e.g. ("a", "1/2") over
+        /// ("a", "1/2/3"). This is the common "a lib vendors another copy of the same
+        /// dependency" case: the nested, deeper copy loses to the shallower one.
+        ///
+        /// If neither path is an ancestor of the other, this isn't a vendored-copy situation but
+        /// a genuine conflict between two unrelated directories that both want the same
+        /// remapping name, so it's recorded in `conflicts` instead of being silently guessed at.
+        fn insert_closest(
+            mappings: &mut HashMap<String, PathBuf>,
+            conflicts: &mut HashMap<String, Vec<PathBuf>>,
+            key: String,
+            path: PathBuf,
+        ) {
+            match mappings.entry(key.clone()) {
                 Entry::Occupied(mut e) => {
-                    if e.get().components().count() > path.components().count() {
+                    let existing = e.get();
+                    if existing == &path {
+                        return
+                    }
+                    if path.starts_with(existing) {
+                        // new path is nested under the existing one, keep the shallower one
+                        return
+                    }
+                    if existing.starts_with(&path) {
                         e.insert(path);
+                        return
                     }
+                    conflicts.entry(key).or_default().push(path);
                 }
                 Entry::Vacant(e) => {
                     e.insert(path);
@@ -85,9 +105,10 @@ impl<'a> RemappingsProvider<'a> {
         // scan all library dirs and autodetect remappings
         if self.auto_detect_remappings {
             let mut lib_remappings = HashMap::new();
+            let mut conflicts: HashMap<String, Vec<PathBuf>> = HashMap::new();
             // find all remappings of from libs that use a foundry.toml
             for r in self.lib_foundry_toml_remappings() {
-                insert_closest(&mut lib_remappings, r.name, r.path.into());
+                insert_closest(&mut lib_remappings, &mut conflicts, r.name, r.path.into());
             }
             // use auto detection for all libs
             for r in self
@@ -103,7 +124,11 @@ impl<'a> RemappingsProvider<'a> {
                 if ["lib/", "src/", "contracts/"].contains(&r.name.as_str()) {
                     continue
                 }
-                insert_closest(&mut lib_remappings, r.name, r.path.into());
+                insert_closest(&mut lib_remappings, &mut conflicts, r.name, r.path.into());
+            }
+
+            if !conflicts.is_empty() {
+                return Err(format_remapping_conflicts(&lib_remappings, &conflicts))
             }
 
             new_remappings.extend(
@@ -196,3 +221,33 @@ impl<'a> Provider for RemappingsProvider<'a> {
         Some(Config::selected_profile())
     }
 }
+
+/// Builds an error message for a set of auto-detected remapping conflicts: names for which two
+/// or more unrelated (non-nested) library directories want the same prefix. Since there's no
+/// correct way to silently guess which one the user meant, this suggests a `remappings.txt` that
+/// pins each conflicting name to its first-detected path so the user can adjust it if that's not
+/// the one they wanted.
+fn format_remapping_conflicts(
+    resolved: &HashMap<String, PathBuf>,
+    conflicts: &HashMap<String, Vec<PathBuf>>,
+) -> Error {
+    let mut names: Vec<_> = conflicts.keys().collect();
+    names.sort();
+
+    let mut msg = String::from(
+        "Detected conflicting auto-generated remappings - multiple unrelated library \
+         directories want the same remapping name:\n",
+    );
+    let mut suggestion = String::new();
+    for name in names {
+        let mut paths = vec![resolved[name].display().to_string()];
+        paths.extend(conflicts[name].iter().map(|p| p.display().to_string()));
+        msg.push_str(&format!("  {name} -> {}\n", paths.join(" vs ")));
+        suggestion.push_str(&format!("{name}={}\n", paths[0]));
+    }
+    msg.push_str(&format!(
+        "\nAdd explicit entries to a `remappings.txt` in the project root to resolve this, e.g.:\n\n{suggestion}"
+    ));
+
+    msg.into()
+}
@@ -4,9 +4,10 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use std::{env, env::VarError, fmt};
 
-/// A regex that matches `${val}` placeholders
-pub static RE_PLACEHOLDER: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(?m)(?P<outer>\$\{\s*(?P<inner>.*?)\s*})").unwrap());
+/// A regex that matches `${val}` or `${val:-default}` placeholders
+pub static RE_PLACEHOLDER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)(?P<outer>\$\{\s*(?P<inner>[^:}\s]+)\s*(:-(?P<default>[^}]*))?\s*})").unwrap()
+});
 
 /// Error when we failed to resolve an env var
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +16,8 @@ pub struct UnresolvedEnvVarError {
     pub unresolved: String,
     /// Var that couldn't be resolved
     pub var: String,
+    /// The config key that referenced `var`, if known
+    pub key: Option<String>,
     /// the `env::var` error
     pub source: VarError,
 }
@@ -26,15 +29,30 @@ impl UnresolvedEnvVarError {
     pub fn try_resolve(&self) -> Result<String, UnresolvedEnvVarError> {
         interpolate(&self.unresolved)
     }
+
+    /// Attaches the config key that referenced the unresolved var, so it can be named in the
+    /// error message
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
 }
 
 impl fmt::Display for UnresolvedEnvVarError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Failed to resolve env var `{}` in `{}`: {}",
-            self.var, self.unresolved, self.source
-        )
+        match &self.key {
+            Some(key) => write!(
+                f,
+                "Failed to resolve env var `{}` in `{}` referenced by config key `{}`: {}",
+                self.var, self.unresolved, key, self.source
+            ),
+            None => write!(
+                f,
+                "Failed to resolve env var `{}` in `{}`: {}",
+                self.var, self.unresolved, self.source
+            ),
+        }
     }
 }
 
@@ -44,18 +62,28 @@ impl std::error::Error for UnresolvedEnvVarError {
     }
 }
 
-/// Replaces all Env var placeholders in the input string with the values they hold
+/// Replaces all Env var placeholders in the input string with the values they hold, falling back
+/// to a `${VAR:-default}` default if `VAR` is unset
 pub fn interpolate(input: &str) -> Result<String, UnresolvedEnvVarError> {
     let mut res = input.to_string();
 
     // loop over all placeholders in the input and replace them one by one
     for caps in RE_PLACEHOLDER.captures_iter(input) {
         let var = &caps["inner"];
-        let value = env::var(var).map_err(|source| UnresolvedEnvVarError {
-            unresolved: input.to_string(),
-            var: var.to_string(),
-            source,
-        })?;
+        let value = match env::var(var) {
+            Ok(value) => value,
+            Err(source) => match caps.name("default") {
+                Some(default) => default.as_str().to_string(),
+                None => {
+                    return Err(UnresolvedEnvVarError {
+                        unresolved: input.to_string(),
+                        var: var.to_string(),
+                        key: None,
+                        source,
+                    })
+                }
+            },
+        };
 
         res = res.replacen(&caps["outer"], &value, 1);
     }
@@ -81,4 +109,33 @@ mod tests {
         assert_eq!(cap.name("outer").unwrap().as_str(), "${API_KEY}");
         assert_eq!(cap.name("inner").unwrap().as_str(), "API_KEY");
     }
+
+    #[test]
+    fn can_find_placeholder_with_default() {
+        let val = "${API_KEY:-default_key}";
+        let cap = RE_PLACEHOLDER.captures(val).unwrap();
+        assert_eq!(cap.name("inner").unwrap().as_str(), "API_KEY");
+        assert_eq!(cap.name("default").unwrap().as_str(), "default_key");
+    }
+
+    #[test]
+    fn interpolate_falls_back_to_default() {
+        std::env::remove_var("_RESOLVE_TEST_UNSET_VAR");
+        let val = interpolate("${_RESOLVE_TEST_UNSET_VAR:-fallback}").unwrap();
+        assert_eq!(val, "fallback");
+    }
+
+    #[test]
+    fn interpolate_prefers_set_var_over_default() {
+        std::env::set_var("_RESOLVE_TEST_SET_VAR", "actual");
+        let val = interpolate("${_RESOLVE_TEST_SET_VAR:-fallback}").unwrap();
+        assert_eq!(val, "actual");
+    }
+
+    #[test]
+    fn interpolate_errors_without_default_and_names_var() {
+        std::env::remove_var("_RESOLVE_TEST_UNSET_VAR_2");
+        let err = interpolate("${_RESOLVE_TEST_UNSET_VAR_2}").unwrap_err();
+        assert_eq!(err.var, "_RESOLVE_TEST_UNSET_VAR_2");
+    }
 }
@@ -46,6 +46,18 @@ pub enum Warning {
         /// is being removed completely without replacement
         new: String,
     },
+    /// A key was found in a `[profile.*]` table that doesn't match any known `Config` field,
+    /// most likely a typo
+    UnknownKey {
+        /// The table the key was found in, e.g. `profile.default`
+        section: String,
+        /// The unknown key
+        key: String,
+        /// The source where the key was found
+        source: Option<String>,
+        /// A known key that looks close enough to be what the user meant, if any
+        suggestion: Option<String>,
+    },
 }
 
 impl fmt::Display for Warning {
@@ -77,6 +89,16 @@ impl fmt::Display for Warning {
             Self::DeprecatedKey { old, new } => f.write_fmt(format_args!(
                 "Key `{old}` is being deprecated in favor of `{new}`. It will be removed in future versions.",
             )),
+            Self::UnknownKey { section, key, source, suggestion } => {
+                let source = source.as_ref().map(|src| format!(" in {src}")).unwrap_or_default();
+                let hint = suggestion
+                    .as_ref()
+                    .map(|s| format!(" Did you mean `{s}`?"))
+                    .unwrap_or_default();
+                f.write_fmt(format_args!(
+                    "Unknown key `{key}` in [{section}]{source}.{hint}"
+                ))
+            }
         }
     }
 }
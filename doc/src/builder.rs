@@ -28,6 +28,9 @@ pub struct DocBuilder {
     pub sources: PathBuf,
     /// Flag whether to build mdbook.
     pub should_build: bool,
+    /// Flag whether to only emit plain markdown, skipping the mdbook scaffolding (summary, book
+    /// config, static assets).
+    pub md: bool,
     /// Documentation configuration.
     pub config: DocConfig,
     /// The array of preprocessors to apply.
@@ -49,6 +52,7 @@ impl DocBuilder {
             root,
             sources,
             should_build: false,
+            md: false,
             config: DocConfig::default(),
             preprocessors: Default::default(),
             fmt: Default::default(),
@@ -61,6 +65,12 @@ impl DocBuilder {
         self
     }
 
+    /// Set `md` flag on the builder.
+    pub fn with_md(mut self, md: bool) -> Self {
+        self.md = md;
+        self
+    }
+
     /// Set config on the builder.
     pub fn with_config(mut self, config: DocConfig) -> Self {
         self.config = config;
@@ -230,40 +240,47 @@ impl DocBuilder {
         let out_dir_src = out_dir.join(Self::SRC);
         fs::create_dir_all(&out_dir_src)?;
 
-        // Write readme content if any
-        let readme_content = {
-            let src_readme = self.sources.join(Self::README);
-            let root_readme = self.root.join(Self::README);
-            if src_readme.exists() {
-                fs::read_to_string(src_readme)?
-            } else if root_readme.exists() {
-                fs::read_to_string(root_readme)?
-            } else {
-                String::new()
-            }
-        };
-        let readme_path = out_dir_src.join(Self::README);
-        fs::write(&readme_path, readme_content)?;
-
-        // Write summary and section readmes
-        let mut summary = BufWriter::default();
-        summary.write_title("Summary")?;
-        summary.write_link_list_item("Home", Self::README, 0)?;
-        self.write_summary_section(&mut summary, &documents.iter().collect::<Vec<_>>(), None, 0)?;
-        fs::write(out_dir_src.join(Self::SUMMARY), summary.finish())?;
+        if !self.md {
+            // Write readme content if any
+            let readme_content = {
+                let src_readme = self.sources.join(Self::README);
+                let root_readme = self.root.join(Self::README);
+                if src_readme.exists() {
+                    fs::read_to_string(src_readme)?
+                } else if root_readme.exists() {
+                    fs::read_to_string(root_readme)?
+                } else {
+                    String::new()
+                }
+            };
+            let readme_path = out_dir_src.join(Self::README);
+            fs::write(&readme_path, readme_content)?;
+
+            // Write summary and section readmes
+            let mut summary = BufWriter::default();
+            summary.write_title("Summary")?;
+            summary.write_link_list_item("Home", Self::README, 0)?;
+            self.write_summary_section(
+                &mut summary,
+                &documents.iter().collect::<Vec<_>>(),
+                None,
+                0,
+            )?;
+            fs::write(out_dir_src.join(Self::SUMMARY), summary.finish())?;
 
-        // Write solidity syntax highlighting
-        fs::write(out_dir.join("solidity.min.js"), include_str!("../static/solidity.min.js"))?;
+            // Write solidity syntax highlighting
+            fs::write(out_dir.join("solidity.min.js"), include_str!("../static/solidity.min.js"))?;
 
-        // Write css files
-        fs::write(out_dir.join("book.css"), include_str!("../static/book.css"))?;
+            // Write css files
+            fs::write(out_dir.join("book.css"), include_str!("../static/book.css"))?;
 
-        // Write book config
-        fs::write(self.out_dir().join("book.toml"), self.book_config()?)?;
+            // Write book config
+            fs::write(self.out_dir().join("book.toml"), self.book_config()?)?;
 
-        // Write .gitignore
-        let gitignore = "book/";
-        fs::write(self.out_dir().join(".gitignore"), gitignore)?;
+            // Write .gitignore
+            let gitignore = "book/";
+            fs::write(self.out_dir().join(".gitignore"), gitignore)?;
+        }
 
         // Write doc files
         for document in documents {
@@ -1,10 +1,11 @@
-use super::{CoverageItem, CoverageItemKind, ItemAnchor, SourceLocation};
+use super::{CoverageItem, CoverageItemKind, HitMap, ItemAnchor, SourceLocation};
 use crate::utils::ICPCMap;
 use ethers::prelude::{
     sourcemap::{SourceElement, SourceMap},
     Bytes,
 };
 use revm::{opcode, spec_opcode_gas, SpecId};
+use std::collections::HashMap;
 
 /// Attempts to find anchors for the given items using the given source map and bytecode.
 pub fn find_anchors(
@@ -162,6 +163,48 @@ pub fn find_anchor_branch(
     anchors.ok_or_else(|| eyre::eyre!("Could not detect branches in source: {}", loc))
 }
 
+/// Attributes the per-program-counter gas costs recorded in `hit_map` (see
+/// [HitMap::gas](super::HitMap::gas)) to the `CoverageItemKind::Function` item among `item_ids`
+/// whose source range encloses them, for `forge test --gas-report-internal`.
+///
+/// Instructions that don't fall within any function's source range (e.g. dispatcher code) are
+/// dropped. A program counter within more than one function's range (e.g. a modifier inlined into
+/// the function it modifies) is attributed to the smallest (innermost) enclosing one.
+///
+/// Returns the attributed gas keyed by index into `items`.
+pub fn attribute_gas(
+    hit_map: &HitMap,
+    source_map: &SourceMap,
+    ic_pc_map: &ICPCMap,
+    item_ids: &[usize],
+    items: &[CoverageItem],
+) -> HashMap<usize, u64> {
+    let pc_ic_map: HashMap<usize, usize> = ic_pc_map.iter().map(|(ic, pc)| (*pc, *ic)).collect();
+
+    let mut gas_by_item = HashMap::new();
+    for (&pc, &gas) in &hit_map.gas {
+        let Some(element) = pc_ic_map.get(&pc).and_then(|ic| source_map.get(*ic)) else { continue };
+
+        let enclosing_function = item_ids
+            .iter()
+            .filter_map(|&item_id| {
+                let item = items.get(item_id)?;
+                if !matches!(item.kind, CoverageItemKind::Function { .. }) {
+                    return None
+                }
+                is_in_source_range(element, &item.loc)
+                    .then_some((item_id, item.loc.length.unwrap_or(usize::MAX)))
+            })
+            .min_by_key(|(_, length)| *length)
+            .map(|(item_id, _)| item_id);
+
+        if let Some(item_id) = enclosing_function {
+            *gas_by_item.entry(item_id).or_default() += gas;
+        }
+    }
+    gas_by_item
+}
+
 /// Calculates whether `element` is within the range of the target `location`.
 fn is_in_source_range(element: &SourceElement, location: &SourceLocation) -> bool {
     let source_ids_match = element.index.map_or(false, |a| a as usize == location.source_id);
@@ -24,6 +24,10 @@ pub struct CoverageReport {
     pub items: HashMap<Version, Vec<CoverageItem>>,
     /// All item anchors for the codebase, keyed by their contract ID.
     pub anchors: HashMap<ContractId, Vec<ItemAnchor>>,
+    /// A PC -> item-ID lookup per contract, built once the first time [Self::add_hit_map] is
+    /// called for that contract and reused for every subsequent hit map, so applying a hit map
+    /// only walks the PCs it actually touched instead of every anchor in the contract.
+    pc_to_item_ids: HashMap<ContractId, BTreeMap<usize, Vec<usize>>>,
 }
 
 impl CoverageReport {
@@ -99,15 +103,27 @@ impl CoverageReport {
     /// This function should only be called *after* all the relevant sources have been processed and
     /// added to the map (see [add_source]).
     pub fn add_hit_map(&mut self, contract_id: &ContractId, hit_map: &HitMap) {
-        if let Some(anchors) = self.anchors.get(contract_id) {
+        let Some(anchors) = self.anchors.get(contract_id) else { return };
+
+        let index = self.pc_to_item_ids.entry(contract_id.clone()).or_insert_with(|| {
+            let mut index: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
             for anchor in anchors {
-                if let Some(hits) = hit_map.hits.get(&anchor.instruction) {
-                    self.items
-                        .get_mut(&contract_id.version)
-                        .and_then(|items| items.get_mut(anchor.item_id))
-                        .expect("Anchor refers to non-existent coverage item")
-                        .hits += hits;
-                }
+                index.entry(anchor.instruction).or_default().push(anchor.item_id);
+            }
+            index
+        });
+
+        let Some(items) = self.items.get_mut(&contract_id.version) else { return };
+
+        // Only walk the PCs this particular hit map actually touched, instead of every anchor in
+        // the contract, and look each one up in the shared index rather than re-scanning anchors.
+        for (pc, hits) in &hit_map.hits {
+            let Some(item_ids) = index.get(pc) else { continue };
+            for &item_id in item_ids {
+                items
+                    .get_mut(item_id)
+                    .expect("Anchor refers to non-existent coverage item")
+                    .hits += hits;
             }
         }
     }
@@ -120,11 +136,16 @@ pub struct HitMaps(pub HashMap<H256, HitMap>);
 impl HitMaps {
     pub fn merge(mut self, other: HitMaps) -> Self {
         for (code_hash, hit_map) in other.0.into_iter() {
-            if let Some(HitMap { hits: extra_hits, .. }) = self.insert(code_hash, hit_map) {
+            if let Some(HitMap { hits: extra_hits, gas: extra_gas, .. }) =
+                self.insert(code_hash, hit_map)
+            {
                 for (pc, hits) in extra_hits.into_iter() {
                     self.entry(code_hash)
                         .and_modify(|map| *map.hits.entry(pc).or_default() += hits);
                 }
+                for (pc, gas) in extra_gas.into_iter() {
+                    self.entry(code_hash).and_modify(|map| map.add_gas(pc, gas));
+                }
             }
         }
         self
@@ -152,17 +173,25 @@ impl DerefMut for HitMaps {
 pub struct HitMap {
     pub bytecode: Bytes,
     pub hits: BTreeMap<usize, u64>,
+    /// Gas consumed by the instruction at each program counter, if gas recording was enabled on
+    /// the collecting inspector. Empty otherwise (e.g. plain `forge coverage` runs).
+    pub gas: BTreeMap<usize, u64>,
 }
 
 impl HitMap {
     pub fn new(bytecode: Bytes) -> Self {
-        Self { bytecode, hits: BTreeMap::new() }
+        Self { bytecode, hits: BTreeMap::new(), gas: BTreeMap::new() }
     }
 
     /// Increase the hit counter for the given program counter.
     pub fn hit(&mut self, pc: usize) {
         *self.hits.entry(pc).or_default() += 1;
     }
+
+    /// Accumulate gas consumed by the instruction at the given program counter.
+    pub fn add_gas(&mut self, pc: usize, gas: u64) {
+        *self.gas.entry(pc).or_default() += gas;
+    }
 }
 
 /// A unique identifier for a contract
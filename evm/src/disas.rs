@@ -0,0 +1,90 @@
+//! EVM bytecode disassembly.
+//!
+//! This uses the same [`revm::OpCode`] table as the debugger (see [`crate::debug::Instruction`]),
+//! so a mnemonic printed here and one printed while stepping through a trace can never diverge.
+
+use revm::{opcode, spec_opcode_gas, OpCode, SpecId};
+
+/// A single decoded instruction from a bytecode stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassembledOp {
+    /// The program counter (byte offset into the code) of this instruction.
+    pub pc: usize,
+    /// The mnemonic, e.g. `PUSH1`, `JUMPDEST`, or `UNKNOWN(0x..)` for an opcode that isn't
+    /// defined under `spec`.
+    pub mnemonic: String,
+    /// The immediate bytes pushed onto the stack by a `PUSHn` instruction, if any.
+    pub push_data: Option<Vec<u8>>,
+}
+
+/// Disassembles `code` into one [DisassembledOp] per instruction.
+///
+/// Unknown opcodes are emitted as `UNKNOWN(0x..)` rather than causing an error, so arbitrary
+/// (including non-EVM or truncated) bytes can always be disassembled. A `PUSHn` whose data runs
+/// past the end of `code` has its `push_data` truncated to whatever bytes remain.
+pub fn disassemble(code: &[u8], spec: SpecId) -> Vec<DisassembledOp> {
+    let opcode_infos = spec_opcode_gas(spec);
+    let mut ops = Vec::new();
+    let mut pc = 0;
+
+    while pc < code.len() {
+        let op = code[pc];
+        let mnemonic = OpCode::try_from_u8(op)
+            .map(|op| op.as_str().to_string())
+            .unwrap_or_else(|| format!("UNKNOWN(0x{op:02x})"));
+
+        let push_data = if opcode_infos[op as usize].is_push() {
+            let len = (op - opcode::PUSH1 + 1) as usize;
+            let end = (pc + 1 + len).min(code.len());
+            Some(code[pc + 1..end].to_vec())
+        } else {
+            None
+        };
+
+        let consumed = 1 + push_data.as_ref().map(Vec::len).unwrap_or(0);
+        ops.push(DisassembledOp { pc, mnemonic, push_data });
+        pc += consumed;
+    }
+
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disassembles_push_and_jumpdest() {
+        // PUSH1 0x01, JUMPDEST, STOP
+        let code = [0x60, 0x01, 0x5b, 0x00];
+        let ops = disassemble(&code, SpecId::LATEST);
+
+        assert_eq!(ops.len(), 3);
+        assert_eq!(ops[0].pc, 0);
+        assert_eq!(ops[0].mnemonic, "PUSH1");
+        assert_eq!(ops[0].push_data, Some(vec![0x01]));
+        assert_eq!(ops[1].pc, 2);
+        assert_eq!(ops[1].mnemonic, "JUMPDEST");
+        assert_eq!(ops[2].pc, 3);
+        assert_eq!(ops[2].mnemonic, "STOP");
+    }
+
+    #[test]
+    fn marks_unknown_opcodes() {
+        let code = [0x0c]; // unassigned opcode
+        let ops = disassemble(&code, SpecId::LATEST);
+
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].mnemonic, "UNKNOWN(0x0c)");
+    }
+
+    #[test]
+    fn truncates_push_data_at_end_of_code() {
+        let code = [0x61, 0x01]; // PUSH2 with only one data byte available
+        let ops = disassemble(&code, SpecId::LATEST);
+
+        assert_eq!(ops.len(), 1);
+        assert_eq!(ops[0].mnemonic, "PUSH2");
+        assert_eq!(ops[0].push_data, Some(vec![0x01]));
+    }
+}
@@ -83,6 +83,11 @@ ethers::contract::abigen!(
             expectCall(address,uint256,bytes)
             getCode(string)
             getDeployedCode(string)
+            deployCode(string)(address)
+            getDeployment(string,uint256)(address)
+            computeCreate2Address(bytes32,bytes32,address)(address)
+            computeCreate2Address(bytes32,bytes32)(address)
+            setNextCreate2Salt(bytes32)
             label(address,string)
             assume(bool)
             setNonce(address,uint64)
@@ -454,6 +454,20 @@ impl Backend {
         }
     }
 
+    /// Overrides a single storage slot of an account
+    pub fn insert_account_storage(
+        &mut self,
+        address: H160,
+        slot: U256,
+        value: U256,
+    ) -> DatabaseResult<()> {
+        if let Some(db) = self.active_fork_db_mut() {
+            db.insert_account_storage(address, slot, value)
+        } else {
+            self.mem_db.insert_account_storage(address, slot, value)
+        }
+    }
+
     /// Returns all snapshots created in this backend
     pub fn snapshots(&self) -> &Snapshots<BackendSnapshot<BackendDatabaseSnapshot>> {
         &self.inner.snapshots
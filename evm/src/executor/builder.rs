@@ -47,6 +47,14 @@ impl ExecutorBuilder {
         self
     }
 
+    /// Enables or disables storage read/write recording on the tracer, for the highest
+    /// verbosity level
+    #[must_use]
+    pub fn set_show_storage(mut self, enable: bool) -> Self {
+        self.inspector_config.show_storage = enable;
+        self
+    }
+
     /// Enables or disables coverage collection
     #[must_use]
     pub fn set_coverage(mut self, enable: bool) -> Self {
@@ -136,6 +136,10 @@ where
                 let acc = self.db.accounts().read().get(&addr).cloned();
                 if let Some(basic) = acc {
                     let _ = sender.send(Ok(basic));
+                } else if self.db.is_replay() {
+                    let _ = sender.send(Err(Self::replay_miss(format!(
+                        "account info for {addr:?}"
+                    ))));
                 } else {
                     self.request_account(addr, sender);
                 }
@@ -144,15 +148,30 @@ where
                 let hash = self.db.block_hashes().read().get(&U256::from(number)).cloned();
                 if let Some(hash) = hash {
                     let _ = sender.send(Ok(hash));
+                } else if self.db.is_replay() {
+                    let _ =
+                        sender.send(Err(Self::replay_miss(format!("block hash for {number}"))));
                 } else {
                     self.request_hash(number, sender);
                 }
             }
             BackendRequest::FullBlock(number, sender) => {
-                self.request_full_block(number, sender);
+                if self.db.is_replay() {
+                    let _ = sender.send(Err(Self::replay_miss(format!(
+                        "full block {number:?} (not covered by `--fork-record` fixtures)"
+                    ))));
+                } else {
+                    self.request_full_block(number, sender);
+                }
             }
             BackendRequest::Transaction(tx, sender) => {
-                self.request_transaction(tx, sender);
+                if self.db.is_replay() {
+                    let _ = sender.send(Err(Self::replay_miss(format!(
+                        "transaction {tx:?} (not covered by `--fork-record` fixtures)"
+                    ))));
+                } else {
+                    self.request_transaction(tx, sender);
+                }
             }
             BackendRequest::Storage(addr, idx, sender) => {
                 // account is already stored in the cache
@@ -160,6 +179,9 @@ where
                     self.db.storage().read().get(&addr).and_then(|acc| acc.get(&idx).copied());
                 if let Some(value) = value {
                     let _ = sender.send(Ok(value));
+                } else if self.db.is_replay() {
+                    let _ = sender
+                        .send(Err(Self::replay_miss(format!("storage slot {idx} for {addr:?}"))));
                 } else {
                     // account present but not storage -> fetch storage
                     self.request_account_storage(addr, idx, sender);
@@ -171,6 +193,14 @@ where
         }
     }
 
+    /// Builds the error returned for a `--fork-replay` fixture miss, naming the missing key so
+    /// the fixture can be targeted for a refresh.
+    fn replay_miss(what: String) -> DatabaseError {
+        DatabaseError::msg(format!(
+            "fork replay: fixture has no recorded {what}; re-run with --fork-record to refresh it"
+        ))
+    }
+
     /// process a request for account's storage
     fn request_account_storage(&mut self, address: Address, idx: U256, listener: StorageSender) {
         match self.storage_requests.entry((address, idx)) {
@@ -592,6 +622,57 @@ impl SharedBackend {
         })
     }
 
+    /// Concurrently fetches and caches the basic account info for a batch of addresses.
+    ///
+    /// Unlike calling [`DatabaseRef::basic`] in a loop, this dispatches every request before
+    /// waiting on any of the responses, so the underlying provider sees them as a single batch of
+    /// concurrent round trips instead of `addresses.len()` consecutive ones. Requests already
+    /// in-flight or cached (e.g. because another suite queried the same address) are deduped by
+    /// the handler as usual, so pre-warming with addresses seen in earlier suites of the same run
+    /// is always safe to call speculatively.
+    pub fn prefetch_accounts(
+        &self,
+        addresses: impl IntoIterator<Item = Address>,
+    ) -> DatabaseResult<()> {
+        tokio::task::block_in_place(|| {
+            let receivers = addresses
+                .into_iter()
+                .map(|address| {
+                    let (sender, rx) = oneshot_channel();
+                    self.backend.clone().try_send(BackendRequest::Basic(address, sender))?;
+                    Ok(rx)
+                })
+                .collect::<DatabaseResult<Vec<_>>>()?;
+
+            for rx in receivers {
+                rx.recv()??;
+            }
+            Ok(())
+        })
+    }
+
+    /// Concurrently fetches and caches a batch of storage slots, see [`Self::prefetch_accounts`].
+    pub fn prefetch_storage(
+        &self,
+        slots: impl IntoIterator<Item = (Address, U256)>,
+    ) -> DatabaseResult<()> {
+        tokio::task::block_in_place(|| {
+            let receivers = slots
+                .into_iter()
+                .map(|(address, index)| {
+                    let (sender, rx) = oneshot_channel();
+                    self.backend.clone().try_send(BackendRequest::Storage(address, index, sender))?;
+                    Ok(rx)
+                })
+                .collect::<DatabaseResult<Vec<_>>>()?;
+
+            for rx in receivers {
+                rx.recv()??;
+            }
+            Ok(())
+        })
+    }
+
     fn do_get_basic(&self, address: Address) -> DatabaseResult<Option<AccountInfo>> {
         tokio::task::block_in_place(|| {
             let (sender, rx) = oneshot_channel();
@@ -670,14 +751,43 @@ mod tests {
         Backend,
     };
     use ethers::{
+        providers::{Http, JsonRpcClient, Provider},
         solc::utils::RuntimeOrHandle,
         types::{Address, Chain},
     };
     use foundry_common::get_http_provider;
     use foundry_config::Config;
-    use std::{collections::BTreeSet, path::PathBuf, sync::Arc};
+    use std::{
+        collections::BTreeSet,
+        path::PathBuf,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
     const ENDPOINT: &str = "https://mainnet.infura.io/v3/c60b0bb42f8a4c6481ecd229eddaca27";
 
+    /// A [JsonRpcClient] wrapper that counts every request forwarded to the inner transport.
+    #[derive(Debug)]
+    struct CountingClient<C> {
+        inner: C,
+        requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl<C: JsonRpcClient> JsonRpcClient for CountingClient<C> {
+        type Error = C::Error;
+
+        async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+        where
+            T: std::fmt::Debug + serde::Serialize + Send + Sync,
+            R: serde::de::DeserializeOwned,
+        {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            self.inner.request(method, params).await
+        }
+    }
+
     #[test]
     fn shared_backend() {
         let provider = get_http_provider(ENDPOINT);
@@ -747,6 +857,8 @@ mod tests {
             url: ENDPOINT.to_string(),
             env: env.clone(),
             evm_opts,
+            record_path: None,
+            replay_path: None,
         };
 
         let backend = Backend::spawn(Some(fork));
@@ -776,4 +888,81 @@ mod tests {
         assert!(db.storage().read().contains_key(&address));
         assert_eq!(db.storage().read().get(&address).unwrap().len(), num_slots as usize);
     }
+
+    // Regression test for the shared fork backend: a `Backend` is cloned once per test executor
+    // (see `MultiContractRunner::test()`), so the number of provider requests for a given account
+    // must stay bounded by the number of *unique* keys queried, not the number of clones/suites
+    // asking for them, even when those suites query concurrently.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn shared_backend_dedupes_concurrent_suite_requests() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client =
+            CountingClient { inner: Http::try_from(ENDPOINT).unwrap(), requests: requests.clone() };
+        let provider = Provider::new(client);
+
+        let meta = BlockchainDbMeta {
+            cfg_env: Default::default(),
+            block_env: Default::default(),
+            hosts: BTreeSet::from([ENDPOINT.to_string()]),
+        };
+        let db = BlockchainDb::new(meta, None);
+        let backend = SharedBackend::spawn_backend(Arc::new(provider), db, None).await;
+
+        // some rng contract from etherscan
+        let address: Address = "63091244180ae240c87d1f528f5f269134cb07b3".parse().unwrap();
+
+        // Simulate a number of test suites, each with their own cloned handle to the shared
+        // backend, all racing to read the same account concurrently.
+        let suites = 16;
+        let handles = (0..suites)
+            .map(|_| {
+                let backend = backend.clone();
+                std::thread::spawn(move || backend.basic(address).unwrap())
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let sent = requests.load(Ordering::SeqCst);
+        assert!(
+            sent < suites,
+            "request count scaled with the number of suites: {sent} requests for {suites} suites"
+        );
+    }
+
+    // `prefetch_storage` should dispatch every slot up front rather than waiting on each one in
+    // turn: round trips still equal the number of distinct slots (no extra or missing requests),
+    // but they're issued as one overlapping batch instead of `slots` sequential request/response
+    // pairs.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn prefetch_storage_batches_distinct_slots() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client =
+            CountingClient { inner: Http::try_from(ENDPOINT).unwrap(), requests: requests.clone() };
+        let provider = Provider::new(client);
+
+        let meta = BlockchainDbMeta {
+            cfg_env: Default::default(),
+            block_env: Default::default(),
+            hosts: BTreeSet::from([ENDPOINT.to_string()]),
+        };
+        let db = BlockchainDb::new(meta, None);
+        let backend = SharedBackend::spawn_backend(Arc::new(provider), db.clone(), None).await;
+
+        let address: Address = "63091244180ae240c87d1f528f5f269134cb07b3".parse().unwrap();
+        let num_slots = 10u64;
+        let slots = (0..num_slots).map(|idx| (address, U256::from(idx)));
+
+        backend.prefetch_storage(slots).unwrap();
+
+        assert_eq!(requests.load(Ordering::SeqCst), num_slots as usize);
+        assert_eq!(db.storage().read().get(&address).unwrap().len(), num_slots as usize);
+
+        // Querying the same slots again must hit the warmed cache, not the provider.
+        for idx in 0..num_slots {
+            let _ = backend.storage(address, idx.into());
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), num_slots as usize);
+    }
 }
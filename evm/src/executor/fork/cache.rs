@@ -19,6 +19,9 @@ pub struct BlockchainDb {
     meta: Arc<RwLock<BlockchainDbMeta>>,
     /// the cache that can be flushed
     cache: Arc<JsonBlockCacheDB>,
+    /// If `true`, a cache miss must be treated as a hard error instead of triggering a live RPC
+    /// fetch; set by [`BlockchainDb::new_replay`] for `--fork-replay` runs.
+    replay: bool,
 }
 
 impl BlockchainDb {
@@ -73,7 +76,43 @@ impl BlockchainDb {
             })
             .unwrap_or_else(|| JsonBlockCacheDB::new(Arc::new(RwLock::new(meta)), cache_path));
 
-        Self { db: Arc::clone(cache.db()), meta: Arc::clone(cache.meta()), cache: Arc::new(cache) }
+        Self {
+            db: Arc::clone(cache.db()),
+            meta: Arc::clone(cache.meta()),
+            cache: Arc::new(cache),
+            replay: false,
+        }
+    }
+
+    /// Creates a new instance of the [BlockchainDb] that serves exclusively from `cache_path`,
+    /// used for `--fork-replay`.
+    ///
+    /// Unlike [`BlockchainDb::new`], this requires the fixture at `cache_path` to already exist
+    /// and load successfully - an empty db would silently turn every lookup into a replay miss
+    /// instead of failing fast on a simple typo. The fixture's own metadata is authoritative over
+    /// `meta`, since it's what the recording was made with; a mismatch is only logged.
+    ///
+    /// Callers must check [`BlockchainDb::is_replay`] and reject any request for data the fixture
+    /// doesn't contain, rather than silently fetching it live.
+    pub fn new_replay(meta: BlockchainDbMeta, cache_path: PathBuf) -> eyre::Result<Self> {
+        let cache = JsonBlockCacheDB::load(&cache_path).map_err(|err| {
+            eyre::eyre!("failed to load fork replay fixture from {}: {}", cache_path.display(), err)
+        })?;
+        if meta != *cache.meta().read() {
+            warn!(target: "cache", "fork replay fixture metadata does not match the requested fork; using the fixture's own metadata");
+        }
+        Ok(Self {
+            db: Arc::clone(cache.db()),
+            meta: Arc::clone(cache.meta()),
+            cache: Arc::new(cache),
+            replay: true,
+        })
+    }
+
+    /// Returns `true` if this db must be served exclusively from its cache, as set by
+    /// [`BlockchainDb::new_replay`]
+    pub fn is_replay(&self) -> bool {
+        self.replay
     }
 
     /// Returns the map that holds the account related info
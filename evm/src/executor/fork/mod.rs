@@ -4,6 +4,7 @@ use super::opts::EvmOpts;
 pub use backend::{BackendHandler, SharedBackend};
 
 use revm::Env;
+use std::path::PathBuf;
 
 mod init;
 pub use init::environment;
@@ -27,4 +28,11 @@ pub struct CreateFork {
     pub env: Env,
     /// All env settings as configured by the user
     pub evm_opts: EvmOpts,
+    /// If set, every value fetched for this fork is additionally written to `<PATH>/storage.json`
+    /// so the run can be replayed later via `replay_path`
+    pub record_path: Option<PathBuf>,
+    /// If set, this fork is served exclusively from the `<PATH>/storage.json` fixture written by
+    /// `record_path`, instead of `url`; requests for data missing from the fixture are a hard
+    /// error instead of falling back to the live endpoint
+    pub replay_path: Option<PathBuf>,
 }
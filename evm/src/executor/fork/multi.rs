@@ -11,6 +11,7 @@ use ethers::{
     providers::{Http, Provider, RetryClient},
     types::{BlockId, BlockNumber},
 };
+use foundry_common::ProviderBuilder;
 use foundry_config::Config;
 use futures::{
     channel::mpsc::{channel, Receiver, Sender},
@@ -477,23 +478,38 @@ async fn create_fork(
     retries: u32,
     backoff: u64,
 ) -> eyre::Result<(CreatedFork, Handler)> {
-    let provider =
-        Arc::new(Provider::<RetryClient<Http>>::new_client(fork.url.as_str(), retries, backoff)?);
+    let mut provider = ProviderBuilder::new(fork.url.as_str())
+        .max_retry(fork.evm_opts.fork_retries.unwrap_or(retries))
+        .initial_backoff(fork.evm_opts.fork_retry_backoff.unwrap_or(backoff));
+    if let Some(cups) = fork.evm_opts.compute_units_per_second {
+        provider = provider.compute_units_per_second(cups);
+    }
+    let provider = Arc::new(provider.build()?);
 
-    // initialise the fork environment
-    fork.env = fork.evm_opts.fork_evm_env(&fork.url).await?;
+    // initialise the fork environment: from the `--fork-replay` fixture if set, without any
+    // network requests, otherwise from the live endpoint as usual
+    fork.env = if let Some(replay_path) = &fork.replay_path {
+        fork.evm_opts.replay_evm_env(replay_path)?
+    } else {
+        fork.evm_opts.fork_evm_env(&fork.url).await?
+    };
 
     let meta = BlockchainDbMeta::new(fork.env.clone(), fork.url.clone());
     let number = meta.block_env.number.as_u64();
 
-    // determine the cache path if caching is enabled
-    let cache_path = if fork.enable_caching {
-        Config::foundry_block_cache_dir(meta.cfg_env.chain_id.as_u64(), number)
+    let db = if let Some(replay_path) = &fork.replay_path {
+        BlockchainDb::new_replay(meta, replay_path.join("storage.json"))?
     } else {
-        None
+        // determine the cache path if caching is enabled
+        let cache_path = if let Some(record_path) = &fork.record_path {
+            Some(record_path.join("storage.json"))
+        } else if fork.enable_caching {
+            Config::foundry_block_cache_dir(meta.cfg_env.chain_id.as_u64(), number)
+        } else {
+            None
+        };
+        BlockchainDb::new(meta, cache_path)
     };
-
-    let db = BlockchainDb::new(meta, cache_path);
     let (backend, handler) =
         SharedBackend::new(provider, db, Some(BlockId::Number(BlockNumber::Number(number.into()))));
     let fork = CreatedFork::new(fork, backend);
@@ -27,6 +27,9 @@ pub struct CheatsConfig {
     pub fs_permissions: FsPermissions,
     /// Project root
     pub root: PathBuf,
+    /// Directory broadcast (deployment) journals are persisted to, used by cheatcodes like
+    /// `getDeployment` that read back previous runs' deployed addresses
+    pub broadcast: PathBuf,
     /// Paths (directories) where file reading/writing is allowed
     pub allowed_paths: Vec<PathBuf>,
     /// How the evm was configured by the user
@@ -52,6 +55,7 @@ impl CheatsConfig {
             paths: config.project_paths(),
             fs_permissions: config.fs_permissions.clone().joined(&config.__root),
             root: config.__root.0.clone(),
+            broadcast: config.broadcast.clone(),
             allowed_paths,
             evm_opts: evm_opts.clone(),
         }
@@ -162,6 +166,7 @@ impl Default for CheatsConfig {
             paths: ProjectPathsConfig::builder().build_with_root("./"),
             fs_permissions: Default::default(),
             root: Default::default(),
+            broadcast: Default::default(),
             allowed_paths: vec![],
             evm_opts: Default::default(),
         }
@@ -251,6 +251,32 @@ pub fn apply<DB: DatabaseExt>(
             data.journaled_state.set_code(inner.0, Bytecode::new_raw(code.0).to_checked());
             Bytes::new()
         }
+        HEVMCalls::DeployCode(inner) => {
+            let bytecode = super::ext::read_bytecode(state, &inner.0)?
+                .into_deployed_bytecode()
+                .ok_or_else(|| {
+                    "No bytecode for contract. Is it abstract or unlinked?".to_string().encode()
+                })?;
+
+            // mirrors a plain `CREATE`: the new contract's address is derived from the caller's
+            // current nonce, which is then incremented, same as `process_create` does for a
+            // broadcasted deployment
+            let nonce = with_journaled_account(&mut data.journaled_state, data.db, caller, |account| {
+                let nonce = account.info.nonce;
+                account.info.nonce += 1;
+                nonce
+            })
+            .map_err(|err| err.encode_string())?;
+            let address = ethers::utils::get_contract_address(caller, nonce);
+
+            // unlike a real `CREATE`, the artifact's constructor is not executed - its already
+            // compiled deployed bytecode is installed directly, so constructor side effects
+            // (immutables, initial storage writes) are not reproduced
+            data.journaled_state.load_account(address, data.db).map_err(|err| err.encode_string())?;
+            data.journaled_state.set_code(address, Bytecode::new_raw(bytecode.0).to_checked());
+
+            address.encode().into()
+        }
         HEVMCalls::Deal(inner) => {
             let who = inner.0;
             let value = inner.1;
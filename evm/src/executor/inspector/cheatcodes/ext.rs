@@ -12,7 +12,7 @@ use ethers::{
     prelude::artifacts::CompactContractBytecode,
     types::*,
 };
-use foundry_common::{fmt::*, fs, get_artifact_path};
+use foundry_common::{find_close_artifacts, fmt::*, fs, get_artifact_path};
 use foundry_config::fs_permissions::FsAccessKind;
 use hex::FromHex;
 use jsonpath_lib;
@@ -68,7 +68,7 @@ fn ffi(state: &Cheatcodes, args: &[String]) -> Result<Bytes, Bytes> {
 #[derive(Deserialize)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
-enum ArtifactBytecode {
+pub(super) enum ArtifactBytecode {
     Hardhat(HardhatArtifact),
     Solc(JsonAbi),
     Forge(CompactContractBytecode),
@@ -76,7 +76,7 @@ enum ArtifactBytecode {
 }
 
 impl ArtifactBytecode {
-    fn into_bytecode(self) -> Option<ethers::types::Bytes> {
+    pub(super) fn into_bytecode(self) -> Option<ethers::types::Bytes> {
         match self {
             ArtifactBytecode::Hardhat(inner) => Some(inner.bytecode),
             ArtifactBytecode::Forge(inner) => {
@@ -87,7 +87,7 @@ impl ArtifactBytecode {
         }
     }
 
-    fn into_deployed_bytecode(self) -> Option<ethers::types::Bytes> {
+    pub(super) fn into_deployed_bytecode(self) -> Option<ethers::types::Bytes> {
         match self {
             ArtifactBytecode::Hardhat(inner) => Some(inner.deployed_bytecode),
             ArtifactBytecode::Forge(inner) => inner.deployed_bytecode.and_then(|bytecode| {
@@ -139,15 +139,112 @@ fn get_deployed_code(state: &Cheatcodes, path: &str) -> Result<Bytes, Bytes> {
 }
 
 /// Reads the bytecode object(s) from the matching artifact
-fn read_bytecode(state: &Cheatcodes, path: &str) -> Result<ArtifactBytecode, Bytes> {
-    let path = get_artifact_path(&state.config.paths, path);
-    let path =
-        state.config.ensure_path_allowed(path, FsAccessKind::Read).map_err(error::encode_error)?;
+///
+/// `path` may be a bare filename (`MyContract.sol`), `<file>:<contract>`, or
+/// `<file>:<contract>:<version>` to disambiguate contracts compiled by more than one solc
+/// version, as well as an explicit path to a JSON artifact.
+pub(super) fn read_bytecode(state: &Cheatcodes, path: &str) -> Result<ArtifactBytecode, Bytes> {
+    let artifact_path = get_artifact_path(&state.config.paths, path);
+    let artifact_path = state
+        .config
+        .ensure_path_allowed(artifact_path, FsAccessKind::Read)
+        .map_err(error::encode_error)?;
 
-    let data = fs::read_to_string(path).map_err(error::encode_error)?;
+    let data = fs::read_to_string(&artifact_path).map_err(|err| {
+        let suggestions = find_close_artifacts(&state.config.paths, path);
+        if suggestions.is_empty() {
+            error::encode_error(err)
+        } else {
+            error::encode_error(format!(
+                "{err}\nDid you mean one of these?\n{}",
+                suggestions.join("\n")
+            ))
+        }
+    })?;
     serde_json::from_str::<ArtifactBytecode>(&data).map_err(error::encode_error)
 }
 
+/// The newest `forge script` broadcast journal schema version this cheatcode understands. Keep in
+/// sync with `cli`'s `SCRIPT_SEQUENCE_VERSION` - this crate can't depend on the CLI's
+/// `ScriptSequence` type directly, so it trusts this versioned, stable subset of the format
+/// instead.
+const MAX_SUPPORTED_BROADCAST_VERSION: u32 = 1;
+
+/// Minimal, version-gated view of a `broadcast/**/<sig>-latest.json` journal - just enough to look
+/// up a deployed contract's address by name.
+#[derive(Deserialize)]
+struct BroadcastJournal {
+    #[serde(default)]
+    version: u32,
+    transactions: Vec<BroadcastedTransaction>,
+}
+
+#[derive(Deserialize)]
+struct BroadcastedTransaction {
+    #[serde(default)]
+    contract_name: Option<String>,
+    #[serde(default)]
+    contract_address: Option<Address>,
+}
+
+/// Returns the address a contract named `name` was deployed to on `chain_id`, by scanning the
+/// persisted broadcast journals under the project's `broadcast/` directory.
+fn get_deployment(state: &Cheatcodes, name: &str, chain_id: U256) -> Result<Bytes, Bytes> {
+    let broadcast_dir = state
+        .config
+        .ensure_path_allowed(&state.config.broadcast, FsAccessKind::Read)
+        .map_err(error::encode_error)?;
+    let chain_id = chain_id.to_string();
+
+    let mut searched = Vec::new();
+    for path in fs::json_files(&broadcast_dir) {
+        let is_latest_journal =
+            path.file_name().and_then(|f| f.to_str()).unwrap_or_default().ends_with("-latest.json");
+        let is_right_chain = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|f| f.to_str())
+            .map(|f| f == chain_id)
+            .unwrap_or_default();
+        if !is_latest_journal || !is_right_chain {
+            continue
+        }
+        searched.push(path.display().to_string());
+
+        let journal = match fs::read_json_file::<BroadcastJournal>(&path) {
+            Ok(journal) => journal,
+            Err(_) => continue,
+        };
+        if journal.version > MAX_SUPPORTED_BROADCAST_VERSION {
+            return Err(format!(
+                "Broadcast journal `{}` was written with a newer schema (version {}) than this \
+                 version of forge understands (version {MAX_SUPPORTED_BROADCAST_VERSION}). Please \
+                 upgrade foundry.",
+                path.display(),
+                journal.version
+            )
+            .encode()
+            .into())
+        }
+
+        if let Some(address) = journal
+            .transactions
+            .iter()
+            .find(|tx| tx.contract_name.as_deref() == Some(name))
+            .and_then(|tx| tx.contract_address)
+        {
+            return Ok(abi::encode(&[Token::Address(address)]).into())
+        }
+    }
+
+    Err(format!(
+        "No broadcast deployment found for contract `{name}` on chain {chain_id}. Searched: [{}]",
+        searched.join(", ")
+    )
+    .encode()
+    .into())
+}
+
 fn set_env(key: &str, val: &str) -> Result<Bytes, Bytes> {
     // `std::env::set_var` may panic in the following situations
     // ref: https://doc.rust-lang.org/std/env/fn.set_var.html
@@ -543,6 +640,7 @@ pub fn apply(
         }
         HEVMCalls::GetCode(inner) => get_code(state, &inner.0),
         HEVMCalls::GetDeployedCode(inner) => get_deployed_code(state, &inner.0),
+        HEVMCalls::GetDeployment(inner) => get_deployment(state, &inner.0, inner.1),
         HEVMCalls::SetEnv(inner) => set_env(&inner.0, &inner.1),
         HEVMCalls::EnvBool0(inner) => get_env(&inner.0, ParamType::Bool, None, None),
         HEVMCalls::EnvUint0(inner) => get_env(&inner.0, ParamType::Uint(256), None, None),
@@ -247,6 +247,10 @@ fn create_fork_request<DB: DatabaseExt>(
         url,
         env: data.env.clone(),
         evm_opts,
+        // `vm.createFork`/`vm.createSelectFork` always talk to a live endpoint; `--fork-record`
+        // and `--fork-replay` only apply to the fork created from `--fork-url`.
+        record_path: None,
+        replay_path: None,
     };
     Ok(fork)
 }
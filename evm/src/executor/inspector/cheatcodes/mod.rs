@@ -124,6 +124,11 @@ pub struct Cheatcodes {
     /// Current broadcasting information
     pub broadcast: Option<Broadcast>,
 
+    /// Salt set via `setNextCreate2Salt` for the next plain `CREATE` performed under a
+    /// broadcast, routing it through [`util::DEFAULT_CREATE2_DEPLOYER`] like a native
+    /// `new Contract{salt: ...}()` would. Cleared once consumed.
+    pub next_create2_salt: Option<H256>,
+
     /// Used to correct the nonce of --sender after the initiating call. For more, check
     /// `docs/scripting`.
     pub corrected_nonce: bool,
@@ -632,13 +637,27 @@ where
 
                 data.env.tx.caller = broadcast.new_origin;
 
+                // If a salt was set via `setNextCreate2Salt`, route this plain CREATE through
+                // the deterministic deployer exactly like a native `new Contract{salt: ...}()`
+                // would.
+                if matches!(call.scheme, revm::CreateScheme::Create) {
+                    if let Some(salt) = std::mem::take(&mut self.next_create2_salt) {
+                        call.scheme = revm::CreateScheme::Create2 {
+                            salt: U256::from_big_endian(salt.as_bytes()),
+                        };
+                    }
+                }
+
                 let (bytecode, to, nonce) = match process_create(
                     broadcast.new_origin,
                     call.init_code.clone(),
                     data,
                     call,
                 ) {
-                    Ok(val) => val,
+                    Ok(Some(val)) => val,
+                    // Nothing to broadcast: a contract is already deployed at the predicted
+                    // CREATE2 address, so we let the local call proceed without recording it.
+                    Ok(None) => return (Return::Continue, None, Gas::new(call.gas_limit), Bytes::new()),
                     Err(err) => {
                         return (Return::Revert, None, Gas::new(call.gas_limit), err.encode_string())
                     }
@@ -116,6 +116,11 @@ fn derive_key(mnemonic: &str, path: &str, index: u32) -> Result<Bytes, Bytes> {
     Ok(private_key.encode().into())
 }
 
+fn compute_create2_address(salt: H256, init_code_hash: H256, deployer: Address) -> Bytes {
+    let address = utils::get_create2_address_from_hash(deployer, salt, init_code_hash);
+    address.encode().into()
+}
+
 fn remember_key(state: &mut Cheatcodes, private_key: U256, chain_id: U256) -> Result<Bytes, Bytes> {
     let key = parse_private_key(private_key)?;
     let wallet = LocalWallet::from(key).with_chain_id(chain_id.as_u64());
@@ -147,6 +152,16 @@ pub fn apply<DB: Database>(
         }
         HEVMCalls::DeriveKey1(inner) => derive_key(&inner.0, &inner.1, inner.2),
         HEVMCalls::RememberKey(inner) => remember_key(state, inner.0, data.env.cfg.chain_id),
+        HEVMCalls::ComputeCreate2Address0(inner) => {
+            Ok(compute_create2_address(inner.0, inner.1, inner.2))
+        }
+        HEVMCalls::ComputeCreate2Address1(inner) => {
+            Ok(compute_create2_address(inner.0, inner.1, DEFAULT_CREATE2_DEPLOYER))
+        }
+        HEVMCalls::SetNextCreate2Salt(inner) => {
+            state.next_create2_salt = Some(inner.0);
+            Ok(Bytes::new())
+        }
         HEVMCalls::Label(inner) => {
             state.labels.insert(inner.0, inner.1.clone());
             Ok(Bytes::new())
@@ -179,12 +194,27 @@ pub fn apply<DB: Database>(
     })
 }
 
+/// Returns whether a contract is already deployed at `addr`, checking both the journal's local
+/// view and, for forked backends, the underlying code hash -- mirroring the deployer sanity
+/// check below.
+fn is_contract_deployed<DB>(data: &mut EVMData<'_, DB>, addr: Address) -> DatabaseResult<bool>
+where
+    DB: Database<Error = DatabaseError>,
+{
+    data.journaled_state.load_account(addr, data.db)?;
+    let info = &data.journaled_state.account(addr).info;
+    Ok(match &info.code {
+        Some(code) => !code.is_empty(),
+        None => !data.db.code_by_hash(info.code_hash)?.is_empty(),
+    })
+}
+
 pub fn process_create<DB>(
     broadcast_sender: Address,
     bytecode: Bytes,
     data: &mut EVMData<'_, DB>,
     call: &mut CreateInputs,
-) -> DatabaseResult<(Bytes, Option<NameOrAddress>, u64)>
+) -> DatabaseResult<Option<(Bytes, Option<NameOrAddress>, u64)>>
 where
     DB: Database<Error = DatabaseError>,
 {
@@ -192,7 +222,7 @@ where
         revm::CreateScheme::Create => {
             call.caller = broadcast_sender;
 
-            Ok((bytecode, None, data.journaled_state.account(broadcast_sender).info.nonce))
+            Ok(Some((bytecode, None, data.journaled_state.account(broadcast_sender).info.nonce)))
         }
         revm::CreateScheme::Create2 { salt } => {
             // Sanity checks for our CREATE2 deployer
@@ -215,6 +245,23 @@ where
                 }
             }
 
+            let mut salt_bytes = [0u8; 32];
+            salt.to_big_endian(&mut salt_bytes);
+            let init_code_hash = H256::from(utils::keccak256(&bytecode));
+            let predicted = utils::get_create2_address_from_hash(
+                DEFAULT_CREATE2_DEPLOYER,
+                H256::from(salt_bytes),
+                init_code_hash,
+            );
+
+            if is_contract_deployed(data, predicted)? {
+                println!(
+                    "Skipping CREATE2 deployment: a contract is already deployed at {predicted:?}"
+                );
+                return Ok(None)
+            }
+            println!("Predicted CREATE2 address: {predicted:?}");
+
             call.caller = DEFAULT_CREATE2_DEPLOYER;
 
             // We have to increment the nonce of the user address, since this create2 will be done
@@ -225,12 +272,14 @@ where
 
             // Proxy deployer requires the data to be on the following format `salt.init_code`
             let mut calldata = BytesMut::with_capacity(32 + bytecode.len());
-            let mut salt_bytes = [0u8; 32];
-            salt.to_big_endian(&mut salt_bytes);
             calldata.put_slice(&salt_bytes);
             calldata.put(bytecode);
 
-            Ok((calldata.freeze(), Some(NameOrAddress::Address(DEFAULT_CREATE2_DEPLOYER)), nonce))
+            Ok(Some((
+                calldata.freeze(),
+                Some(NameOrAddress::Address(DEFAULT_CREATE2_DEPLOYER)),
+                nonce,
+            )))
         }
     }
 }
@@ -1,11 +1,32 @@
 use crate::coverage::{HitMap, HitMaps};
 use bytes::Bytes;
-use revm::{Database, EVMData, Inspector, Interpreter, Return};
+use ethers::types::H256;
+use revm::{Database, EVMData, GasInspector, Inspector, Interpreter, Return};
+use std::{cell::RefCell, rc::Rc};
 
 #[derive(Default, Debug)]
 pub struct CoverageCollector {
     /// Maps that track instruction hit data.
     pub maps: HitMaps,
+    /// Used to look up the gas cost of each instruction, if set.
+    gas_inspector: Option<Rc<RefCell<GasInspector>>>,
+    /// The (bytecode hash, program counter, gas remaining before the instruction) of the step
+    /// that just ran, recorded in `step` and consumed in `step_end`.
+    pending_step: Option<(H256, usize, u64)>,
+}
+
+impl CoverageCollector {
+    /// Additionally records per-instruction gas costs into the collected [HitMap]s, so that
+    /// `forge test --gas-report-internal` can attribute gas to the Solidity function a program
+    /// counter belongs to.
+    ///
+    /// Gas Inspector should be called externally **before** [CoverageCollector], this is why we
+    /// need it as `Rc<RefCell<_>>` here (mirrors [super::Tracer::with_steps_recording]).
+    #[must_use]
+    pub fn with_gas_recording(mut self, gas_inspector: Rc<RefCell<GasInspector>>) -> Self {
+        self.gas_inspector = Some(gas_inspector);
+        self
+    }
 }
 
 impl<DB> Inspector<DB> for CoverageCollector
@@ -33,10 +54,32 @@ where
         _: &mut EVMData<'_, DB>,
         _is_static: bool,
     ) -> Return {
-        self.maps
-            .entry(interpreter.contract.bytecode.hash())
-            .and_modify(|map| map.hit(interpreter.program_counter()));
+        let code_hash = interpreter.contract.bytecode.hash();
+        let pc = interpreter.program_counter();
+
+        self.maps.entry(code_hash).and_modify(|map| map.hit(pc));
+
+        if let Some(gas_inspector) = &self.gas_inspector {
+            self.pending_step = Some((code_hash, pc, gas_inspector.borrow().gas_remaining()));
+        }
 
         Return::Continue
     }
+
+    fn step_end(
+        &mut self,
+        _interpreter: &mut Interpreter,
+        _: &mut EVMData<'_, DB>,
+        _is_static: bool,
+        status: Return,
+    ) -> Return {
+        if let (Some(gas_inspector), Some((code_hash, pc, gas_before))) =
+            (&self.gas_inspector, self.pending_step.take())
+        {
+            let gas_cost = gas_before.saturating_sub(gas_inspector.borrow().gas_remaining());
+            self.maps.entry(code_hash).and_modify(|map| map.add_gas(pc, gas_cost));
+        }
+
+        status
+    }
 }
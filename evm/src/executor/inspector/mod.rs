@@ -54,6 +54,11 @@ pub struct InspectorStackConfig {
     pub gas_price: U256,
     /// Whether tracing is enabled
     pub tracing: bool,
+    /// Whether to record storage reads and writes for each call frame while tracing.
+    ///
+    /// Requires `tracing` to be enabled as well. Disabled by default since it forces the
+    /// tracer to record every step, which is otherwise only needed by the debugger.
+    pub show_storage: bool,
     /// Whether the debugger is enabled
     pub debugger: bool,
     /// The fuzzer inspector and its state, if it exists.
@@ -83,18 +88,32 @@ impl InspectorStackConfig {
             cheatcodes.gas_price = Some(self.gas_price);
         }
 
-        if self.tracing {
-            stack.tracer = Some(Tracer::default());
-        }
-        if self.debugger {
+        if self.tracing || self.debugger {
             let gas_inspector = Rc::new(RefCell::new(GasInspector::default()));
             stack.gas = Some(gas_inspector.clone());
-            stack.debugger = Some(Debugger::new(gas_inspector));
+
+            if self.tracing {
+                let mut tracer = Tracer::default();
+                if self.show_storage {
+                    tracer = tracer.with_steps_recording(gas_inspector.clone());
+                }
+                stack.tracer = Some(tracer);
+            }
+
+            if self.debugger {
+                stack.debugger = Some(Debugger::new(gas_inspector));
+            }
         }
         stack.fuzzer = self.fuzzer.clone();
 
         if self.coverage {
-            stack.coverage = Some(CoverageCollector::default());
+            let mut coverage = CoverageCollector::default();
+            // Reuse the gas inspector set up for tracing/the debugger above, if any, so
+            // `--gas-report-internal` gets per-instruction gas costs for free when tracing is on.
+            if let Some(gas_inspector) = stack.gas.clone() {
+                coverage = coverage.with_gas_recording(gas_inspector);
+            }
+            stack.coverage = Some(coverage);
         }
 
         if self.trace_printer {
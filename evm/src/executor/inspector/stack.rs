@@ -217,6 +217,7 @@ where
                 &mut self.gas.as_deref().map(|gas| gas.borrow_mut()),
                 &mut self.debugger,
                 &mut self.tracer,
+                &mut self.coverage,
                 &mut self.logs,
                 &mut self.cheatcodes,
                 &mut self.printer,
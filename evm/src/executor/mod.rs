@@ -184,6 +184,28 @@ impl Executor {
         Ok(self)
     }
 
+    /// Set the code of an account.
+    pub fn set_code(&mut self, address: Address, code: Bytes) -> DatabaseResult<&mut Self> {
+        let mut account = self.backend_mut().basic(address)?.unwrap_or_default();
+        let code = Bytecode::new_raw(code).to_checked();
+        account.code_hash = code.hash();
+        account.code = Some(code);
+
+        self.backend_mut().insert_account_info(address, account);
+        Ok(self)
+    }
+
+    /// Set a storage slot of an account.
+    pub fn set_storage(
+        &mut self,
+        address: Address,
+        slot: U256,
+        value: U256,
+    ) -> DatabaseResult<&mut Self> {
+        self.backend_mut().insert_account_storage(address, slot, value)?;
+        Ok(self)
+    }
+
     pub fn set_tracing(&mut self, tracing: bool) -> &mut Self {
         self.inspector_config.tracing = tracing;
         self
@@ -194,6 +216,15 @@ impl Executor {
         self
     }
 
+    /// Enables or disables recording of storage reads and writes for each call frame.
+    ///
+    /// This only has an effect when tracing is also enabled, and is meant for the highest
+    /// verbosity level since it requires step-by-step recording.
+    pub fn set_show_storage(&mut self, show_storage: bool) -> &mut Self {
+        self.inspector_config.show_storage = show_storage;
+        self
+    }
+
     pub fn set_trace_printer(&mut self, trace_printer: bool) -> &mut Self {
         self.inspector_config.trace_printer = trace_printer;
         self
@@ -9,8 +9,9 @@ use foundry_common::{self, try_get_http_provider, RpcUrl};
 use foundry_config::Config;
 use revm::{BlockEnv, CfgEnv, SpecId, TxEnv};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::path::{Path, PathBuf};
 
-use super::fork::environment;
+use super::fork::{environment, JsonBlockCacheDB};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct EvmOpts {
@@ -27,9 +28,21 @@ pub struct EvmOpts {
     /// initial retry backoff
     pub fork_retry_backoff: Option<u64>,
 
+    /// Number of retries for spurious requests before giving up.
+    pub fork_retries: Option<u32>,
+
+    /// Number of assumed available compute units per second for this provider.
+    pub compute_units_per_second: Option<u64>,
+
     /// Disables storage caching entirely.
     pub no_storage_caching: bool,
 
+    /// If set, records every fetched fork value into `<PATH>/storage.json` for later replay.
+    pub fork_record: Option<PathBuf>,
+
+    /// If set, serves all forked RPC data from `<PATH>/storage.json` instead of a live endpoint.
+    pub fork_replay: Option<PathBuf>,
+
     /// the initial balance of each deployed test contract
     pub initial_balance: U256,
 
@@ -52,6 +65,11 @@ impl EvmOpts {
     /// If a `fork_url` is set, it gets configured with settings fetched from the endpoint (chain
     /// id, )
     pub async fn evm_env(&self) -> revm::Env {
+        if let Some(ref fork_replay) = self.fork_replay {
+            return self
+                .replay_evm_env(fork_replay)
+                .expect("Could not load environment from fork replay fixture")
+        }
         if let Some(ref fork_url) = self.fork_url {
             self.fork_evm_env(fork_url).await.expect("Could not instantiate forked environment")
         } else {
@@ -65,6 +83,9 @@ impl EvmOpts {
     ///
     /// Returns an error if a RPC request failed, or the fork url is not a valid url
     pub fn evm_env_blocking(&self) -> eyre::Result<revm::Env> {
+        if let Some(ref fork_replay) = self.fork_replay {
+            return self.replay_evm_env(fork_replay)
+        }
         if let Some(ref fork_url) = self.fork_url {
             RuntimeOrHandle::new().block_on(async { self.fork_evm_env(fork_url).await })
         } else {
@@ -72,6 +93,29 @@ impl EvmOpts {
         }
     }
 
+    /// Returns the `revm::Env` reconstructed from a `--fork-record` fixture, without making any
+    /// network requests.
+    ///
+    /// The chain config and block info come straight from the fixture's own metadata, since
+    /// that's exactly what was pinned when the fixture was recorded.
+    pub fn replay_evm_env(&self, fixture_dir: &Path) -> eyre::Result<revm::Env> {
+        let cache_path = fixture_dir.join("storage.json");
+        let cache = JsonBlockCacheDB::load(&cache_path).wrap_err_with(|| {
+            format!("Could not load fork replay fixture from {}", cache_path.display())
+        })?;
+        let meta = cache.meta().read().clone();
+        Ok(revm::Env {
+            cfg: meta.cfg_env,
+            block: meta.block_env,
+            tx: TxEnv {
+                gas_price: self.env.gas_price.unwrap_or_default().into(),
+                gas_limit: self.gas_limit().as_u64(),
+                caller: self.sender,
+                ..Default::default()
+            },
+        })
+    }
+
     /// Returns the `revm::Env` configured with settings retrieved from the endpoints
     pub async fn fork_evm_env(&self, fork_url: impl AsRef<str>) -> eyre::Result<revm::Env> {
         let fork_url = fork_url.as_ref();
@@ -134,8 +178,19 @@ impl EvmOpts {
     /// be at `~/.foundry/cache/mainnet/14435000/storage.json`
     pub fn get_fork(&self, config: &Config, env: revm::Env) -> Option<CreateFork> {
         let url = self.fork_url.clone()?;
-        let enable_caching = config.enable_caching(&url, env.cfg.chain_id.as_u64());
-        Some(CreateFork { url, enable_caching, env, evm_opts: self.clone() })
+        // `--fork-record`/`--fork-replay` always want a backing cache, regardless of
+        // `no_storage_caching` or the project's `rpc_storage_caching` settings.
+        let enable_caching = self.fork_record.is_some() ||
+            self.fork_replay.is_some() ||
+            config.enable_caching(&url, env.cfg.chain_id.as_u64());
+        Some(CreateFork {
+            url,
+            enable_caching,
+            env,
+            evm_opts: self.clone(),
+            record_path: self.fork_record.clone(),
+            replay_path: self.fork_replay.clone(),
+        })
     }
 
     /// Returns the gas limit to use
@@ -8,6 +8,9 @@ pub mod trace;
 /// Debugger data structures
 pub mod debug;
 
+/// EVM bytecode disassembly, sharing the debugger's opcode table
+pub mod disas;
+
 /// Coverage data structures
 pub mod coverage;
 
@@ -108,24 +108,24 @@ impl CallTraceDecoder {
                     1,
                     "ecrecover",
                     [
-                        ParamType::FixedBytes(32),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
+                        ("hash", ParamType::FixedBytes(32)),
+                        ("v", ParamType::Uint(256)),
+                        ("r", ParamType::Uint(256)),
+                        ("s", ParamType::Uint(256)),
                     ],
                     [ParamType::Address],
                 ),
-                precompile(2, "keccak", [ParamType::Bytes], [ParamType::FixedBytes(32)]),
-                precompile(3, "ripemd", [ParamType::Bytes], [ParamType::FixedBytes(32)]),
-                precompile(4, "identity", [ParamType::Bytes], [ParamType::Bytes]),
+                precompile(2, "keccak", [("data", ParamType::Bytes)], [ParamType::FixedBytes(32)]),
+                precompile(3, "ripemd", [("data", ParamType::Bytes)], [ParamType::FixedBytes(32)]),
+                precompile(4, "identity", [("data", ParamType::Bytes)], [ParamType::Bytes]),
                 precompile(
                     5,
                     "modexp",
                     [
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Bytes,
+                        ("Bsize", ParamType::Uint(256)),
+                        ("Esize", ParamType::Uint(256)),
+                        ("Msize", ParamType::Uint(256)),
+                        ("BEM", ParamType::Bytes),
                     ],
                     [ParamType::Bytes],
                 ),
@@ -133,29 +133,33 @@ impl CallTraceDecoder {
                     6,
                     "ecadd",
                     [
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
+                        ("x1", ParamType::Uint(256)),
+                        ("y1", ParamType::Uint(256)),
+                        ("x2", ParamType::Uint(256)),
+                        ("y2", ParamType::Uint(256)),
                     ],
                     [ParamType::Uint(256), ParamType::Uint(256)],
                 ),
                 precompile(
                     7,
                     "ecmul",
-                    [ParamType::Uint(256), ParamType::Uint(256), ParamType::Uint(256)],
+                    [
+                        ("x1", ParamType::Uint(256)),
+                        ("y1", ParamType::Uint(256)),
+                        ("scalar", ParamType::Uint(256)),
+                    ],
                     [ParamType::Uint(256), ParamType::Uint(256)],
                 ),
                 precompile(
                     8,
                     "ecpairing",
                     [
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
-                        ParamType::Uint(256),
+                        ("x1", ParamType::Uint(256)),
+                        ("y1", ParamType::Uint(256)),
+                        ("x2", ParamType::Uint(256)),
+                        ("y2", ParamType::Uint(256)),
+                        ("x3", ParamType::Uint(256)),
+                        ("y3", ParamType::Uint(256)),
                     ],
                     [ParamType::Uint(256)],
                 ),
@@ -163,11 +167,11 @@ impl CallTraceDecoder {
                     9,
                     "blake2f",
                     [
-                        ParamType::Uint(4),
-                        ParamType::FixedBytes(64),
-                        ParamType::FixedBytes(128),
-                        ParamType::FixedBytes(16),
-                        ParamType::FixedBytes(1),
+                        ("rounds", ParamType::Uint(4)),
+                        ("h", ParamType::FixedBytes(64)),
+                        ("m", ParamType::FixedBytes(128)),
+                        ("t", ParamType::FixedBytes(16)),
+                        ("f", ParamType::FixedBytes(1)),
                     ],
                     [ParamType::FixedBytes(64)],
                 ),
@@ -248,6 +252,8 @@ impl CallTraceDecoder {
     }
 
     pub async fn decode(&self, traces: &mut CallTraceArena) {
+        self.prefetch_signatures(traces).await;
+
         for node in traces.arena.iter_mut() {
             // Set contract name
             if let Some(contract) = self.contracts.get(&node.trace.address).cloned() {
@@ -314,6 +320,53 @@ impl CallTraceDecoder {
         }
     }
 
+    /// Collects every function selector and event topic0 in `traces` that we don't already have
+    /// an ABI for, and resolves them against the signature database in a single batched request
+    /// each, instead of one request per call/log while decoding.
+    async fn prefetch_signatures(&self, traces: &CallTraceArena) {
+        let identifier = match &self.signature_identifier {
+            Some(identifier) => identifier,
+            None => return,
+        };
+
+        let unknown_selectors: Vec<Vec<u8>> = traces
+            .arena
+            .iter()
+            .filter_map(|node| match &node.trace.data {
+                RawOrDecodedCall::Raw(bytes) if bytes.len() >= SELECTOR_LEN => {
+                    (!self.functions.contains_key(&bytes[..SELECTOR_LEN]))
+                        .then(|| bytes[..SELECTOR_LEN].to_vec())
+                }
+                _ => None,
+            })
+            .collect();
+
+        let unknown_topics: Vec<Vec<u8>> = traces
+            .arena
+            .iter()
+            .flat_map(|node| node.logs.iter())
+            .filter_map(|log| match log {
+                RawOrDecodedLog::Raw(raw_log) if !raw_log.topics.is_empty() => {
+                    let key = (raw_log.topics[0], raw_log.topics.len() - 1);
+                    (!self.events.contains_key(&key)).then(|| raw_log.topics[0].0.to_vec())
+                }
+                _ => None,
+            })
+            .collect();
+
+        if unknown_selectors.is_empty() && unknown_topics.is_empty() {
+            return
+        }
+
+        let mut identifier = identifier.write().await;
+        if !unknown_selectors.is_empty() {
+            identifier.identify_functions(&unknown_selectors).await;
+        }
+        if !unknown_topics.is_empty() {
+            identifier.identify_events(&unknown_topics).await;
+        }
+    }
+
     async fn decode_events(&self, node: &mut CallTraceNode) {
         for log in node.logs.iter_mut() {
             self.decode_event(log).await;
@@ -341,20 +394,36 @@ impl CallTraceDecoder {
             for mut event in events {
                 // ensure all params are named, otherwise this will cause issues with decoding: See also <https://github.com/rust-ethereum/ethabi/issues/206>
                 let empty_params = patch_nameless_params(&mut event);
+                // Indexed dynamic types (string, bytes, arrays, tuples) only ever reach us as the
+                // keccak hash of their value, since that's all the topic can hold. `ethabi` still
+                // decodes them to a token (the raw hash bytes), so without this we'd print the
+                // hash with no indication that it isn't the real value.
+                let indexed_dynamic: Vec<bool> = event
+                    .inputs
+                    .iter()
+                    .map(|input| input.indexed && input.kind.is_dynamic())
+                    .collect();
                 if let Ok(decoded) = event.parse_log(raw_log.clone()) {
                     *log = RawOrDecodedLog::Decoded(
                         event.name,
                         decoded
                             .params
                             .into_iter()
-                            .map(|param| {
+                            .enumerate()
+                            .map(|(i, param)| {
                                 // undo patched names
                                 let name = if empty_params.contains(&param.name) {
                                     "".to_string()
                                 } else {
                                     param.name
                                 };
-                                (name, self.apply_label(&param.value))
+                                let value = self.apply_label(&param.value);
+                                let value = if indexed_dynamic.get(i).copied().unwrap_or(false) {
+                                    format!("<hash of indexed {}> {value}", event.inputs[i].kind)
+                                } else {
+                                    value
+                                };
+                                (name, value)
                             })
                             .collect(),
                     );
@@ -386,7 +455,7 @@ fn patch_nameless_params(event: &mut Event) -> HashSet<String> {
 
 fn precompile<I, O>(number: u8, name: impl ToString, inputs: I, outputs: O) -> (Address, Function)
 where
-    I: IntoIterator<Item = ParamType>,
+    I: IntoIterator<Item = (&'static str, ParamType)>,
     O: IntoIterator<Item = ParamType>,
 {
     (
@@ -396,7 +465,7 @@ where
             name: name.to_string(),
             inputs: inputs
                 .into_iter()
-                .map(|kind| Param { name: "".to_string(), kind, internal_type: None })
+                .map(|(name, kind)| Param { name: name.to_string(), kind, internal_type: None })
                 .collect(),
             outputs: outputs
                 .into_iter()
@@ -144,6 +144,71 @@ impl SignaturesIdentifier {
         self.ensure_not_offline()?;
         self.identify(SelectorType::Event, identifier, get_event).await
     }
+
+    /// Fetches all of the unresolved `identifiers` that aren't already cached in a single batched
+    /// request, so subsequent calls to [`identify_function`](Self::identify_function) or
+    /// [`identify_event`](Self::identify_event) for the same identifiers resolve from the cache
+    /// without any further network access.
+    async fn prefetch(&mut self, selector_type: SelectorType, identifiers: &[Vec<u8>]) {
+        if self.offline {
+            return
+        }
+
+        let map = match selector_type {
+            SelectorType::Function => &self.cached.functions,
+            SelectorType::Event => &self.cached.events,
+        };
+
+        let unresolved: Vec<String> = identifiers
+            .iter()
+            .filter(|identifier| !self.unavailable.contains(identifier.as_slice()))
+            .map(|identifier| format!("0x{}", hex::encode(identifier)))
+            .filter(|hex_identifier| !map.contains_key(hex_identifier))
+            .collect();
+
+        if unresolved.is_empty() {
+            return
+        }
+
+        match self.sign_eth_api.decode_selectors(&unresolved, selector_type).await {
+            Ok(resolved) => {
+                let map = match selector_type {
+                    SelectorType::Function => &mut self.cached.functions,
+                    SelectorType::Event => &mut self.cached.events,
+                };
+                for hex_identifier in unresolved {
+                    if let Some(signature) = resolved.get(&hex_identifier).and_then(|s| s.first()) {
+                        map.insert(hex_identifier, signature.clone());
+                    }
+                }
+            }
+            Err(err) => warn!(?err, "failed to batch-fetch signatures"),
+        }
+    }
+
+    /// Identifies as many `Function`s as possible in a single batched request, in the same order
+    /// as `identifiers`.
+    pub async fn identify_functions(&mut self, identifiers: &[Vec<u8>]) -> Vec<Option<Function>> {
+        self.prefetch(SelectorType::Function, identifiers).await;
+
+        let mut out = Vec::with_capacity(identifiers.len());
+        for identifier in identifiers {
+            out.push(self.identify_function(identifier).await);
+        }
+        out
+    }
+
+    /// Identifies as many `Event`s as possible in a single batched request, in the same order as
+    /// `identifiers`.
+    pub async fn identify_events(&mut self, identifiers: &[Vec<u8>]) -> Vec<Option<Event>> {
+        self.prefetch(SelectorType::Event, identifiers).await;
+
+        let mut out = Vec::with_capacity(identifiers.len());
+        for identifier in identifiers {
+            out.push(self.identify_event(identifier).await);
+        }
+        out
+    }
 }
 
 impl Drop for SignaturesIdentifier {
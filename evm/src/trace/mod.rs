@@ -76,6 +76,17 @@ impl CallTraceArena {
         }
     }
 
+    /// Returns the gas used by the frame at `idx` alone, i.e. excluding the gas used by its
+    /// child calls. [`CallTrace::gas_cost`] includes children, since that's what the EVM reports
+    /// for a call; refunds (e.g. from `SSTORE`) are folded into the frame that triggered them,
+    /// since that's the frame `gas_cost` accounts for.
+    pub fn self_gas(&self, idx: usize) -> u64 {
+        let node = &self.arena[idx];
+        let children_gas: u64 =
+            node.children.iter().map(|&child| self.arena[child].trace.gas_cost).sum();
+        node.trace.gas_cost.saturating_sub(children_gas)
+    }
+
     pub fn addresses(&self) -> HashSet<(&Address, Option<&[u8]>)> {
         self.arena
             .iter()
@@ -178,76 +189,232 @@ const BRANCH: &str = "  ├─ ";
 const CALL: &str = "→ ";
 const RETURN: &str = "← ";
 
+impl CallTraceArena {
+    /// Renders the trace the same way [fmt::Display] does, except that any call frame deeper
+    /// than `max_depth` is collapsed into a one-line summary instead of being expanded. Pass
+    /// `None` to render every frame, which is equivalent to `format!("{self:#}")`.
+    ///
+    /// This is a pure rendering-time filter: the underlying [CallTraceArena] (and anything that
+    /// reads it directly, like coverage's `collect()`) is unaffected.
+    pub fn render(&self, verbose: bool, max_depth: Option<usize>) -> String {
+        let mut out = String::new();
+        fmt_node(self, &mut out, 0, "  ", "  ", verbose, 0, max_depth)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Produces one folded-stack line per call frame (`frame1;frame2;...;frameN weight`),
+    /// compatible with inferno/speedscope, weighted by [`self_gas`](Self::self_gas) so the
+    /// resulting flamegraph attributes gas to the frame that actually spent it rather than
+    /// double-counting it in every ancestor. Reverted frames are kept in the stack (so their
+    /// ancestors' totals stay correct) but have their label suffixed with `[reverted]`.
+    pub fn folded_stack_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if !self.arena.is_empty() {
+            self.fold_node(0, &mut Vec::new(), &mut lines);
+        }
+        lines
+    }
+
+    fn fold_node(&self, idx: usize, stack: &mut Vec<String>, lines: &mut Vec<String>) {
+        let node = &self.arena[idx];
+        stack.push(frame_label(&node.trace));
+        lines.push(format!("{} {}", stack.join(";"), self.self_gas(idx)));
+        for &child in &node.children {
+            self.fold_node(child, stack, lines);
+        }
+        stack.pop();
+    }
+}
+
+/// Short `Label::function` (or `address::function` if unlabeled) frame name used by
+/// [`CallTraceArena::folded_stack_lines`].
+fn frame_label(trace: &CallTrace) -> String {
+    let address = to_checksum(&trace.address, None);
+    let name = trace.label.as_ref().unwrap_or(&address);
+    let func = if trace.created() {
+        "new".to_string()
+    } else {
+        match &trace.data {
+            RawOrDecodedCall::Raw(bytes) if bytes.len() >= 4 => hex::encode(&bytes[0..4]),
+            RawOrDecodedCall::Raw(_) => "fallback".to_string(),
+            RawOrDecodedCall::Decoded(func, _, _) => func.clone(),
+        }
+    };
+    let label = format!("{name}::{func}");
+    if trace.success {
+        label
+    } else {
+        format!("{label} [reverted]")
+    }
+}
+
 impl fmt::Display for CallTraceArena {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fn inner(
-            arena: &CallTraceArena,
-            writer: &mut (impl Write + ?Sized),
-            idx: usize,
-            left: &str,
-            child: &str,
-            verbose: bool,
-        ) -> fmt::Result {
-            let node = &arena.arena[idx];
-
-            // Display trace header
-            if !verbose {
-                writeln!(writer, "{left}{}", node.trace)?;
-            } else {
-                writeln!(writer, "{left}{:#}", node.trace)?;
-            }
+        fmt_node(self, f, 0, "  ", "  ", f.alternate(), 0, None)
+    }
+}
 
-            // Display logs and subcalls
-            let left_prefix = format!("{child}{BRANCH}");
-            let right_prefix = format!("{child}{PIPE}");
-            for child in &node.ordering {
-                match child {
-                    LogCallOrder::Log(index) => {
-                        let mut log = String::new();
-                        write!(log, "{}", node.logs[*index])?;
-
-                        // Prepend our tree structure symbols to each line of the displayed log
-                        log.lines().enumerate().try_for_each(|(i, line)| {
-                            writeln!(
-                                writer,
-                                "{}{}",
-                                if i == 0 { &left_prefix } else { &right_prefix },
-                                line
-                            )
-                        })?;
-                    }
-                    LogCallOrder::Call(index) => {
-                        inner(
-                            arena,
-                            writer,
-                            node.children[*index],
-                            &left_prefix,
-                            &right_prefix,
-                            verbose,
-                        )?;
-                    }
-                }
-            }
+/// Writes the subtree rooted at `idx`, collapsing it into a one-line summary if `depth` exceeds
+/// `max_depth`.
+fn fmt_node(
+    arena: &CallTraceArena,
+    writer: &mut (impl Write + ?Sized),
+    idx: usize,
+    left: &str,
+    child: &str,
+    verbose: bool,
+    depth: usize,
+    max_depth: Option<usize>,
+) -> fmt::Result {
+    let node = &arena.arena[idx];
+
+    // Display trace header
+    if !verbose {
+        writeln!(writer, "{left}{}", node.trace)?;
+    } else {
+        // At the highest verbosity, also break down how much of the frame's gas was
+        // spent in its own execution versus its child calls.
+        let self_gas = arena.self_gas(idx);
+        if self_gas != node.trace.gas_cost {
+            writeln!(writer, "{left}{:#} [self gas: {self_gas}]", node.trace)?;
+        } else {
+            writeln!(writer, "{left}{:#}", node.trace)?;
+        }
+    }
+
+    // Display logs and subcalls
+    let left_prefix = format!("{child}{BRANCH}");
+    let right_prefix = format!("{child}{PIPE}");
+
+    // Display storage reads/writes recorded for this frame. These are only present
+    // when step recording was enabled on the tracer (the highest verbosity level),
+    // since capturing them on every step would slow down normal runs.
+    if verbose {
+        print_storage_accesses(writer, node, &left_prefix, &right_prefix)?;
+    }
+
+    for child in &node.ordering {
+        match child {
+            LogCallOrder::Log(index) => {
+                let mut log = String::new();
+                write!(log, "{}", node.logs[*index])?;
 
-            // Display trace return data
-            let color = trace_color(&node.trace);
-            write!(writer, "{child}{EDGE}")?;
-            write!(writer, "{}", color.paint(RETURN))?;
-            if node.trace.created() {
-                if let RawOrDecodedReturnData::Raw(bytes) = &node.trace.output {
-                    writeln!(writer, "{} bytes of code", bytes.len())?;
+                // Prepend our tree structure symbols to each line of the displayed log
+                log.lines().enumerate().try_for_each(|(i, line)| {
+                    writeln!(
+                        writer,
+                        "{}{}",
+                        if i == 0 { &left_prefix } else { &right_prefix },
+                        line
+                    )
+                })?;
+            }
+            LogCallOrder::Call(index) => {
+                let child_idx = node.children[*index];
+                if max_depth.map_or(false, |max_depth| depth + 1 > max_depth) {
+                    write_collapsed_call(arena, writer, child_idx, &left_prefix)?;
                 } else {
-                    unreachable!("We should never have decoded calldata for contract creations");
+                    fmt_node(
+                        arena,
+                        writer,
+                        child_idx,
+                        &left_prefix,
+                        &right_prefix,
+                        verbose,
+                        depth + 1,
+                        max_depth,
+                    )?;
                 }
-            } else {
-                writeln!(writer, "{}", node.trace.output)?;
             }
+        }
+    }
 
-            Ok(())
+    // Display trace return data
+    let color = trace_color(&node.trace);
+    write!(writer, "{child}{EDGE}")?;
+    write!(writer, "{}", color.paint(RETURN))?;
+    if node.trace.created() {
+        if let RawOrDecodedReturnData::Raw(bytes) = &node.trace.output {
+            writeln!(writer, "{} bytes of code", bytes.len())?;
+        } else {
+            unreachable!("We should never have decoded calldata for contract creations");
         }
+    } else {
+        writeln!(writer, "{}", node.trace.output)?;
+    }
 
-        inner(self, f, 0, "  ", "  ", f.alternate())
+    Ok(())
+}
+
+/// Writes a one-line summary for a frame collapsed by `max_depth`, covering the whole subtree
+/// rooted at `idx` so callers can tell whether anything inside reverted.
+fn write_collapsed_call(
+    arena: &CallTraceArena,
+    writer: &mut (impl Write + ?Sized),
+    idx: usize,
+    prefix: &str,
+) -> fmt::Result {
+    let (call_count, gas_used, any_reverted) = collapsed_subtree_summary(arena, idx);
+    writeln!(
+        writer,
+        "{prefix}{BRANCH}... {call_count} calls collapsed, {gas_used} gas{}",
+        if any_reverted { " (reverted)" } else { "" }
+    )
+}
+
+/// Returns `(number of calls in the subtree, gas used by the root frame, whether any frame in
+/// the subtree reverted)`.
+fn collapsed_subtree_summary(arena: &CallTraceArena, idx: usize) -> (usize, u64, bool) {
+    let node = &arena.arena[idx];
+    let mut call_count = 1;
+    let mut any_reverted = !node.trace.success;
+    for &child in &node.children {
+        let (child_count, _, child_reverted) = collapsed_subtree_summary(arena, child);
+        call_count += child_count;
+        any_reverted |= child_reverted;
+    }
+    (call_count, node.trace.gas_cost, any_reverted)
+}
+
+/// The maximum number of storage accesses printed for a single frame before the list is
+/// truncated in favor of a count.
+const MAX_DISPLAYED_STORAGE_ACCESSES: usize = 64;
+
+/// Writes the storage slots read and written by `node`'s frame, indented under it.
+///
+/// Storage accesses are only recorded on [CallTraceStep]s when the tracer's step recording is
+/// enabled, which only happens at the highest verbosity level.
+fn print_storage_accesses(
+    writer: &mut (impl Write + ?Sized),
+    node: &CallTraceNode,
+    left_prefix: &str,
+    right_prefix: &str,
+) -> fmt::Result {
+    let accesses: Vec<_> =
+        node.trace.steps.iter().filter_map(|step| step.state_diff.map(|diff| (step, diff))).collect();
+
+    if accesses.is_empty() {
+        return Ok(())
     }
+
+    writeln!(writer, "{left_prefix}storage")?;
+    for (step, (slot, value)) in accesses.iter().take(MAX_DISPLAYED_STORAGE_ACCESSES) {
+        let verb = match step.op {
+            Instruction::OpCode(op) if op == opcode::SLOAD => "read",
+            _ => "write",
+        };
+        writeln!(writer, "{right_prefix}  [{verb}] {slot:#066x} = {value:#066x}")?;
+    }
+    if accesses.len() > MAX_DISPLAYED_STORAGE_ACCESSES {
+        writeln!(
+            writer,
+            "{right_prefix}  ... {} more storage accesses",
+            accesses.len() - MAX_DISPLAYED_STORAGE_ACCESSES
+        )?;
+    }
+
+    Ok(())
 }
 
 /// A raw or decoded log.
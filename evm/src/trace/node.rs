@@ -9,6 +9,7 @@ use crate::{
 };
 use ethers::{
     abi::{Abi, Function},
+    core::utils::to_checksum,
     types::{Action, Address, Call, CallResult, Create, CreateResult, Res, Suicide},
 };
 use foundry_common::SELECTOR_LEN;
@@ -100,6 +101,20 @@ impl CallTraceNode {
         errors: &Abi,
         verbosity: u8,
     ) {
+        // For delegatecalls the tracer already records the implementation's address as
+        // `trace.address` (see `Tracer::call`), since that's whose code actually ran. Label the
+        // frame with the proxy it was reached through, so it reads as `Proxy::Impl::function(..)`
+        // instead of attributing the call to the implementation alone.
+        if self.trace.kind == CallKind::DelegateCall {
+            if let Some(proxy_label) = labels.get(&self.trace.caller) {
+                let impl_label =
+                    self.trace.label.clone().unwrap_or_else(|| to_checksum(&self.trace.address, None));
+                if Some(proxy_label) != Some(&impl_label) {
+                    self.trace.label = Some(format!("{proxy_label}::{impl_label}"));
+                }
+            }
+        }
+
         debug_assert!(!funcs.is_empty(), "requires at least 1 func");
         // This is safe because (1) we would not have an entry for the given
         // selector if no functions with that selector were added and (2) the
@@ -184,7 +199,20 @@ impl CallTraceNode {
                 precompile_fn.signature(),
                 precompile_fn.decode_input(bytes).map_or_else(
                     |_| vec![hex::encode(bytes)],
-                    |tokens| tokens.iter().map(|token| utils::label(token, labels)).collect(),
+                    |tokens| {
+                        tokens
+                            .iter()
+                            .zip(precompile_fn.inputs.iter())
+                            .map(|(token, param)| {
+                                let value = utils::label(token, labels);
+                                if param.name.is_empty() {
+                                    value
+                                } else {
+                                    format!("{}: {value}", param.name)
+                                }
+                            })
+                            .collect()
+                    },
                 ),
             );
 
@@ -3500,27 +3500,27 @@ mod tests {
         assert!(content.ends_with('\n') && !content.ends_with("\n\n"));
     }
 
+    #[derive(Eq)]
+    struct PrettyString(String);
+
+    impl PartialEq for PrettyString {
+        fn eq(&self, other: &PrettyString) -> bool {
+            self.0.lines().eq(other.0.lines())
+        }
+    }
+
+    impl std::fmt::Debug for PrettyString {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
     fn test_formatter(
         filename: &str,
         config: FormatterConfig,
         source: &str,
         expected_source: &str,
     ) {
-        #[derive(Eq)]
-        struct PrettyString(String);
-
-        impl PartialEq for PrettyString {
-            fn eq(&self, other: &PrettyString) -> bool {
-                self.0.lines().eq(other.0.lines())
-            }
-        }
-
-        impl std::fmt::Debug for PrettyString {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                f.write_str(&self.0)
-            }
-        }
-
         assert_eof(expected_source);
 
         let source_parsed = parse(source).unwrap();
@@ -3617,4 +3617,66 @@ mod tests {
     test_directory! { TrailingComma }
     test_directory! { PragmaDirective }
     test_directory! { Annotation }
+
+    // Formatting is expected to be a fixpoint: running the formatter on its own output must
+    // reproduce that output exactly. Exercise this over every expected-output fixture in
+    // `testdata/`, rather than per test directory, so a regression anywhere in the corpus fails
+    // a single, easy-to-spot test.
+    #[test]
+    fn formatting_the_testdata_corpus_is_idempotent() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata");
+        for dir in fs::read_dir(&testdata).unwrap() {
+            let dir = dir.unwrap().path();
+            if !dir.is_dir() {
+                continue
+            }
+
+            for file in fs::read_dir(&dir).unwrap() {
+                let file = file.unwrap().path();
+                let Some(filename) = file.file_name().and_then(|name| name.to_str()) else {
+                    continue
+                };
+                if filename.strip_suffix("fmt.sol").and_then(|f| f.strip_suffix('.')).is_none() {
+                    continue
+                }
+
+                let source = fs::read_to_string(&file).unwrap();
+
+                let default_config = FormatterConfig { line_length: 80, ..Default::default() };
+                let mut config = toml::Value::try_from(&default_config).unwrap();
+                let config_table = config.as_table_mut().unwrap();
+                let mut lines = source.split('\n').peekable();
+                while let Some(line) = lines.peek() {
+                    let entry = line
+                        .strip_prefix("//")
+                        .and_then(|line| line.trim().strip_prefix("config:"))
+                        .map(str::trim);
+                    let entry = if let Some(entry) = entry { entry } else { break };
+                    match toml::from_str::<toml::Value>(entry) {
+                        Ok(toml::Value::Table(table)) => config_table.extend(table),
+                        _ => panic!("Invalid config item in {filename}"),
+                    }
+                    lines.next();
+                }
+                let config: FormatterConfig =
+                    config.try_into().unwrap_or_else(|err| panic!("Invalid config for {filename}: {err}"));
+                let expected = lines.join("\n");
+
+                let parsed = parse(&expected).unwrap();
+                let mut once = String::new();
+                format(&mut once, parsed, config.clone()).unwrap();
+
+                let reparsed = parse(&once).unwrap();
+                let mut twice = String::new();
+                format(&mut twice, reparsed, config).unwrap();
+
+                pretty_assertions::assert_eq!(
+                    PrettyString(once),
+                    PrettyString(twice),
+                    "formatting {} twice is not a fixpoint",
+                    file.display()
+                );
+            }
+        }
+    }
 }
@@ -36,6 +36,14 @@ impl GasReport {
         Self { report_for, ignore, ..Default::default() }
     }
 
+    /// Returns true if `name` matches any of the glob `patterns`, e.g. `"Mock*"` matching
+    /// `"MockERC20"`. Patterns without wildcards behave as an exact match.
+    fn is_match(patterns: &[String], name: &str) -> bool {
+        patterns.iter().any(|pattern| {
+            glob::Pattern::new(pattern).map(|glob| glob.matches(name)).unwrap_or(false)
+        })
+    }
+
     pub fn analyze(&mut self, traces: &[(TraceKind, CallTraceArena)]) {
         traces.iter().for_each(|(_, trace)| {
             self.analyze_node(0, trace);
@@ -52,22 +60,24 @@ impl GasReport {
 
         if let Some(name) = &trace.contract {
             let contract_name = name.rsplit(':').next().unwrap_or(name.as_str()).to_string();
+            let is_listed = Self::is_match(&self.report_for, &contract_name);
+            let is_ignored = Self::is_match(&self.ignore, &contract_name);
             // If the user listed the contract in 'gas_reports' (the foundry.toml field) a
             // report for the contract is generated even if it's listed in the ignore
             // list. This is addressed this way because getting a report you don't expect is
             // preferable than not getting one you expect. A warning is printed to stderr
             // indicating the "double listing".
-            if self.report_for.contains(&contract_name) && self.ignore.contains(&contract_name) {
+            if is_listed && is_ignored {
                 eprintln!(
                     "{}: {} is listed in both 'gas_reports' and 'gas_reports_ignore'.",
                     yansi::Paint::yellow("warning").bold(),
                     contract_name
                 );
             }
-            let report_contract = (!self.ignore.contains(&contract_name) &&
-                self.report_for.contains(&"*".to_string())) ||
-                (!self.ignore.contains(&contract_name) && self.report_for.is_empty()) ||
-                (self.report_for.contains(&contract_name));
+            let reports_everything = self.report_for.iter().any(|pattern| pattern == "*");
+            let report_contract = (!is_ignored && reports_everything) ||
+                (!is_ignored && self.report_for.is_empty()) ||
+                is_listed;
             if report_contract {
                 let mut contract_report =
                     self.contracts.entry(name.to_string()).or_insert_with(Default::default);
@@ -116,6 +126,57 @@ impl GasReport {
     }
 }
 
+/// A gas report for internal (non-externally-called) Solidity functions, attributed from
+/// per-instruction gas costs recorded by the coverage inspector (see
+/// `foundry_evm::coverage::anchors::attribute_gas`).
+///
+/// Unlike [GasReport], which only sees gas spent in external call frames, this report can
+/// attribute gas to `internal`/`private` functions. It's necessarily approximate: gas shared
+/// between a function and code inlined into it (e.g. modifiers) is counted against whichever
+/// function encloses the smallest source range, and the report only exists when `forge test` runs
+/// with coverage instrumentation enabled (i.e. `--gas-report-internal`), which disables the
+/// optimizer and therefore does not reflect optimized gas usage.
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct InternalGasReport {
+    pub contracts: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl InternalGasReport {
+    /// Accumulates `gas` spent in `function` of `contract`.
+    pub fn add(&mut self, contract: String, function: String, gas: u64) {
+        *self.contracts.entry(contract).or_default().entry(function).or_default() += gas;
+    }
+}
+
+impl Display for InternalGasReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        for (name, functions) in self.contracts.iter() {
+            if functions.is_empty() {
+                continue
+            }
+
+            let mut table = Table::new();
+            table.load_preset(ASCII_MARKDOWN);
+            table.set_header(vec![Cell::new(format!("{name} contract (internal functions)"))
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Green)]);
+            table.add_row(vec![
+                Cell::new("Function Name").add_attribute(Attribute::Bold).fg(Color::Magenta),
+                Cell::new("Gas").add_attribute(Attribute::Bold).fg(Color::Yellow),
+            ]);
+            functions.iter().for_each(|(fname, gas)| {
+                table.add_row(vec![
+                    Cell::new(fname).add_attribute(Attribute::Bold),
+                    Cell::new(gas.to_string()).fg(Color::Yellow),
+                ]);
+            });
+            writeln!(f, "{table}")?;
+            writeln!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for GasReport {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         for (name, contract) in self.contracts.iter() {
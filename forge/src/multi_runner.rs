@@ -2,7 +2,7 @@ use crate::{result::SuiteResult, ContractRunner, TestFilter, TestOptions};
 use ethers::{
     abi::Abi,
     prelude::{artifacts::CompactContractBytecode, ArtifactId, ArtifactOutput},
-    solc::{contracts::ArtifactContracts, Artifact, ProjectCompileOutput},
+    solc::{artifacts::Libraries, contracts::ArtifactContracts, Artifact, ProjectCompileOutput},
     types::{Address, Bytes, U256},
 };
 use eyre::Result;
@@ -16,7 +16,11 @@ use foundry_evm::{
 };
 use foundry_utils::PostLinkInput;
 use rayon::prelude::*;
-use std::{collections::BTreeMap, path::Path, sync::mpsc::Sender};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::mpsc::Sender,
+};
 
 pub type DeployableContracts = BTreeMap<ArtifactId, (Abi, Bytes, Vec<Bytes>)>;
 
@@ -144,6 +148,7 @@ impl MultiContractRunner {
                         .with_spec(self.evm_spec)
                         .with_gas_limit(self.evm_opts.gas_limit())
                         .set_tracing(self.evm_opts.verbosity >= 3)
+                        .set_show_storage(self.evm_opts.verbosity >= 5)
                         .set_coverage(self.coverage)
                         .build(db.clone());
                     let identifier = id.identifier();
@@ -222,6 +227,9 @@ pub struct MultiContractRunnerBuilder {
     pub coverage: bool,
     /// Settings related to fuzz and/or invariant tests
     pub test_options: Option<TestOptions>,
+    /// Library addresses to link against, for libraries that are already deployed rather than
+    /// deployed fresh alongside each test contract
+    pub libraries: Libraries,
 }
 
 impl MultiContractRunnerBuilder {
@@ -245,6 +253,46 @@ impl MultiContractRunnerBuilder {
             .map(|(i, c)| (i, c.into_contract_bytecode()))
             .collect::<Vec<(ArtifactId, CompactContractBytecode)>>();
 
+        self.build_with_contracts(root, contracts, env, evm_opts)
+    }
+
+    /// Like [Self::build], but for callers that already hold the compiled `(ArtifactId,
+    /// CompactContractBytecode)` pairs (e.g. because they derived them from a
+    /// [ProjectCompileOutput] they couldn't afford to also hand over by value), sparing them a
+    /// redundant extraction from (or clone of) the full compiler output.
+    pub fn build_with_contracts(
+        self,
+        root: impl AsRef<Path>,
+        contracts: Vec<(ArtifactId, CompactContractBytecode)>,
+        env: revm::Env,
+        evm_opts: EvmOpts,
+    ) -> Result<MultiContractRunner> {
+        // Results, source paths and the gas report are all keyed by `ArtifactId::identifier()`,
+        // which is the fully qualified `<path>:<name>` of a contract, so two contracts with the
+        // same name in different files are unambiguous. But that's only true as long as every
+        // identifier is actually unique - if it isn't, whichever contract is inserted last
+        // silently wins and the other one's results vanish. Catch that up front instead of
+        // letting it happen quietly downstream.
+        //
+        // The same identifier can legitimately appear more than once when a source is compiled
+        // under multiple solc versions (e.g. a shared dependency pulled in by files with
+        // incompatible pragmas) - those are written to disk as separate `<contract>.<version>.json`
+        // artifacts (see `foundry_common::get_artifact_path`), so key the check on the full
+        // `(identifier, version)` pair and only reject true collisions.
+        let mut seen = HashMap::with_capacity(contracts.len());
+        for (id, _) in &contracts {
+            let identifier = id.identifier();
+            let key = (identifier.clone(), id.version.clone());
+            if let Some(previous) = seen.insert(key, id.source.clone()) {
+                eyre::bail!(
+                    "duplicate contract `{identifier}` compiled with solc {} found in `{}` and `{}`",
+                    id.version,
+                    previous.display(),
+                    id.source.display()
+                );
+            }
+        }
+
         let mut known_contracts = ContractsByArtifact::default();
         let source_paths = contracts
             .iter()
@@ -257,7 +305,7 @@ impl MultiContractRunnerBuilder {
         foundry_utils::link_with_nonce_or_address(
             ArtifactContracts::from_iter(contracts),
             &mut known_contracts,
-            Default::default(),
+            self.libraries.clone(),
             evm_opts.sender,
             U256::one(),
             &mut deployable_contracts,
@@ -365,4 +413,13 @@ impl MultiContractRunnerBuilder {
         self.coverage = enable;
         self
     }
+
+    /// Links against the given pre-deployed libraries instead of deploying fresh instances of
+    /// them for each test contract, e.g. libraries configured via `--libraries`/`libraries` in
+    /// `foundry.toml` that solc wasn't given at compile time.
+    #[must_use]
+    pub fn with_libraries(mut self, libraries: Libraries) -> Self {
+        self.libraries = libraries;
+        self
+    }
 }
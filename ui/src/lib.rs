@@ -6,7 +6,10 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ethers::{solc::artifacts::ContractBytecodeSome, types::Address};
+use ethers::{
+    solc::{artifacts::ContractBytecodeSome, sourcemap::SourceMap},
+    types::Address,
+};
 use eyre::Result;
 use forge::{
     debug::{DebugStep, Instruction},
@@ -16,7 +19,7 @@ use forge::{
 use revm::{opcode, SpecId};
 use std::{
     cmp::{max, min},
-    collections::{BTreeMap, HashMap, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
     io,
     sync::mpsc,
     thread,
@@ -54,11 +57,16 @@ pub struct Tui {
     key_buffer: String,
     /// Current step in the debug steps
     current_step: usize,
+    /// Index into `debug_arena` of the call frame initially shown
+    initial_call_index: usize,
     identified_contracts: HashMap<Address, String>,
     known_contracts: HashMap<String, ContractBytecodeSome>,
     known_contracts_sources: HashMap<String, BTreeMap<u32, String>>,
     /// A mapping of source -> (PC -> IC map for deploy code, PC -> IC map for runtime code)
     pc_ic_maps: BTreeMap<String, (PCICMap, PCICMap)>,
+    /// A mapping of source -> (deploy code source map, runtime code source map), parsed once up
+    /// front instead of being re-parsed from the raw source map string on every frame redraw.
+    source_maps: HashMap<String, (SourceMap, SourceMap)>,
 }
 
 impl Tui {
@@ -67,6 +75,7 @@ impl Tui {
     pub fn new(
         debug_arena: Vec<(Address, Vec<DebugStep>, CallKind)>,
         current_step: usize,
+        initial_call_index: usize,
         identified_contracts: HashMap<Address, String>,
         known_contracts: HashMap<String, ContractBytecodeSome>,
         known_contracts_sources: HashMap<String, BTreeMap<u32, String>>,
@@ -101,15 +110,47 @@ impl Tui {
                 ))
             })
             .collect();
+        let source_maps = known_contracts
+            .iter()
+            .filter_map(|(contract_name, bytecode)| {
+                Some((
+                    contract_name.clone(),
+                    (
+                        bytecode.bytecode.source_map()?.ok()?,
+                        bytecode.deployed_bytecode.bytecode.as_ref()?.source_map()?.ok()?,
+                    ),
+                ))
+            })
+            .collect();
         Ok(Tui {
             debug_arena,
             terminal,
             key_buffer: String::new(),
             current_step,
+            initial_call_index,
             identified_contracts,
             known_contracts,
             known_contracts_sources,
             pc_ic_maps,
+            source_maps,
+        })
+    }
+
+    /// Finds the `(call index, step index)` of the instruction that actually executed a
+    /// `REVERT`/`INVALID` opcode, if the debugged execution reverted at all.
+    ///
+    /// Ancestor call frames only ever observe their sub-call failing; the opcode itself is only
+    /// present in the frame that is deepest in the call stack at the time of the revert, so we walk
+    /// `debug_arena` from the end (the order `DebugArena::flatten` produces is pre-order, so the
+    /// frame that bubbled the revert up is the last one recorded) and take the first match.
+    pub fn find_revert(
+        debug_arena: &[(Address, Vec<DebugStep>, CallKind)],
+    ) -> Option<(usize, usize)> {
+        debug_arena.iter().enumerate().rev().find_map(|(call_index, (_, steps, _))| {
+            steps
+                .iter()
+                .position(|step| matches!(step.pretty_opcode().as_str(), "REVERT" | "INVALID"))
+                .map(|step_index| (call_index, step_index))
         })
     }
 
@@ -134,6 +175,7 @@ impl Tui {
         identified_contracts: &HashMap<Address, String>,
         known_contracts: &HashMap<String, ContractBytecodeSome>,
         pc_ic_maps: &BTreeMap<String, (PCICMap, PCICMap)>,
+        source_maps: &HashMap<String, (SourceMap, SourceMap)>,
         known_contracts_sources: &HashMap<String, BTreeMap<u32, String>>,
         debug_steps: &[DebugStep],
         opcode_list: &[String],
@@ -142,6 +184,7 @@ impl Tui {
         draw_memory: &mut DrawMemory,
         stack_labels: bool,
         mem_utf: bool,
+        breakpoints: &HashSet<(usize, usize)>,
     ) {
         let total_size = f.size();
         if total_size.width < 225 {
@@ -151,6 +194,7 @@ impl Tui {
                 identified_contracts,
                 known_contracts,
                 pc_ic_maps,
+                source_maps,
                 known_contracts_sources,
                 debug_steps,
                 opcode_list,
@@ -159,6 +203,7 @@ impl Tui {
                 draw_memory,
                 stack_labels,
                 mem_utf,
+                breakpoints,
             );
         } else {
             Tui::square_layout(
@@ -167,6 +212,7 @@ impl Tui {
                 identified_contracts,
                 known_contracts,
                 pc_ic_maps,
+                source_maps,
                 known_contracts_sources,
                 debug_steps,
                 opcode_list,
@@ -175,6 +221,7 @@ impl Tui {
                 draw_memory,
                 stack_labels,
                 mem_utf,
+                breakpoints,
             );
         }
     }
@@ -186,6 +233,7 @@ impl Tui {
         identified_contracts: &HashMap<Address, String>,
         known_contracts: &HashMap<String, ContractBytecodeSome>,
         pc_ic_maps: &BTreeMap<String, (PCICMap, PCICMap)>,
+        source_maps: &HashMap<String, (SourceMap, SourceMap)>,
         known_contracts_sources: &HashMap<String, BTreeMap<u32, String>>,
         debug_steps: &[DebugStep],
         opcode_list: &[String],
@@ -194,6 +242,7 @@ impl Tui {
         draw_memory: &mut DrawMemory,
         stack_labels: bool,
         mem_utf: bool,
+        breakpoints: &HashSet<(usize, usize)>,
     ) {
         let total_size = f.size();
         if let [app, footer] = Layout::default()
@@ -221,6 +270,7 @@ impl Tui {
                     identified_contracts,
                     known_contracts,
                     pc_ic_maps,
+                    source_maps,
                     known_contracts_sources,
                     debug_steps[current_step].pc,
                     call_kind,
@@ -233,6 +283,7 @@ impl Tui {
                     opcode_list,
                     current_step,
                     draw_memory,
+                    breakpoints,
                     op_pane,
                 );
                 Tui::draw_stack(
@@ -259,6 +310,7 @@ impl Tui {
         identified_contracts: &HashMap<Address, String>,
         known_contracts: &HashMap<String, ContractBytecodeSome>,
         pc_ic_maps: &BTreeMap<String, (PCICMap, PCICMap)>,
+        source_maps: &HashMap<String, (SourceMap, SourceMap)>,
         known_contracts_sources: &HashMap<String, BTreeMap<u32, String>>,
         debug_steps: &[DebugStep],
         opcode_list: &[String],
@@ -267,6 +319,7 @@ impl Tui {
         draw_memory: &mut DrawMemory,
         stack_labels: bool,
         mem_utf: bool,
+        breakpoints: &HashSet<(usize, usize)>,
     ) {
         let total_size = f.size();
 
@@ -300,6 +353,7 @@ impl Tui {
                             identified_contracts,
                             known_contracts,
                             pc_ic_maps,
+                            source_maps,
                             known_contracts_sources,
                             debug_steps[current_step].pc,
                             call_kind,
@@ -312,6 +366,7 @@ impl Tui {
                             opcode_list,
                             current_step,
                             draw_memory,
+                            breakpoints,
                             op_pane,
                         );
                         Tui::draw_stack(
@@ -346,7 +401,7 @@ impl Tui {
         let block_controls = Block::default();
 
         let text_output = Text::from(Span::styled(
-            "[q]: quit | [k/j]: prev/next op | [a/s]: prev/next jump | [c/C]: prev/next call | [g/G]: start/end | [t]: toggle stack labels | [m]: toggle memory decoding | [shift + j/k]: scroll stack | [ctrl + j/k]: scroll memory",
+            "[q]: quit | [k/j]: prev/next op | [a/s]: prev/next jump | [c/C]: prev/next call | [g/G]: start/end | [b]: toggle breakpoint | [R]: run to breakpoint | [t]: toggle stack labels | [m]: toggle memory decoding | [shift + j/k]: scroll stack | [ctrl + j/k]: scroll memory",
             Style::default().add_modifier(Modifier::DIM)
         ));
         let paragraph = Paragraph::new(text_output)
@@ -363,6 +418,7 @@ impl Tui {
         identified_contracts: &HashMap<Address, String>,
         known_contracts: &HashMap<String, ContractBytecodeSome>,
         pc_ic_maps: &BTreeMap<String, (PCICMap, PCICMap)>,
+        source_maps: &HashMap<String, (SourceMap, SourceMap)>,
         known_contracts_sources: &HashMap<String, BTreeMap<u32, String>>,
         pc: usize,
         call_kind: CallKind,
@@ -381,255 +437,83 @@ impl Tui {
         let mut text_output: Text = Text::from("");
 
         if let Some(contract_name) = identified_contracts.get(&address) {
-            if let (Some(known), Some(source_code)) =
+            if let (Some(_known), Some(source_code)) =
                 (known_contracts.get(contract_name), known_contracts_sources.get(contract_name))
             {
                 let pc_ic_map = pc_ic_maps.get(contract_name);
-                // grab either the creation source map or runtime sourcemap
+                let source_map = source_maps.get(contract_name);
+                // grab either the creation source map or runtime sourcemap, both parsed once up
+                // front in `Tui::new` instead of being re-parsed from the raw string every frame
                 if let Some((sourcemap, ic)) =
                     if matches!(call_kind, CallKind::Create | CallKind::Create2) {
-                        known.bytecode.source_map().zip(pc_ic_map.and_then(|(c, _)| c.get(&pc)))
+                        source_map
+                            .map(|(deploy, _)| deploy)
+                            .zip(pc_ic_map.and_then(|(c, _)| c.get(&pc)))
                     } else {
-                        known
-                            .deployed_bytecode
-                            .bytecode
-                            .as_ref()
-                            .expect("no bytecode")
-                            .source_map()
+                        source_map
+                            .map(|(_, runtime)| runtime)
                             .zip(pc_ic_map.and_then(|(_, r)| r.get(&pc)))
                     }
                 {
-                    match sourcemap {
-                        Ok(sourcemap) => {
-                            // we are handed a vector of SourceElements that give
-                            // us a span of sourcecode that is currently being executed
-                            // This includes an offset and length. This vector is in
-                            // instruction pointer order, meaning the location of
-                            // the instruction - sum(push_bytes[..pc])
-                            if let Some(source_idx) = sourcemap[*ic].index {
-                                if let Some(source) = source_code.get(&source_idx) {
-                                    let offset = sourcemap[*ic].offset;
-                                    let len = sourcemap[*ic].length;
-
-                                    // split source into before, relevant, and after chunks
-                                    // split by line as well to do some formatting stuff
-                                    let mut before = source[..offset]
-                                        .split_inclusive('\n')
-                                        .collect::<Vec<&str>>();
-                                    let actual = source[offset..offset + len]
-                                        .split_inclusive('\n')
-                                        .map(|s| s.to_string())
-                                        .collect::<Vec<String>>();
-                                    let mut after = source[offset + len..]
-                                        .split_inclusive('\n')
-                                        .collect::<VecDeque<&str>>();
-
-                                    let mut line_number = 0;
-
-                                    let num_lines = before.len() + actual.len() + after.len();
-                                    let height = area.height as usize;
-                                    let needed_highlight = actual.len();
-                                    let mid_len = before.len() + actual.len();
-
-                                    // adjust what text we show of the source code
-                                    let (start_line, end_line) = if needed_highlight > height {
-                                        // highlighted section is more lines than we have avail
-                                        (before.len(), before.len() + needed_highlight)
-                                    } else if height > num_lines {
-                                        // we can fit entire source
-                                        (0, num_lines)
-                                    } else {
-                                        let remaining = height - needed_highlight;
-                                        let mut above = remaining / 2;
-                                        let mut below = remaining / 2;
-                                        if below > after.len() {
-                                            // unused space below the highlight
-                                            above += below - after.len();
-                                        } else if above > before.len() {
-                                            // we have unused space above the highlight
-                                            below += above - before.len();
-                                        } else {
-                                            // no unused space
-                                        }
+                    // we are handed a vector of SourceElements that give
+                    // us a span of sourcecode that is currently being executed
+                    // This includes an offset and length. This vector is in
+                    // instruction pointer order, meaning the location of
+                    // the instruction - sum(push_bytes[..pc])
+                    if let Some(source_idx) = sourcemap[*ic].index {
+                        if let Some(source) = source_code.get(&source_idx) {
+                            let offset = sourcemap[*ic].offset;
+                            let len = sourcemap[*ic].length;
 
-                                        (before.len().saturating_sub(above), mid_len + below)
-                                    };
-
-                                    let max_line_num = num_lines.to_string().len();
-                                    // We check if there is other text on the same line before the
-                                    // highlight starts
-                                    if let Some(last) = before.pop() {
-                                        if !last.ends_with('\n') {
-                                            before.iter().skip(start_line).for_each(|line| {
-                                                text_output.lines.push(Spans::from(vec![
-                                                    Span::styled(
-                                                        format!(
-                                                            "{: >max_line_num$}",
-                                                            line_number.to_string(),
-                                                            max_line_num = max_line_num
-                                                        ),
-                                                        Style::default()
-                                                            .fg(Color::Gray)
-                                                            .bg(Color::DarkGray),
-                                                    ),
-                                                    Span::styled(
-                                                        "\u{2800} ".to_string() + line,
-                                                        Style::default()
-                                                            .add_modifier(Modifier::DIM),
-                                                    ),
-                                                ]));
-                                                line_number += 1;
-                                            });
-
-                                            text_output.lines.push(Spans::from(vec![
-                                                Span::styled(
-                                                    format!(
-                                                        "{: >max_line_num$}",
-                                                        line_number.to_string(),
-                                                        max_line_num = max_line_num
-                                                    ),
-                                                    Style::default()
-                                                        .fg(Color::Cyan)
-                                                        .bg(Color::DarkGray)
-                                                        .add_modifier(Modifier::BOLD),
-                                                ),
-                                                Span::raw("\u{2800} "),
-                                                Span::raw(last),
-                                                Span::styled(
-                                                    actual[0].to_string(),
-                                                    Style::default()
-                                                        .fg(Color::Cyan)
-                                                        .add_modifier(Modifier::BOLD),
-                                                ),
-                                            ]));
-                                            line_number += 1;
-
-                                            actual.iter().skip(1).for_each(|s| {
-                                                text_output.lines.push(Spans::from(vec![
-                                                    Span::styled(
-                                                        format!(
-                                                            "{: >max_line_num$}",
-                                                            line_number.to_string(),
-                                                            max_line_num = max_line_num
-                                                        ),
-                                                        Style::default()
-                                                            .fg(Color::Cyan)
-                                                            .bg(Color::DarkGray)
-                                                            .add_modifier(Modifier::BOLD),
-                                                    ),
-                                                    Span::raw("\u{2800} "),
-                                                    Span::styled(
-                                                        // this is a hack to add coloring
-                                                        // because tui does weird trimming
-                                                        if s.is_empty() || s == "\n" {
-                                                            "\u{2800} \n".to_string()
-                                                        } else {
-                                                            s.to_string()
-                                                        },
-                                                        Style::default()
-                                                            .fg(Color::Cyan)
-                                                            .add_modifier(Modifier::BOLD),
-                                                    ),
-                                                ]));
-                                                line_number += 1;
-                                            });
-                                        } else {
-                                            before.push(last);
-                                            before.iter().skip(start_line).for_each(|line| {
-                                                text_output.lines.push(Spans::from(vec![
-                                                    Span::styled(
-                                                        format!(
-                                                            "{: >max_line_num$}",
-                                                            line_number.to_string(),
-                                                            max_line_num = max_line_num
-                                                        ),
-                                                        Style::default()
-                                                            .fg(Color::Gray)
-                                                            .bg(Color::DarkGray),
-                                                    ),
-                                                    Span::styled(
-                                                        "\u{2800} ".to_string() + line,
-                                                        Style::default()
-                                                            .add_modifier(Modifier::DIM),
-                                                    ),
-                                                ]));
-
-                                                line_number += 1;
-                                            });
-                                            actual.iter().for_each(|s| {
-                                                text_output.lines.push(Spans::from(vec![
-                                                    Span::styled(
-                                                        format!(
-                                                            "{: >max_line_num$}",
-                                                            line_number.to_string(),
-                                                            max_line_num = max_line_num
-                                                        ),
-                                                        Style::default()
-                                                            .fg(Color::Cyan)
-                                                            .bg(Color::DarkGray)
-                                                            .add_modifier(Modifier::BOLD),
-                                                    ),
-                                                    Span::raw("\u{2800} "),
-                                                    Span::styled(
-                                                        if s.is_empty() || s == "\n" {
-                                                            "\u{2800} \n".to_string()
-                                                        } else {
-                                                            s.to_string()
-                                                        },
-                                                        Style::default()
-                                                            .fg(Color::Cyan)
-                                                            .add_modifier(Modifier::BOLD),
-                                                    ),
-                                                ]));
-                                                line_number += 1;
-                                            });
-                                        }
-                                    } else {
-                                        actual.iter().for_each(|s| {
-                                            text_output.lines.push(Spans::from(vec![
-                                                Span::styled(
-                                                    format!(
-                                                        "{: >max_line_num$}",
-                                                        line_number.to_string(),
-                                                        max_line_num = max_line_num
-                                                    ),
-                                                    Style::default()
-                                                        .fg(Color::Cyan)
-                                                        .bg(Color::DarkGray)
-                                                        .add_modifier(Modifier::BOLD),
-                                                ),
-                                                Span::raw("\u{2800} "),
-                                                Span::styled(
-                                                    if s.is_empty() || s == "\n" {
-                                                        "\u{2800} \n".to_string()
-                                                    } else {
-                                                        s.to_string()
-                                                    },
-                                                    Style::default()
-                                                        .fg(Color::Cyan)
-                                                        .add_modifier(Modifier::BOLD),
-                                                ),
-                                            ]));
-                                            line_number += 1;
-                                        });
-                                    }
+                            // split source into before, relevant, and after chunks
+                            // split by line as well to do some formatting stuff
+                            let mut before =
+                                source[..offset].split_inclusive('\n').collect::<Vec<&str>>();
+                            let actual = source[offset..offset + len]
+                                .split_inclusive('\n')
+                                .map(|s| s.to_string())
+                                .collect::<Vec<String>>();
+                            let mut after = source[offset + len..]
+                                .split_inclusive('\n')
+                                .collect::<VecDeque<&str>>();
 
-                                    // fill in the rest of the line as unhighlighted
-                                    if let Some(last) = actual.last() {
-                                        if !last.ends_with('\n') {
-                                            if let Some(post) = after.pop_front() {
-                                                if let Some(last) = text_output.lines.last_mut() {
-                                                    last.0.push(Span::raw(post));
-                                                }
-                                            }
-                                        }
-                                    }
+                            let mut line_number = 0;
 
-                                    // add after highlighted text
-                                    while mid_len + after.len() > end_line {
-                                        after.pop_back();
-                                    }
-                                    after.iter().for_each(|line| {
+                            let num_lines = before.len() + actual.len() + after.len();
+                            let height = area.height as usize;
+                            let needed_highlight = actual.len();
+                            let mid_len = before.len() + actual.len();
+
+                            // adjust what text we show of the source code
+                            let (start_line, end_line) = if needed_highlight > height {
+                                // highlighted section is more lines than we have avail
+                                (before.len(), before.len() + needed_highlight)
+                            } else if height > num_lines {
+                                // we can fit entire source
+                                (0, num_lines)
+                            } else {
+                                let remaining = height - needed_highlight;
+                                let mut above = remaining / 2;
+                                let mut below = remaining / 2;
+                                if below > after.len() {
+                                    // unused space below the highlight
+                                    above += below - after.len();
+                                } else if above > before.len() {
+                                    // we have unused space above the highlight
+                                    below += above - before.len();
+                                } else {
+                                    // no unused space
+                                }
+
+                                (before.len().saturating_sub(above), mid_len + below)
+                            };
+
+                            let max_line_num = num_lines.to_string().len();
+                            // We check if there is other text on the same line before the
+                            // highlight starts
+                            if let Some(last) = before.pop() {
+                                if !last.ends_with('\n') {
+                                    before.iter().skip(start_line).for_each(|line| {
                                         text_output.lines.push(Spans::from(vec![
                                             Span::styled(
                                                 format!(
@@ -648,16 +532,176 @@ impl Tui {
                                         ]));
                                         line_number += 1;
                                     });
+
+                                    text_output.lines.push(Spans::from(vec![
+                                        Span::styled(
+                                            format!(
+                                                "{: >max_line_num$}",
+                                                line_number.to_string(),
+                                                max_line_num = max_line_num
+                                            ),
+                                            Style::default()
+                                                .fg(Color::Cyan)
+                                                .bg(Color::DarkGray)
+                                                .add_modifier(Modifier::BOLD),
+                                        ),
+                                        Span::raw("\u{2800} "),
+                                        Span::raw(last),
+                                        Span::styled(
+                                            actual[0].to_string(),
+                                            Style::default()
+                                                .fg(Color::Cyan)
+                                                .add_modifier(Modifier::BOLD),
+                                        ),
+                                    ]));
+                                    line_number += 1;
+
+                                    actual.iter().skip(1).for_each(|s| {
+                                        text_output.lines.push(Spans::from(vec![
+                                            Span::styled(
+                                                format!(
+                                                    "{: >max_line_num$}",
+                                                    line_number.to_string(),
+                                                    max_line_num = max_line_num
+                                                ),
+                                                Style::default()
+                                                    .fg(Color::Cyan)
+                                                    .bg(Color::DarkGray)
+                                                    .add_modifier(Modifier::BOLD),
+                                            ),
+                                            Span::raw("\u{2800} "),
+                                            Span::styled(
+                                                // this is a hack to add coloring
+                                                // because tui does weird trimming
+                                                if s.is_empty() || s == "\n" {
+                                                    "\u{2800} \n".to_string()
+                                                } else {
+                                                    s.to_string()
+                                                },
+                                                Style::default()
+                                                    .fg(Color::Cyan)
+                                                    .add_modifier(Modifier::BOLD),
+                                            ),
+                                        ]));
+                                        line_number += 1;
+                                    });
                                 } else {
-                                    text_output.extend(Text::from("No source for srcmap index"));
+                                    before.push(last);
+                                    before.iter().skip(start_line).for_each(|line| {
+                                        text_output.lines.push(Spans::from(vec![
+                                            Span::styled(
+                                                format!(
+                                                    "{: >max_line_num$}",
+                                                    line_number.to_string(),
+                                                    max_line_num = max_line_num
+                                                ),
+                                                Style::default()
+                                                    .fg(Color::Gray)
+                                                    .bg(Color::DarkGray),
+                                            ),
+                                            Span::styled(
+                                                "\u{2800} ".to_string() + line,
+                                                Style::default().add_modifier(Modifier::DIM),
+                                            ),
+                                        ]));
+
+                                        line_number += 1;
+                                    });
+                                    actual.iter().for_each(|s| {
+                                        text_output.lines.push(Spans::from(vec![
+                                            Span::styled(
+                                                format!(
+                                                    "{: >max_line_num$}",
+                                                    line_number.to_string(),
+                                                    max_line_num = max_line_num
+                                                ),
+                                                Style::default()
+                                                    .fg(Color::Cyan)
+                                                    .bg(Color::DarkGray)
+                                                    .add_modifier(Modifier::BOLD),
+                                            ),
+                                            Span::raw("\u{2800} "),
+                                            Span::styled(
+                                                if s.is_empty() || s == "\n" {
+                                                    "\u{2800} \n".to_string()
+                                                } else {
+                                                    s.to_string()
+                                                },
+                                                Style::default()
+                                                    .fg(Color::Cyan)
+                                                    .add_modifier(Modifier::BOLD),
+                                            ),
+                                        ]));
+                                        line_number += 1;
+                                    });
                                 }
                             } else {
-                                text_output.extend(Text::from("No srcmap index"));
+                                actual.iter().for_each(|s| {
+                                    text_output.lines.push(Spans::from(vec![
+                                        Span::styled(
+                                            format!(
+                                                "{: >max_line_num$}",
+                                                line_number.to_string(),
+                                                max_line_num = max_line_num
+                                            ),
+                                            Style::default()
+                                                .fg(Color::Cyan)
+                                                .bg(Color::DarkGray)
+                                                .add_modifier(Modifier::BOLD),
+                                        ),
+                                        Span::raw("\u{2800} "),
+                                        Span::styled(
+                                            if s.is_empty() || s == "\n" {
+                                                "\u{2800} \n".to_string()
+                                            } else {
+                                                s.to_string()
+                                            },
+                                            Style::default()
+                                                .fg(Color::Cyan)
+                                                .add_modifier(Modifier::BOLD),
+                                        ),
+                                    ]));
+                                    line_number += 1;
+                                });
                             }
+
+                            // fill in the rest of the line as unhighlighted
+                            if let Some(last) = actual.last() {
+                                if !last.ends_with('\n') {
+                                    if let Some(post) = after.pop_front() {
+                                        if let Some(last) = text_output.lines.last_mut() {
+                                            last.0.push(Span::raw(post));
+                                        }
+                                    }
+                                }
+                            }
+
+                            // add after highlighted text
+                            while mid_len + after.len() > end_line {
+                                after.pop_back();
+                            }
+                            after.iter().for_each(|line| {
+                                text_output.lines.push(Spans::from(vec![
+                                    Span::styled(
+                                        format!(
+                                            "{: >max_line_num$}",
+                                            line_number.to_string(),
+                                            max_line_num = max_line_num
+                                        ),
+                                        Style::default().fg(Color::Gray).bg(Color::DarkGray),
+                                    ),
+                                    Span::styled(
+                                        "\u{2800} ".to_string() + line,
+                                        Style::default().add_modifier(Modifier::DIM),
+                                    ),
+                                ]));
+                                line_number += 1;
+                            });
+                        } else {
+                            text_output.extend(Text::from("No source for srcmap index"));
                         }
-                        Err(e) => text_output.extend(Text::from(format!(
-                            "Error in source map parsing: '{e}', please open an issue"
-                        ))),
+                    } else {
+                        text_output.extend(Text::from("No srcmap index"));
                     }
                 } else {
                     text_output.extend(Text::from("No sourcemap for contract"));
@@ -682,6 +726,7 @@ impl Tui {
         opcode_list: &[String],
         current_step: usize,
         draw_memory: &mut DrawMemory,
+        breakpoints: &HashSet<(usize, usize)>,
         area: Rect,
     ) {
         let block_source_code = Block::default()
@@ -735,9 +780,18 @@ impl Tui {
         let max_pc_len =
             debug_steps.iter().fold(0, |max_val, val| val.pc.max(max_val)).to_string().len();
 
+        let call_index = draw_memory.inner_call_index;
+
         // Define closure that prints one more line of source code
         let mut add_new_line = |line_number| {
-            let bg_color = if line_number == current_step { Color::DarkGray } else { Color::Reset };
+            let is_breakpoint = breakpoints.contains(&(call_index, line_number));
+            let bg_color = if line_number == current_step {
+                Color::DarkGray
+            } else if is_breakpoint {
+                Color::Red
+            } else {
+                Color::Reset
+            };
 
             // Format line number
             let line_number_format = if line_number == current_step {
@@ -982,18 +1036,18 @@ impl Ui for Tui {
                     let event = event::read().unwrap();
                     if let Event::Key(key) = event {
                         if tx.send(Interrupt::KeyPressed(key)).is_err() {
-                            return
+                            return;
                         }
                     } else if let Event::Mouse(mouse) = event {
                         if tx.send(Interrupt::MouseEvent(mouse)).is_err() {
-                            return
+                            return;
                         }
                     }
                 }
                 // Force update if time has passed
                 if last_tick.elapsed() > tick_rate {
                     if tx.send(Interrupt::IntervalElapsed).is_err() {
-                        return
+                        return;
                     }
                     last_tick = Instant::now();
                 }
@@ -1002,14 +1056,23 @@ impl Ui for Tui {
 
         self.terminal.clear()?;
         let mut draw_memory: DrawMemory = DrawMemory::default();
+        draw_memory.inner_call_index = self.initial_call_index;
 
         let debug_call: Vec<(Address, Vec<DebugStep>, CallKind)> = self.debug_arena.clone();
-        let mut opcode_list: Vec<String> =
-            debug_call[0].1.iter().map(|step| step.pretty_opcode()).collect();
-        let mut last_index = 0;
+        let mut opcode_list: Vec<String> = debug_call[draw_memory.inner_call_index]
+            .1
+            .iter()
+            .map(|step| step.pretty_opcode())
+            .collect();
+        let mut last_index = draw_memory.inner_call_index;
 
         let mut stack_labels = false;
         let mut mem_utf = false;
+        // Breakpoints are keyed by (call index, step index) within `debug_call`, i.e. the same
+        // coordinates used by `draw_memory.inner_call_index`/`current_step`. This is coarser than a
+        // true `file:line` breakpoint, but it lines up exactly with what's highlighted in the source
+        // pane, so setting one while looking at a source line behaves the same way.
+        let mut breakpoints: HashSet<(usize, usize)> = HashSet::new();
         // UI thread that manages drawing
         loop {
             if last_index != draw_memory.inner_call_index {
@@ -1032,7 +1095,7 @@ impl Ui for Tui {
                             LeaveAlternateScreen,
                             DisableMouseCapture
                         )?;
-                        return Ok(TUIExitReason::CharExit)
+                        return Ok(TUIExitReason::CharExit);
                     }
                     // Move down
                     KeyCode::Char('j') | KeyCode::Down => {
@@ -1042,9 +1105,9 @@ impl Ui for Tui {
                                 let max_mem = (debug_call[draw_memory.inner_call_index].1
                                     [self.current_step]
                                     .memory
-                                    .len() /
-                                    32)
-                                .saturating_sub(1);
+                                    .len()
+                                    / 32)
+                                    .saturating_sub(1);
                                 if draw_memory.current_mem_startline < max_mem {
                                     draw_memory.current_mem_startline += 1;
                                 }
@@ -1158,8 +1221,8 @@ impl Ui for Tui {
                                 .find_map(|(i, op)| {
                                     if i > 0 {
                                         match (
-                                            prev_ops[i - 1].contains("JUMP") &&
-                                                prev_ops[i - 1] != "JUMPDEST",
+                                            prev_ops[i - 1].contains("JUMP")
+                                                && prev_ops[i - 1] != "JUMPDEST",
                                             &**op,
                                         ) {
                                             (true, "JUMPDEST") => Some(i - 1),
@@ -1181,6 +1244,32 @@ impl Ui for Tui {
                     KeyCode::Char('m') => {
                         mem_utf = !mem_utf;
                     }
+                    // toggle a breakpoint at the step currently shown in the source pane
+                    KeyCode::Char('b') => {
+                        let here = (draw_memory.inner_call_index, self.current_step);
+                        if !breakpoints.remove(&here) {
+                            breakpoints.insert(here);
+                        }
+                        self.key_buffer.clear();
+                    }
+                    // run forward to the next breakpoint, if any, across calls
+                    KeyCode::Char('R') => {
+                        let mut call_index = draw_memory.inner_call_index;
+                        let mut step = self.current_step + 1;
+                        'outer: while call_index < debug_call.len() {
+                            while step < debug_call[call_index].1.len() {
+                                if breakpoints.contains(&(call_index, step)) {
+                                    draw_memory.inner_call_index = call_index;
+                                    self.current_step = step;
+                                    break 'outer;
+                                }
+                                step += 1;
+                            }
+                            call_index += 1;
+                            step = 0;
+                        }
+                        self.key_buffer.clear();
+                    }
                     KeyCode::Char(other) => match other {
                         '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
                             self.key_buffer.push(other);
@@ -1229,6 +1318,7 @@ impl Ui for Tui {
                     &self.identified_contracts,
                     &self.known_contracts,
                     &self.pc_ic_maps,
+                    &self.source_maps,
                     &self.known_contracts_sources,
                     &debug_call[draw_memory.inner_call_index].1[..],
                     &opcode_list,
@@ -1237,6 +1327,7 @@ impl Ui for Tui {
                     &mut draw_memory,
                     stack_labels,
                     mem_utf,
+                    &breakpoints,
                 )
             })?;
         }
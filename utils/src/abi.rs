@@ -85,50 +85,35 @@ pub fn abi_to_solidity(contract_abi: &RawAbi, mut contract_name: &str) -> eyre::
         functions.push(format!("{func};"));
     }
 
+    let mut errors = Vec::with_capacity(contract_abi.errors.len());
+    for error in contract_abi.errors() {
+        let inputs = error.inputs.iter().map(format_error_param).collect::<Vec<_>>().join(", ");
+        errors.push(format!("error {}({inputs});", error.name));
+    }
+
     let functions = functions.join("\n");
     let events = events.join("\n");
+    let errors = errors.join("\n");
 
-    let sol = if structs.structs_types().is_empty() {
-        if events.is_empty() {
-            format!(
-                r#"interface {contract_name} {{
-    {functions}
-}}
-"#
-            )
-        } else {
-            format!(
-                r#"interface {contract_name} {{
-    {events}
-
-    {functions}
-}}
-"#
-            )
-        }
-    } else {
-        let structs = format_struct_types(&structs);
-        match events.is_empty() {
-            true => format!(
-                r#"interface {contract_name} {{
-    {structs}
-
-    {functions}
-}}
-"#
-            ),
-            false => format!(
-                r#"interface {contract_name} {{
-    {events}
-
-    {structs}
+    let mut sections = Vec::new();
+    if !events.is_empty() {
+        sections.push(events);
+    }
+    if !structs.structs_types().is_empty() {
+        sections.push(format_struct_types(&structs));
+    }
+    if !errors.is_empty() {
+        sections.push(errors);
+    }
+    sections.push(functions);
 
-    {functions}
+    let sol = format!(
+        r#"interface {contract_name} {{
+    {}
 }}
-"#
-            ),
-        }
-    };
+"#,
+        sections.join("\n\n    ")
+    );
     forge_fmt::fmt(&sol).map_err(|err| eyre::eyre!(err.to_string()))
 }
 
@@ -286,6 +271,28 @@ fn format_event_params(
     Ok(ty)
 }
 
+/// Returns the error parameter formatted as a string.
+///
+/// Unlike function/event params, error params can't be resolved against [`InternalStructs`] (the
+/// ABI's `internalType` metadata isn't tracked for errors), so tuple params fall back to their raw
+/// ABI type.
+fn format_error_param(param: &Param) -> String {
+    let kind = expand_error_param_type(&param.kind);
+    if param.name.is_empty() {
+        kind
+    } else {
+        format!("{kind} {}", param.name)
+    }
+}
+
+fn expand_error_param_type(kind: &ParamType) -> String {
+    match kind {
+        ParamType::Array(ty) => format!("{}[]", expand_error_param_type(ty)),
+        ParamType::FixedArray(ty, size) => format!("{}[{}]", expand_error_param_type(ty), *size),
+        _ => kind.to_string(),
+    }
+}
+
 /// Returns all struct type defs
 fn format_struct_types(structs: &InternalStructs) -> String {
     structs